@@ -0,0 +1,43 @@
+//! Serial vs. parallel RGBA8 conversion of 4K frames.
+//!
+//! Run with `cargo bench --features rayon`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use omt::{Codec, OwnedMediaFrame, VideoFrameBuilder};
+use std::hint::black_box;
+
+const WIDTH: usize = 3840;
+const HEIGHT: usize = 2160;
+
+/// A 4K frame with a horizontal gradient in every plane.
+fn frame(codec: Codec) -> OwnedMediaFrame {
+    let bytes_per_pixel = if codec == Codec::Bgra { 4 } else { 2 };
+    let row: Vec<u8> = (0..WIDTH * bytes_per_pixel).map(|i| i as u8).collect();
+    let mut data = row.repeat(HEIGHT);
+    if codec == Codec::Uyva {
+        data.extend(std::iter::repeat_n(255, WIDTH * HEIGHT));
+    }
+
+    VideoFrameBuilder::with_format(codec, WIDTH as i32, HEIGHT as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame")
+}
+
+fn bench_to_rgba8(c: &mut Criterion) {
+    for codec in [Codec::Uyva, Codec::Uyvy, Codec::Bgra] {
+        let frame = frame(codec);
+        let media_frame = frame.as_media_frame();
+
+        let mut group = c.benchmark_group(format!("to_rgba8_{}", codec.fourcc()));
+        group.sample_size(20);
+        group.bench_function("serial", |b| b.iter(|| black_box(media_frame.to_rgba8())));
+        group.bench_function("parallel", |b| {
+            b.iter(|| black_box(media_frame.to_rgba8_parallel()))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_to_rgba8);
+criterion_main!(benches);
@@ -77,7 +77,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!(
                 "Sent {} frames, {} connections",
                 frame_count,
-                sender.connections()
+                sender.connection_count()
             );
 
             // Print statistics
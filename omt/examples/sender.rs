@@ -55,7 +55,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Monitor for connections
     loop {
-        let connections = sender.connections();
+        let connections = sender.connection_count();
         if connections > 0 {
             println!("Active connections: {}", connections);
         }
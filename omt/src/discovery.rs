@@ -1,11 +1,122 @@
 //! Network discovery for OMT sources.
 
+use crate::error::Error;
+use crate::receiver::probe_url;
+use crate::settings::Settings;
 use std::ffi::CStr;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Largest source count accepted from libomt; larger counts indicate corruption.
+const MAX_SOURCE_COUNT: i32 = 10000;
+
+/// Time allowed for a configured discovery server to accept a connection.
+const DISCOVERY_SERVER_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// (server, reason) of the last failed query, shared by all [`Discovery`]
+/// values as libomt runs a single discovery per process.
+static LAST_ERROR: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+/// Timing options for [`DiscoverySession::wait_for()`].
+///
+/// # Examples
+///
+/// ```
+/// use omt::{Discovery, DiscoveryOptions};
+/// use std::time::Duration;
+///
+/// let discovery = Discovery::with_options(DiscoveryOptions {
+///     poll_interval: Duration::from_millis(100),
+///     ..DiscoveryOptions::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscoveryOptions {
+    /// Time between successive queries of the source list.
+    ///
+    /// Default: 500 ms
+    pub poll_interval: Duration,
+    /// Time to wait after the first query before polling again.
+    ///
+    /// The first query starts discovery in the background, so its result is
+    /// usually incomplete. On slow networks, increasing this avoids polling
+    /// before responses can have arrived.
+    ///
+    /// Default: 0 (poll at `poll_interval` right away)
+    pub initial_wait: Duration,
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            initial_wait: Duration::ZERO,
+        }
+    }
+}
 
 /// Discovery utility for finding OMT sources on the network.
+///
+/// [`get_addresses()`](Self::get_addresses) lists the sources currently known
+/// to libomt, and [`last_error()`](Self::last_error) tells why the last query
+/// failed. To wait for sources or filter them, use a [`DiscoverySession`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::Discovery;
+///
+/// let sources = Discovery::get_addresses();
+/// if let Some(error) = Discovery.last_error() {
+///     eprintln!("Discovery failed: {}", error);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Discovery;
 
+/// A discovery session with its own timing options, source filter and error
+/// state.
+///
+/// The session records failures reported while querying libomt, which can be
+/// retrieved with [`last_error()`](Self::last_error).
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::DiscoverySession;
+///
+/// let discovery = DiscoverySession::new();
+/// let sources = discovery.addresses();
+/// if let Some(error) = discovery.last_error() {
+///     eprintln!("Discovery failed: {}", error);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct DiscoverySession {
+    options: DiscoveryOptions,
+    // Lowercased sender name filter applied by sources()
+    filter: Option<String>,
+    // (server, reason) of the last failure; Error itself is not Clone
+    last_error: Mutex<Option<(String, String)>>,
+}
+
 impl Discovery {
+    /// Creates a discovery session with the given timing options.
+    ///
+    /// Shorthand for [`DiscoverySession::with_options()`].
+    pub fn with_options(options: DiscoveryOptions) -> DiscoverySession {
+        DiscoverySession::with_options(options)
+    }
+
+    /// Creates a discovery session that only lists sources with `filter` in
+    /// their name.
+    ///
+    /// Shorthand for [`DiscoverySession::new_filtered()`].
+    pub fn new_filtered(filter: &str) -> DiscoverySession {
+        DiscoverySession::new_filtered(filter)
+    }
+
     /// Returns a list of available OMT sources on the network.
     ///
     /// Each string is in the format "HOSTNAME (NAME)" or a URL like "omt://hostname:port".
@@ -17,6 +128,10 @@ impl Discovery {
     /// so the first call typically returns an empty or incomplete list as the discovery
     /// process is still initializing.
     ///
+    /// If a discovery server is configured and no sources are listed, the
+    /// server is probed, and a server that does not accept connections is
+    /// recorded as a failure, see [`last_error()`](Self::last_error).
+    ///
     /// # Memory Safety Note
     ///
     /// The C API returns a `char**` array that is "valid until the next call to getaddresses".
@@ -39,46 +154,473 @@ impl Discovery {
     /// }
     /// ```
     pub fn get_addresses() -> Vec<String> {
-        let mut count: i32 = 0;
+        query_addresses().0
+    }
 
-        // SAFETY: omt_discovery_getaddresses is a C function that returns a pointer to an array
-        // of C strings. The function writes the array length to the count parameter.
-        let addresses = unsafe { omt_sys::omt_discovery_getaddresses(&mut count as *mut i32) };
+    /// Returns the error recorded by the last discovery query.
+    ///
+    /// Every query made through [`get_addresses()`](Self::get_addresses) or a
+    /// [`DiscoverySession`] records its outcome here, as libomt runs a single
+    /// discovery for the whole process. Failures are an unreachable discovery
+    /// server, an implausible source count, or source names that are not
+    /// valid UTF-8.
+    ///
+    /// Returns `None` if no query has been made yet or the last query succeeded.
+    pub fn last_error(&self) -> Option<Error> {
+        to_error(&LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
 
-        // Validate inputs from C
-        if addresses.is_null() || count <= 0 {
-            return Vec::new();
+impl DiscoverySession {
+    /// Creates a new discovery session with default [`DiscoveryOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new discovery session with the given timing options.
+    pub fn with_options(options: DiscoveryOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
         }
+    }
 
-        // Guard against unreasonably large counts that might indicate corruption
-        if count > 10000 {
-            eprintln!(
-                "Warning: Discovery returned suspiciously large count: {}",
-                count
-            );
-            return Vec::new();
+    /// Creates a new discovery session whose [`sources()`](Self::sources)
+    /// only lists sources with `filter` in their name.
+    ///
+    /// Matching is case-insensitive and applies to the sender name portion of
+    /// a `"HOSTNAME (NAME)"` source, so a filter never matches the hostname.
+    /// Sources listed by URL only (`omt://hostname:port`) have no sender name
+    /// and are excluded. [`addresses()`](Self::addresses) and
+    /// [`local_sources()`](Self::local_sources) are not filtered.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::DiscoverySession;
+    ///
+    /// let discovery = DiscoverySession::new_filtered("studio-a");
+    /// // Lists e.g. "HOST (STUDIO-A Camera 1)" but not "STUDIO-A (Graphics)"
+    /// for source in discovery.sources() {
+    ///     println!("Found source: {}", source);
+    /// }
+    /// ```
+    pub fn new_filtered(filter: &str) -> Self {
+        Self {
+            filter: Some(filter.to_lowercase()),
+            ..Self::default()
         }
+    }
 
-        let mut result = Vec::with_capacity(count as usize);
-        for i in 0..count as isize {
-            unsafe {
-                // SAFETY: The C API guarantees that addresses points to an array of at least
-                // 'count' pointers. We validate each pointer before dereferencing.
-                let ptr = *addresses.offset(i);
-                if !ptr.is_null() {
-                    // SAFETY: CStr::from_ptr requires the pointer to be valid and point to
-                    // a null-terminated C string. The C API guarantees this for the duration
-                    // of this call. We immediately copy the string data to avoid lifetime issues.
-                    if let Ok(cstr) = CStr::from_ptr(ptr).to_str() {
-                        result.push(cstr.to_string());
-                    }
-                    // If UTF-8 validation fails, we skip this entry
-                }
+    /// Returns the lowercased sender name filter set with
+    /// [`new_filtered()`](Self::new_filtered), if any.
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// Returns the timing options of this session.
+    pub fn options(&self) -> DiscoveryOptions {
+        self.options
+    }
+
+    /// Waits until a source with the given name appears and returns its address.
+    ///
+    /// `name` matches either a full address as returned by
+    /// [`addresses()`](Self::addresses) or the source name part of a
+    /// `"HOSTNAME (NAME)"` address. The source list is queried immediately, then
+    /// after [`initial_wait`](DiscoveryOptions::initial_wait) and every
+    /// [`poll_interval`](DiscoveryOptions::poll_interval) until `timeout` expires.
+    ///
+    /// Returns `None` if no matching source was found in time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::DiscoverySession;
+    /// use std::time::Duration;
+    ///
+    /// let discovery = DiscoverySession::new();
+    /// if let Some(address) = discovery.wait_for("My Camera", Duration::from_secs(5)) {
+    ///     println!("Found {}", address);
+    /// }
+    /// ```
+    pub fn wait_for(&self, name: &str, timeout: Duration) -> Option<String> {
+        let suffix = format!("({})", name);
+        self.poll_until(timeout, |address| {
+            address == name || address.ends_with(&suffix)
+        })
+    }
+
+    /// Waits until a source matching `name` appears and returns its address.
+    ///
+    /// Like [`wait_for()`](Self::wait_for), but less strict: `name` matches
+    /// either a full address exactly, or any part of the sender name of a
+    /// `"HOSTNAME (NAME)"` address. For example, `"Camera"` matches
+    /// `"STUDIO-PC (Camera 1)"` but not `"CAMERA-PC (Graphics)"`. If several
+    /// sources match, the first one listed is returned.
+    ///
+    /// Returns `None` if no matching source was found within `timeout`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::{DiscoverySession, FrameType, PreferredVideoFormat, ReceiveFlags, Receiver};
+    /// use std::time::Duration;
+    ///
+    /// let discovery = DiscoverySession::new();
+    /// if let Some(address) = discovery.wait_for_source("Camera", Duration::from_secs(5)) {
+    ///     let receiver = Receiver::new(
+    ///         &address,
+    ///         FrameType::VIDEO,
+    ///         PreferredVideoFormat::Uyvy,
+    ///         ReceiveFlags::NONE,
+    ///     )?;
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn wait_for_source(&self, name: &str, timeout: Duration) -> Option<String> {
+        self.poll_until(timeout, |address| source_matches(address, name))
+    }
+
+    /// Polls the source list with this session's timing options until an
+    /// address satisfies `matches` or `timeout` expires.
+    fn poll_until(&self, timeout: Duration, matches: impl Fn(&str) -> bool) -> Option<String> {
+        poll_addresses(
+            self.options,
+            timeout,
+            &SystemClock,
+            || self.addresses(),
+            matches,
+        )
+    }
+
+    /// Returns a list of available OMT sources on the network.
+    ///
+    /// Behaves like [`Discovery::get_addresses()`], but also records the
+    /// failure of the query in this session, so it can be retrieved with
+    /// [`last_error()`](Self::last_error). Failures are a configured discovery
+    /// server that does not accept connections while no sources are listed,
+    /// an implausible source count, or source names that are not valid UTF-8.
+    /// Sources that could be read are returned regardless. A query without
+    /// failures clears any previously recorded error.
+    pub fn addresses(&self) -> Vec<String> {
+        let (addresses, failure) = query_addresses();
+        *self.last_error.lock().unwrap_or_else(|e| e.into_inner()) = failure;
+        addresses
+    }
+
+    /// Returns a snapshot of the currently known source names, e.g.
+    /// `"HOSTNAME (Sender Name)"`.
+    ///
+    /// Like [`addresses()`](Self::addresses), but each source is listed only
+    /// once, in the order it was first reported. Every call queries the current
+    /// list, so sources that appeared or disappeared since the last call are
+    /// reflected.
+    ///
+    /// Discovery is asynchronous: it starts in the background on the first
+    /// query, so a call right after [`new()`](Self::new) may return an empty
+    /// list. Use [`wait_for()`](Self::wait_for) to wait for a specific source.
+    ///
+    /// For sessions created with [`new_filtered()`](Self::new_filtered), only
+    /// sources whose sender name contains the filter are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::DiscoverySession;
+    ///
+    /// let discovery = DiscoverySession::new();
+    /// for source in discovery.sources() {
+    ///     println!("Found source: {}", source);
+    /// }
+    /// ```
+    pub fn sources(&self) -> Vec<String> {
+        let sources = dedup_addresses(self.addresses());
+        match &self.filter {
+            Some(filter) => filter_names(sources, filter),
+            None => sources,
+        }
+    }
+
+    /// Returns the sources from [`addresses()`](Self::addresses) that originate
+    /// on this machine.
+    ///
+    /// The host of an address is the `HOSTNAME` part of a `"HOSTNAME (NAME)"`
+    /// entry, or the host of an `omt://hostname:port` URL. A source is local if
+    /// its host is `localhost`, a loopback IP address, or matches the local
+    /// hostname, compared case-insensitively and ignoring any domain suffix.
+    ///
+    /// The local hostname is taken from the `HOSTNAME` environment variable, then
+    /// `COMPUTERNAME` on Windows, and finally `/etc/hostname` on other
+    /// platforms. If it cannot be determined, only loopback sources are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::DiscoverySession;
+    ///
+    /// for source in DiscoverySession::new().local_sources() {
+    ///     println!("Local source: {}", source);
+    /// }
+    /// ```
+    pub fn local_sources(&self) -> Vec<String> {
+        filter_local(self.addresses(), local_hostname().as_deref())
+    }
+
+    /// Returns the error recorded by the last call to [`addresses()`](Self::addresses)
+    /// on this session.
+    ///
+    /// Returns `None` if no query has been made yet or the last query succeeded.
+    /// See [`Discovery::last_error()`] for the last failure of any query.
+    pub fn last_error(&self) -> Option<Error> {
+        to_error(&self.last_error.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// Converts a recorded (server, reason) failure into an [`Error`].
+fn to_error(failure: &Option<(String, String)>) -> Option<Error> {
+    failure
+        .as_ref()
+        .map(|(server, reason)| Error::DiscoveryFailed {
+            server: server.clone(),
+            reason: reason.clone(),
+        })
+}
+
+/// Queries libomt for the currently known sources and records the outcome
+/// for [`Discovery::last_error()`].
+///
+/// Returns the addresses together with the (server, reason) of any failure.
+fn query_addresses() -> (Vec<String>, Option<(String, String)>) {
+    // An unreadable setting is reported as DNS-SD, the server is informational
+    let server = Settings::discovery_server().unwrap_or_default();
+    let (addresses, mut failure) = list_addresses();
+    if failure.is_none() && addresses.is_empty() {
+        failure = check_server(&server, DISCOVERY_SERVER_PROBE_TIMEOUT);
+    }
+
+    let failure = failure.map(|reason| (server, reason));
+    *LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()) = failure.clone();
+    (addresses, failure)
+}
+
+/// Probes a configured discovery server in the format `omt://hostname:port`.
+///
+/// libomt keeps connecting to an unreachable server in the background and
+/// meanwhile lists no sources, so an empty list alone does not reveal it.
+/// Returns a description of the failure, or `None` if no server is configured
+/// or it accepts connections.
+fn check_server(server: &str, timeout: Duration) -> Option<String> {
+    if server.is_empty() {
+        return None;
+    }
+    probe_url(server, timeout)
+        .err()
+        .map(|reason| format!("discovery server {} is unreachable: {}", server, reason))
+}
+
+/// Lists the sources currently known to libomt.
+///
+/// Returns the addresses together with a description of any failure libomt
+/// reported, such as an implausible source count or source names that are not
+/// valid UTF-8. Sources that could be read are returned regardless.
+fn list_addresses() -> (Vec<String>, Option<String>) {
+    let mut count: i32 = 0;
+
+    // SAFETY: omt_discovery_getaddresses is a C function that returns a pointer to an array
+    // of C strings. The function writes the array length to the count parameter.
+    let addresses = unsafe { omt_sys::omt_discovery_getaddresses(&mut count as *mut i32) };
+
+    // Validate inputs from C
+    if addresses.is_null() || count <= 0 {
+        return (Vec::new(), None);
+    }
+
+    // Guard against unreasonably large counts that might indicate corruption
+    if count > MAX_SOURCE_COUNT {
+        eprintln!(
+            "Warning: Discovery returned suspiciously large count: {}",
+            count
+        );
+        return (
+            Vec::new(),
+            Some(format!(
+                "libomt reported an implausible source count of {}",
+                count
+            )),
+        );
+    }
+
+    let mut names = Vec::with_capacity(count as usize);
+    for i in 0..count as isize {
+        unsafe {
+            // SAFETY: The C API guarantees that addresses points to an array of at least
+            // 'count' pointers. We validate each pointer before dereferencing.
+            let ptr = *addresses.offset(i);
+            if !ptr.is_null() {
+                // SAFETY: CStr::from_ptr requires the pointer to be valid and point to
+                // a null-terminated C string. The C API guarantees this for the duration
+                // of this call. decode_addresses copies the string data before returning.
+                names.push(CStr::from_ptr(ptr));
             }
         }
+    }
 
-        result
+    decode_addresses(names)
+}
+
+/// Copies source names into owned strings, skipping names that are not valid UTF-8.
+///
+/// Returns the addresses and, if any names were skipped, a description of the failure.
+fn decode_addresses<'a>(
+    names: impl IntoIterator<Item = &'a CStr>,
+) -> (Vec<String>, Option<String>) {
+    let mut addresses = Vec::new();
+    let mut invalid = 0;
+    for name in names {
+        match name.to_str() {
+            Ok(address) => addresses.push(address.to_string()),
+            Err(_) => invalid += 1,
+        }
     }
+
+    let failure = (invalid > 0).then(|| format!("{} source names are not valid UTF-8", invalid));
+    (addresses, failure)
+}
+
+/// Time source used while polling, so tests can simulate the passage of time.
+trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+    /// Blocks for `duration`.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The real clock.
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Queries `addresses` until one satisfies `matches` or `timeout` expires.
+///
+/// The first query is made immediately, the second after
+/// [`initial_wait`](DiscoveryOptions::initial_wait) and the following ones
+/// every [`poll_interval`](DiscoveryOptions::poll_interval).
+fn poll_addresses(
+    options: DiscoveryOptions,
+    timeout: Duration,
+    clock: &impl Clock,
+    mut addresses: impl FnMut() -> Vec<String>,
+    matches: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let deadline = clock.now() + timeout;
+    let mut delay = options.initial_wait;
+
+    loop {
+        if let Some(address) = addresses().into_iter().find(|address| matches(address)) {
+            return Some(address);
+        }
+
+        let now = clock.now();
+        if now >= deadline {
+            return None;
+        }
+        clock.sleep(delay.max(options.poll_interval).min(deadline - now));
+        delay = Duration::ZERO;
+    }
+}
+
+/// Determines the hostname of this machine, without any domain suffix.
+fn local_hostname() -> Option<String> {
+    let hostname = std::env::var("HOSTNAME").ok().or_else(|| {
+        if cfg!(windows) {
+            std::env::var("COMPUTERNAME").ok()
+        } else {
+            std::fs::read_to_string("/etc/hostname").ok()
+        }
+    })?;
+    let hostname = short_host(hostname.trim());
+    (!hostname.is_empty()).then(|| hostname.to_string())
+}
+
+/// Extracts the host part of a `"HOSTNAME (NAME)"` or `omt://hostname:port` address.
+fn address_host(address: &str) -> &str {
+    if let Some(rest) = address.strip_prefix("omt://") {
+        let authority = rest.split('/').next().unwrap_or(rest);
+        // Bracketed IPv6 literal, e.g. omt://[::1]:6400
+        if let Some(ipv6) = authority.strip_prefix('[') {
+            return ipv6.split(']').next().unwrap_or(ipv6);
+        }
+        return authority
+            .rsplit_once(':')
+            .map_or(authority, |(host, _)| host);
+    }
+    address
+        .split_once(" (")
+        .map_or(address, |(host, _)| host)
+        .trim()
+}
+
+/// Extracts the sender name of a `"HOSTNAME (NAME)"` address.
+fn address_name(address: &str) -> Option<&str> {
+    let (_, rest) = address.split_once(" (")?;
+    rest.strip_suffix(')')
+}
+
+/// Returns whether `name` is the full `address` or part of its sender name.
+fn source_matches(address: &str, name: &str) -> bool {
+    address == name || address_name(address).is_some_and(|source| source.contains(name))
+}
+
+/// Keeps the addresses whose sender name contains the lowercase `filter`,
+/// ignoring case.
+fn filter_names(addresses: Vec<String>, filter: &str) -> Vec<String> {
+    addresses
+        .into_iter()
+        .filter(|address| {
+            address_name(address).is_some_and(|name| name.to_lowercase().contains(filter))
+        })
+        .collect()
+}
+
+/// Strips the domain suffix from a hostname; IP addresses are returned unchanged.
+fn short_host(host: &str) -> &str {
+    if host.parse::<std::net::IpAddr>().is_ok() {
+        return host;
+    }
+    host.split('.').next().unwrap_or(host)
+}
+
+/// Removes repeated addresses, keeping the first occurrence of each.
+fn dedup_addresses(addresses: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    addresses
+        .into_iter()
+        .filter(|address| seen.insert(address.clone()))
+        .collect()
+}
+
+/// Keeps the addresses whose host is loopback or equal to `hostname`.
+fn filter_local(addresses: Vec<String>, hostname: Option<&str>) -> Vec<String> {
+    addresses
+        .into_iter()
+        .filter(|address| {
+            let host = address_host(address);
+            host.eq_ignore_ascii_case("localhost")
+                || host
+                    .parse::<std::net::IpAddr>()
+                    .is_ok_and(|ip| ip.is_loopback())
+                || hostname.is_some_and(|name| short_host(host).eq_ignore_ascii_case(name))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -92,4 +634,245 @@ mod tests {
         // Should not panic, might be empty
         assert!(addresses.len() >= 0);
     }
+
+    #[test]
+    fn test_discovery_options_default() {
+        let options = DiscoverySession::new().options();
+        assert_eq!(options, DiscoveryOptions::default());
+        assert_eq!(options.poll_interval, Duration::from_millis(500));
+        assert_eq!(options.initial_wait, Duration::ZERO);
+    }
+
+    /// A clock that only advances when slept on.
+    struct SimulatedClock(std::cell::Cell<Instant>);
+
+    impl Clock for SimulatedClock {
+        fn now(&self) -> Instant {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    /// Polls for a source that appears after `appears_after` of simulated time.
+    ///
+    /// Returns the simulated time until it was found and the number of queries.
+    fn simulate_wait(options: DiscoveryOptions, appears_after: Duration) -> (Duration, usize) {
+        let start = Instant::now();
+        let clock = SimulatedClock(std::cell::Cell::new(start));
+        let mut queries = 0;
+        let address = poll_addresses(
+            options,
+            Duration::from_secs(5),
+            &clock,
+            || {
+                queries += 1;
+                if clock.now() - start >= appears_after {
+                    vec!["STUDIO-PC (Camera 1)".to_string()]
+                } else {
+                    Vec::new()
+                }
+            },
+            |address| source_matches(address, "Camera 1"),
+        );
+        assert_eq!(address.as_deref(), Some("STUDIO-PC (Camera 1)"));
+        (clock.now() - start, queries)
+    }
+
+    #[test]
+    fn test_poll_interval_detects_source_sooner() {
+        let appears_after = Duration::from_millis(120);
+
+        let fast = DiscoveryOptions {
+            poll_interval: Duration::from_millis(10),
+            ..DiscoveryOptions::default()
+        };
+        assert_eq!(
+            simulate_wait(fast, appears_after),
+            (Duration::from_millis(120), 13)
+        );
+        assert_eq!(
+            simulate_wait(DiscoveryOptions::default(), appears_after),
+            (Duration::from_millis(500), 2)
+        );
+    }
+
+    #[test]
+    fn test_poll_initial_wait_and_timeout() {
+        let options = DiscoveryOptions {
+            poll_interval: Duration::from_millis(100),
+            initial_wait: Duration::from_millis(300),
+        };
+        assert_eq!(
+            simulate_wait(options, Duration::from_millis(350)),
+            (Duration::from_millis(400), 3)
+        );
+
+        let start = Instant::now();
+        let clock = SimulatedClock(std::cell::Cell::new(start));
+        let mut queries = 0;
+        let address = poll_addresses(
+            options,
+            Duration::from_millis(250),
+            &clock,
+            || {
+                queries += 1;
+                Vec::new()
+            },
+            |_| true,
+        );
+        assert_eq!(address, None);
+        // Queried at 0 and at the deadline, the initial wait being cut short
+        assert_eq!(
+            (clock.now() - start, queries),
+            (Duration::from_millis(250), 2)
+        );
+    }
+
+    #[test]
+    fn test_address_host() {
+        assert_eq!(address_host("STUDIO-PC (Camera 1)"), "STUDIO-PC");
+        assert_eq!(address_host("omt://studio-pc:6400"), "studio-pc");
+        assert_eq!(address_host("omt://127.0.0.1:6400/"), "127.0.0.1");
+        assert_eq!(address_host("omt://[::1]:6400"), "::1");
+    }
+
+    #[test]
+    fn test_source_matches() {
+        assert_eq!(
+            address_name("STUDIO-PC (Camera (Left))"),
+            Some("Camera (Left)")
+        );
+        assert_eq!(address_name("omt://studio-pc:6400"), None);
+
+        assert!(source_matches(
+            "STUDIO-PC (Camera 1)",
+            "STUDIO-PC (Camera 1)"
+        ));
+        assert!(source_matches("STUDIO-PC (Camera 1)", "Camera"));
+        assert!(source_matches("STUDIO-PC (Camera 1)", "era 1"));
+        assert!(!source_matches("CAMERA-PC (Graphics)", "CAMERA"));
+        assert!(!source_matches("STUDIO-PC (Camera 1)", "camera"));
+        assert!(source_matches(
+            "omt://studio-pc:6400",
+            "omt://studio-pc:6400"
+        ));
+        assert!(!source_matches("omt://studio-pc:6400", "studio"));
+    }
+
+    #[test]
+    fn test_filter_names() {
+        let addresses = vec![
+            "HOST (STUDIO-A Camera 1)".to_string(),
+            "HOST (studio-a graphics)".to_string(),
+            "STUDIO-A (Camera 2)".to_string(),
+            "HOST (STUDIO-B Camera 1)".to_string(),
+            "omt://studio-a:6400".to_string(),
+        ];
+        assert_eq!(
+            filter_names(addresses, "studio-a"),
+            vec!["HOST (STUDIO-A Camera 1)", "HOST (studio-a graphics)"]
+        );
+
+        let discovery = Discovery::new_filtered("STUDIO-A");
+        assert_eq!(discovery.filter(), Some("studio-a"));
+        assert_eq!(DiscoverySession::new().filter(), None);
+    }
+
+    #[test]
+    fn test_dedup_addresses() {
+        let addresses = vec![
+            "STUDIO-PC (Camera 1)".to_string(),
+            "STUDIO-PC (Camera 2)".to_string(),
+            "STUDIO-PC (Camera 1)".to_string(),
+            "omt://10.0.0.5:6400".to_string(),
+            "STUDIO-PC (Camera 2)".to_string(),
+        ];
+        assert_eq!(
+            dedup_addresses(addresses),
+            vec![
+                "STUDIO-PC (Camera 1)",
+                "STUDIO-PC (Camera 2)",
+                "omt://10.0.0.5:6400",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_local() {
+        let addresses = vec![
+            "STUDIO-PC (Camera 1)".to_string(),
+            "REMOTE-PC (Camera 2)".to_string(),
+            "studio-pc.example.com (Graphics)".to_string(),
+            "omt://localhost:6400".to_string(),
+            "omt://127.0.0.1:6401".to_string(),
+            "omt://[::1]:6402".to_string(),
+            "omt://10.0.0.5:6400".to_string(),
+            "omt://remote-pc:6400".to_string(),
+        ];
+
+        assert_eq!(
+            filter_local(addresses.clone(), Some("studio-pc")),
+            vec![
+                "STUDIO-PC (Camera 1)",
+                "studio-pc.example.com (Graphics)",
+                "omt://localhost:6400",
+                "omt://127.0.0.1:6401",
+                "omt://[::1]:6402",
+            ]
+        );
+        assert_eq!(
+            filter_local(addresses, None),
+            vec![
+                "omt://localhost:6400",
+                "omt://127.0.0.1:6401",
+                "omt://[::1]:6402",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_addresses_skips_invalid_utf8() {
+        let names = [c"STUDIO-PC (Camera 1)", c"STUDIO-PC (\xff)"];
+        let (addresses, failure) = decode_addresses(names);
+        assert_eq!(addresses, vec!["STUDIO-PC (Camera 1)"]);
+        assert_eq!(
+            failure.as_deref(),
+            Some("1 source names are not valid UTF-8")
+        );
+
+        assert_eq!(decode_addresses(names[..1].iter().copied()).1, None);
+    }
+
+    #[test]
+    fn test_session_last_error_starts_empty() {
+        assert!(DiscoverySession::new().last_error().is_none());
+
+        let failure = Some(("omt://server:6400".to_string(), "refused".to_string()));
+        match to_error(&failure) {
+            Some(Error::DiscoveryFailed { server, reason }) => {
+                assert_eq!(server, "omt://server:6400");
+                assert_eq!(reason, "refused");
+            }
+            other => panic!("Expected DiscoveryFailed, got {:?}", other),
+        }
+        assert!(to_error(&None).is_none());
+    }
+
+    #[test]
+    fn test_check_server() {
+        let timeout = Duration::from_millis(100);
+        assert_eq!(check_server("", timeout), None);
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let server = format!("omt://127.0.0.1:{}", listener.local_addr().unwrap().port());
+        assert_eq!(check_server(&server, timeout), None);
+
+        drop(listener);
+        let reason = check_server(&server, timeout).expect("Closed server was reachable");
+        assert!(reason.starts_with(&format!("discovery server {} is unreachable", server)));
+        assert!(check_server("server:6400", timeout).is_some());
+    }
 }
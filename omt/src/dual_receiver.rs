@@ -0,0 +1,113 @@
+//! Simultaneous preview and full resolution reception from one source.
+
+use crate::error::Result;
+use crate::frame::MediaFrame;
+use crate::receiver::Receiver;
+use crate::types::{FrameType, PreferredVideoFormat, ReceiveFlags};
+
+/// Receives a cheap preview of a source, with full resolution video on demand.
+///
+/// libomt delivers either preview or full frames per receiver, so this wraps
+/// two receivers connected to the same address: one created with
+/// [`ReceiveFlags::PREVIEW`], which gets 1/8th size frames flagged with
+/// [`VideoFlags::PREVIEW`](crate::VideoFlags::PREVIEW), and one without it.
+///
+/// The preview receiver connects immediately. The full resolution receiver
+/// connects on the first call to [`full_frame()`](Self::full_frame), so its
+/// first frame may take longer to arrive, and can be disconnected again with
+/// [`release_full()`](Self::release_full) to save bandwidth while only the
+/// preview is shown. Both receivers are disconnected when the `DualReceiver`
+/// is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::{DualReceiver, PreferredVideoFormat};
+///
+/// let mut receiver = DualReceiver::new("omt://hostname:6400", PreferredVideoFormat::Uyvy)?;
+/// if let Some(frame) = receiver.preview_frame(1000)? {
+///     println!("Preview: {}x{}", frame.width(), frame.height());
+/// }
+/// // The user selected this source, show it at full resolution
+/// if let Some(frame) = receiver.full_frame(1000)? {
+///     println!("Full: {}x{}", frame.width(), frame.height());
+/// }
+/// # Ok::<(), omt::Error>(())
+/// ```
+pub struct DualReceiver {
+    address: String,
+    format: PreferredVideoFormat,
+    preview: Receiver,
+    full: Option<Receiver>,
+}
+
+impl DualReceiver {
+    /// Creates a dual receiver for the source at `address` and connects the preview.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address to connect to. Either the full name from discovery
+    ///   or a URL in the format `omt://hostname:port`
+    /// * `format` - Preferred uncompressed video format for both receivers
+    pub fn new(address: &str, format: PreferredVideoFormat) -> Result<Self> {
+        let preview = Receiver::new(address, FrameType::VIDEO, format, ReceiveFlags::PREVIEW)?;
+        Ok(Self {
+            address: address.to_string(),
+            format,
+            preview,
+            full: None,
+        })
+    }
+
+    /// Returns the address of the source.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Receives the next preview video frame.
+    ///
+    /// Returns `Ok(None)` if no frame arrived within `timeout_ms`.
+    pub fn preview_frame(&mut self, timeout_ms: i32) -> Result<Option<MediaFrame<'_>>> {
+        self.preview.receive(FrameType::VIDEO, timeout_ms)
+    }
+
+    /// Receives the next full resolution video frame.
+    ///
+    /// Connects the full resolution receiver first if it is not connected.
+    /// Returns `Ok(None)` if no frame arrived within `timeout_ms`.
+    pub fn full_frame(&mut self, timeout_ms: i32) -> Result<Option<MediaFrame<'_>>> {
+        if self.full.is_none() {
+            self.full = Some(Receiver::new(
+                &self.address,
+                FrameType::VIDEO,
+                self.format,
+                ReceiveFlags::NONE,
+            )?);
+        }
+        let Some(full) = self.full.as_mut() else {
+            return Ok(None);
+        };
+        full.receive(FrameType::VIDEO, timeout_ms)
+    }
+
+    /// Returns whether the full resolution receiver is connected.
+    pub fn is_full_connected(&self) -> bool {
+        self.full.is_some()
+    }
+
+    /// Disconnects the full resolution receiver until the next
+    /// [`full_frame()`](Self::full_frame) call.
+    pub fn release_full(&mut self) {
+        self.full = None;
+    }
+
+    /// Returns the preview receiver, e.g. to set tally or query statistics.
+    pub fn preview(&self) -> &Receiver {
+        &self.preview
+    }
+
+    /// Returns the full resolution receiver, if connected.
+    pub fn full(&self) -> Option<&Receiver> {
+        self.full.as_ref()
+    }
+}
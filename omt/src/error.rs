@@ -59,14 +59,126 @@ pub enum Error {
         reason: String,
     },
 
+    /// The frame's codec is not in the sender's allowed set, or libomt cannot
+    /// send it at all.
+    ///
+    /// See [`Codec::is_runtime_supported()`](crate::Codec::is_runtime_supported) and [`Sender::set_allowed_codecs()`](crate::Sender::set_allowed_codecs).
+    #[error("codec not allowed by sender: {0}")]
+    CodecNotAllowed(crate::types::Codec),
+
+    /// Discovery failed to list sources, e.g. because the configured
+    /// discovery server is unreachable.
+    ///
+    /// See [`Discovery::last_error()`](crate::Discovery::last_error).
+    #[error("discovery failed: {reason}")]
+    DiscoveryFailed {
+        /// Discovery server address as configured in the settings, empty if
+        /// DNS-SD discovery is used.
+        server: String,
+        /// Reason for the failure.
+        reason: String,
+    },
+
+    /// None of the given addresses could be connected to.
+    ///
+    /// See [`Receiver::create_any()`](crate::Receiver::create_any).
+    #[error("cannot connect to '{address}': {reason}")]
+    ConnectFailed {
+        /// The last address that was tried.
+        address: String,
+        /// Reason the last address failed.
+        reason: String,
+    },
+
+    /// An I/O error occurred, e.g. while writing a recording.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A native libomt call failed with the given return code.
+    #[error("native call failed with code {0}")]
+    Native(i32),
+
+    /// A video frame could not be converted.
+    #[error("conversion failed: {0}")]
+    Convert(#[from] ConvertError),
+
     /// Generic error with message.
     #[error("{0}")]
     Other(String),
 }
 
+/// Reasons a video frame cannot be converted to another pixel format.
+///
+/// Returned by [`MediaFrame::try_to_rgba8()`](crate::MediaFrame::try_to_rgba8).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum ConvertError {
+    /// The frame only carries compressed VMX1 data.
+    ///
+    /// This happens when receiving with
+    /// [`ReceiveFlags::COMPRESSED_ONLY`](crate::ReceiveFlags::COMPRESSED_ONLY),
+    /// which skips decoding.
+    #[error("frame only contains compressed data, receive without COMPRESSED_ONLY to decode")]
+    CompressedOnly,
+
+    /// The frame is not a video frame, or its codec cannot be converted.
+    #[error("codec not supported for this conversion")]
+    UnsupportedCodec,
+
+    /// The dimensions, stride or data length of the frame are inconsistent.
+    #[error("invalid frame geometry")]
+    InvalidGeometry,
+}
+
 impl Error {
     /// Creates a new error with a custom message.
     pub fn other(msg: impl fmt::Display) -> Self {
         Self::Other(msg.to_string())
     }
+
+    /// Returns the name of the variant, e.g. `"Native"`.
+    ///
+    /// Stable across messages, so suited for log fields and metrics labels.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NullPointer => "NullPointer",
+            Self::NulError(_) => "NulError",
+            Self::InvalidUtf8 => "InvalidUtf8",
+            Self::Timeout => "Timeout",
+            Self::SenderCreateFailed => "SenderCreateFailed",
+            Self::ReceiverCreateFailed => "ReceiverCreateFailed",
+            Self::InvalidFrameType => "InvalidFrameType",
+            Self::InvalidCodec(_) => "InvalidCodec",
+            Self::BufferTooSmall { .. } => "BufferTooSmall",
+            Self::InvalidParameter { .. } => "InvalidParameter",
+            Self::CodecNotAllowed(_) => "CodecNotAllowed",
+            Self::DiscoveryFailed { .. } => "DiscoveryFailed",
+            Self::ConnectFailed { .. } => "ConnectFailed",
+            Self::Io(_) => "Io",
+            Self::Native(_) => "Native",
+            Self::Convert(_) => "Convert",
+            Self::Other(_) => "Other",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_error() {
+        let error = Error::Native(-3);
+        assert_eq!(error.name(), "Native");
+        assert_eq!(error.to_string(), "native call failed with code -3");
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(Error::Timeout.name(), "Timeout");
+        assert_eq!(
+            Error::Convert(ConvertError::CompressedOnly).name(),
+            "Convert"
+        );
+        assert_eq!(Error::other("boom").name(), "Other");
+    }
 }
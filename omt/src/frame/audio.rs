@@ -1,8 +1,13 @@
 //! Audio-specific methods for MediaFrame.
 
 use crate::frame::MediaFrame;
+use crate::types::{Codec, FrameType};
+use std::ops::Deref;
 use std::slice;
 
+/// Maximum number of audio channels supported by OMT.
+pub(crate) const MAX_CHANNELS: usize = 32;
+
 impl<'a> MediaFrame<'a> {
     /// Returns the sample rate (e.g., 48000, 44100).
     ///
@@ -25,6 +30,26 @@ impl<'a> MediaFrame<'a> {
         self.ffi.SamplesPerChannel
     }
 
+    /// Returns the size in bytes of the audio as 32-bit float samples.
+    ///
+    /// This is `channels * samples_per_channel * 4`, the size of the planar f32
+    /// data of an audio frame, computed from the header alone. Useful to size
+    /// buffers in advance.
+    ///
+    /// Returns `None` if this is not an audio frame, the channel or sample count
+    /// is negative, or the size overflows `usize`.
+    pub fn audio_output_len(&self) -> Option<usize> {
+        if self.frame_type() != FrameType::AUDIO {
+            return None;
+        }
+
+        let channels = usize::try_from(self.channels()).ok()?;
+        let samples = usize::try_from(self.samples_per_channel()).ok()?;
+        channels
+            .checked_mul(samples)?
+            .checked_mul(std::mem::size_of::<f32>())
+    }
+
     /// Returns the audio data as f32 slices (one per channel).
     ///
     /// Each slice contains `samples_per_channel` samples.
@@ -33,12 +58,12 @@ impl<'a> MediaFrame<'a> {
     /// Returns `None` if the data is not properly aligned or sized for f32 conversion.
     pub fn as_f32_planar(&self) -> Option<Vec<&'a [f32]>> {
         let data = self.data();
-        let samples_per_channel = self.samples_per_channel() as usize;
-        let channels = self.channels() as usize;
-        let samples_per_plane = samples_per_channel * std::mem::size_of::<f32>();
+        let samples_per_channel = usize::try_from(self.samples_per_channel()).ok()?;
+        let channels = usize::try_from(self.channels()).ok()?;
+        let samples_per_plane = samples_per_channel.checked_mul(std::mem::size_of::<f32>())?;
 
-        // Validate total data size
-        let expected_size = channels * samples_per_plane;
+        // Validate total data size without overflowing on 32-bit targets
+        let expected_size = channels.checked_mul(samples_per_plane)?;
         if data.len() != expected_size {
             return None;
         }
@@ -48,23 +73,229 @@ impl<'a> MediaFrame<'a> {
             return None;
         }
 
-        let mut result = Vec::with_capacity(channels);
-        for ch in 0..channels {
-            let offset = ch * samples_per_plane;
-            if offset + samples_per_plane <= data.len() {
-                let plane_data = &data[offset..offset + samples_per_plane];
+        // Planes are empty when there are no samples, chunks_exact needs a non-zero size
+        if samples_per_plane == 0 {
+            return Some(vec![&[][..]; channels]);
+        }
 
+        let result = data
+            .chunks_exact(samples_per_plane)
+            .map(|plane_data| {
                 // SAFETY: We've validated:
-                // 1. The data length matches expected size
-                // 2. The pointer is properly aligned for f32
-                // 3. The slice bounds are within the valid data range
+                // 1. The data length is exactly `channels` planes of `samples_per_plane` bytes
+                // 2. The pointer is properly aligned for f32, and each plane starts at a
+                //    multiple of 4 bytes from it
+                // 3. Each chunk holds exactly `samples_per_channel` f32 values
                 // 4. The lifetime 'a ensures the data remains valid
-                let f32_slice = unsafe {
+                unsafe {
                     slice::from_raw_parts(plane_data.as_ptr() as *const f32, samples_per_channel)
-                };
-                result.push(f32_slice);
-            }
+                }
+            })
+            .collect();
+        Some(result)
+    }
+
+    /// Returns an iterator over the audio samples, one item per sample index.
+    ///
+    /// Each item is a [`SampleFrame`] holding one sample per channel, i.e. an
+    /// interleaved view of the planar audio that is computed lazily without
+    /// allocating an interleaved buffer. A `SampleFrame` dereferences to `&[f32]`
+    /// of length `channels()`.
+    ///
+    /// The iterator borrows the frame's data for the lifetime `'a`, so it cannot
+    /// outlive the frame's source (e.g. the next receive call on the receiver).
+    ///
+    /// Returns `None` if this is not an FPA1 audio frame, under the same
+    /// conditions as [`as_f32_planar()`](MediaFrame::as_f32_planar), or if the
+    /// frame has more than 32 channels.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(frames) = frame.audio_frames() {
+    ///     for samples in frames {
+    ///         // One sample per channel
+    ///         let sum: f32 = samples.iter().sum();
+    ///         let mono = sum / samples.len() as f32;
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn audio_frames(&self) -> Option<AudioFrames<'a>> {
+        // Other frames can carry data of a matching length by coincidence
+        if self.frame_type() != FrameType::AUDIO || self.codec() != Some(Codec::Fpa1) {
+            return None;
+        }
+
+        let planes = self.as_f32_planar()?;
+        if planes.len() > MAX_CHANNELS {
+            return None;
+        }
+
+        Some(AudioFrames {
+            planes,
+            index: 0,
+            len: self.samples_per_channel().max(0) as usize,
+        })
+    }
+
+    /// Returns whether each channel contains a clipped sample.
+    ///
+    /// A channel is clipped if the absolute value of any of its samples exceeds
+    /// `threshold`. Use `1.0` for full scale floating point audio.
+    ///
+    /// Returns one flag per channel, or `None` if this is not an audio frame or
+    /// the data cannot be viewed as planar f32 (see [`as_f32_planar()`](MediaFrame::as_f32_planar)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(clipped) = frame.audio_clipped(1.0) {
+    ///     for (channel, clipped) in clipped.iter().enumerate() {
+    ///         if *clipped {
+    ///             println!("Channel {} is clipping", channel);
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn audio_clipped(&self, threshold: f32) -> Option<Vec<bool>> {
+        if self.frame_type() != FrameType::AUDIO {
+            return None;
+        }
+
+        let planes = self.as_f32_planar()?;
+        Some(
+            planes
+                .iter()
+                .map(|plane| plane.iter().any(|sample| sample.abs() > threshold))
+                .collect(),
+        )
+    }
+
+    /// Returns the audio as a single interleaved f32 buffer.
+    ///
+    /// The result holds `samples_per_channel() * channels()` values ordered by
+    /// sample index, i.e. `L0 R0 L1 R1 ...` for stereo, as expected by audio
+    /// output libraries such as cpal or rodio. This is the inverse of
+    /// [`AudioFrameBuilder::from_interleaved()`](crate::AudioFrameBuilder::from_interleaved).
+    ///
+    /// Returns `None` under the same conditions as [`audio_clipped()`](MediaFrame::audio_clipped).
+    pub fn audio_data_interleaved(&self) -> Option<Vec<f32>> {
+        if self.frame_type() != FrameType::AUDIO {
+            return None;
+        }
+
+        let planes = self.as_f32_planar()?;
+        let samples_per_channel = planes.first().map_or(0, |plane| plane.len());
+        let mut result = Vec::with_capacity(samples_per_channel * planes.len());
+        for i in 0..samples_per_channel {
+            result.extend(planes.iter().map(|plane| plane[i]));
         }
         Some(result)
     }
+
+    /// Converts the audio to interleaved signed 16-bit PCM.
+    ///
+    /// Samples are clamped to `[-1.0, 1.0]` before scaling, so out-of-range
+    /// values saturate instead of wrapping. Positive samples are scaled by
+    /// 32767 and negative samples by 32768, mapping `1.0` to `i16::MAX` and
+    /// `-1.0` to `i16::MIN`. NaN samples become `0`.
+    ///
+    /// The result holds `samples_per_channel() * channels()` values ordered by
+    /// sample index, i.e. `L0 R0 L1 R1 ...` for stereo, as expected by WAV files.
+    ///
+    /// Returns `None` under the same conditions as [`audio_clipped()`](MediaFrame::audio_clipped).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(pcm) = frame.audio_to_i16_interleaved() {
+    ///     let bytes: Vec<u8> = pcm.iter().flat_map(|s| s.to_le_bytes()).collect();
+    /// }
+    /// # }
+    /// ```
+    pub fn audio_to_i16_interleaved(&self) -> Option<Vec<i16>> {
+        if self.frame_type() != FrameType::AUDIO {
+            return None;
+        }
+
+        let planes = self.as_f32_planar()?;
+        let samples_per_channel = planes.first().map_or(0, |plane| plane.len());
+        let mut result = Vec::with_capacity(samples_per_channel * planes.len());
+        for i in 0..samples_per_channel {
+            result.extend(planes.iter().map(|plane| f32_to_i16(plane[i])));
+        }
+        Some(result)
+    }
+}
+
+/// Converts a floating point sample to 16-bit PCM, saturating at full scale.
+fn f32_to_i16(sample: f32) -> i16 {
+    let sample = sample.clamp(-1.0, 1.0);
+    // `as` maps NaN to 0; the clamp keeps the product within i16 range
+    if sample < 0.0 {
+        (sample * 32768.0) as i16
+    } else {
+        (sample * 32767.0) as i16
+    }
+}
+
+/// One sample per channel at a single sample index.
+///
+/// Produced by [`AudioFrames`]. Dereferences to a slice with one `f32` per channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleFrame {
+    samples: [f32; MAX_CHANNELS],
+    channels: usize,
 }
+
+impl Deref for SampleFrame {
+    type Target = [f32];
+
+    fn deref(&self) -> &[f32] {
+        &self.samples[..self.channels]
+    }
+}
+
+/// Iterator over the samples of an audio frame, see [`MediaFrame::audio_frames()`].
+#[derive(Debug, Clone)]
+pub struct AudioFrames<'a> {
+    planes: Vec<&'a [f32]>,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for AudioFrames<'_> {
+    type Item = SampleFrame;
+
+    fn next(&mut self) -> Option<SampleFrame> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let mut frame = SampleFrame {
+            samples: [0.0; MAX_CHANNELS],
+            channels: self.planes.len(),
+        };
+        for (sample, plane) in frame.samples.iter_mut().zip(&self.planes) {
+            *sample = plane[self.index];
+        }
+        self.index += 1;
+
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for AudioFrames<'_> {}
@@ -4,9 +4,15 @@ mod audio;
 mod metadata;
 mod video;
 
-use crate::types::{Codec, FrameType};
+pub(crate) use audio::MAX_CHANNELS;
+pub use audio::{AudioFrames, SampleFrame};
+pub(crate) use video::FIELD_ORDER_PREFIX;
+
+use crate::time::{OMT_TIME_BASE, duration_from_ticks};
+use crate::types::{Codec, ColorSpace, FrameType};
 use std::marker::PhantomData;
 use std::slice;
+use std::time::Duration;
 
 /// A media frame containing video, audio, or metadata.
 ///
@@ -39,6 +45,8 @@ pub struct MediaFrame<'a> {
     _marker: PhantomData<&'a ()>,
     // Tracks whether this frame owns its data (true for cloned frames)
     owns_data: bool,
+    // Color space of a built frame, which `ffi` cannot hold for BT.2020
+    color_space: Option<ColorSpace>,
 }
 
 // Common methods available for all frame types
@@ -58,6 +66,7 @@ impl<'a> MediaFrame<'a> {
                 ffi: unsafe { *ptr },
                 _marker: PhantomData,
                 owns_data: false, // Borrowed from C library
+                color_space: None,
             })
         }
     }
@@ -75,9 +84,17 @@ impl<'a> MediaFrame<'a> {
             ffi,
             _marker: PhantomData,
             owns_data: false, // Borrowed from OwnedMediaFrame
+            color_space: None,
         }
     }
 
+    /// Sets the color space reported by [`color_space()`](Self::color_space)
+    /// and used by the conversions, including values libomt lacks.
+    pub(crate) fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = Some(color_space);
+        self
+    }
+
     /// Returns a reference to the underlying FFI structure.
     pub(crate) fn as_ffi(&self) -> &omt_sys::OMTMediaFrame {
         &self.ffi
@@ -88,6 +105,39 @@ impl<'a> MediaFrame<'a> {
         &mut self.ffi
     }
 
+    /// Returns a pointer to the underlying `OMTMediaFrame` for FFI interop.
+    ///
+    /// This is an escape hatch for passing frames to other C libraries that
+    /// understand the OMT frame layout. Prefer the safe accessors wherever possible.
+    ///
+    /// # Safety
+    ///
+    /// The returned pointer, and the `Data`, `CompressedData` and `FrameMetadata`
+    /// buffers it points to, are only valid while this `MediaFrame` is alive and,
+    /// for received frames, until the next receive call on the receiver or sender
+    /// that produced it. The caller must not use the pointer beyond that point and
+    /// must not write through it.
+    pub unsafe fn as_raw_ffi(&self) -> *const omt_sys::OMTMediaFrame {
+        &self.ffi
+    }
+
+    /// Returns a mutable pointer to the underlying `OMTMediaFrame` for FFI interop.
+    ///
+    /// Intended for frames that own their data, i.e. frames created by
+    /// [`clone()`](Clone::clone), where C code may need to modify the frame in place.
+    ///
+    /// # Safety
+    ///
+    /// In addition to the contract of [`as_raw_ffi()`](MediaFrame::as_raw_ffi):
+    ///
+    /// - The `Data`, `CompressedData` and `FrameMetadata` pointers and their
+    ///   lengths must not be changed, as they are freed when an owning frame is dropped.
+    /// - Buffer contents may only be written if this frame owns its data. Frames
+    ///   borrowed from a receiver or an `OwnedMediaFrame` must be treated as read-only.
+    pub unsafe fn as_raw_ffi_mut(&mut self) -> *mut omt_sys::OMTMediaFrame {
+        &mut self.ffi
+    }
+
     /// Returns the frame type.
     pub fn frame_type(&self) -> FrameType {
         FrameType::from_ffi(self.ffi.Type).unwrap_or(FrameType::NONE)
@@ -100,6 +150,44 @@ impl<'a> MediaFrame<'a> {
         self.ffi.Timestamp
     }
 
+    /// Returns the timestamp in seconds.
+    ///
+    /// Returns `None` for the `-1` auto-generated timestamp sentinel (or any
+    /// other negative value).
+    pub fn timestamp_seconds(&self) -> Option<f64> {
+        if self.ffi.Timestamp < 0 {
+            None
+        } else {
+            Some(self.ffi.Timestamp as f64 / OMT_TIME_BASE as f64)
+        }
+    }
+
+    /// Returns the timestamp as a [`Duration`].
+    ///
+    /// Returns `None` for the `-1` auto-generated timestamp sentinel (or any
+    /// other negative value). Use [`ticks_from_duration()`](crate::ticks_from_duration)
+    /// for the reverse conversion when sending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::MetadataFrameBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let frame = MetadataFrameBuilder::new()
+    ///     .metadata("<test/>")
+    ///     .timestamp(omt::ticks_from_duration(Duration::from_millis(1500)))
+    ///     .build()?;
+    /// assert_eq!(
+    ///     frame.as_media_frame().timestamp_duration(),
+    ///     Some(Duration::from_millis(1500))
+    /// );
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn timestamp_duration(&self) -> Option<Duration> {
+        duration_from_ticks(self.ffi.Timestamp)
+    }
+
     /// Returns the codec.
     pub fn codec(&self) -> Option<Codec> {
         Codec::from_ffi(self.ffi.Codec)
@@ -138,6 +226,88 @@ impl<'a> MediaFrame<'a> {
         }
     }
 
+    /// Returns the total size of the buffers this frame references, in bytes.
+    ///
+    /// This is the sum of [`data()`](Self::data),
+    /// [`compressed_data()`](Self::compressed_data) and the per-frame metadata
+    /// including its null terminator, i.e. roughly what a
+    /// [`clone()`](Clone::clone) would copy.
+    pub fn byte_size(&self) -> usize {
+        let metadata = if self.ffi.FrameMetadata.is_null() {
+            0
+        } else {
+            usize::try_from(self.ffi.FrameMetadataLength).unwrap_or(0)
+        };
+        self.data().len() + self.compressed_data().len() + metadata
+    }
+
+    /// Returns the data length the frame's geometry calls for.
+    ///
+    /// For uncompressed video, this is [`Codec::expected_data_len()`] for the
+    /// frame's codec, width, height and stride. For audio, it is
+    /// `channels * samples_per_channel * 4` bytes of 32-bit float samples.
+    ///
+    /// Returns `None` for VMX1 video, metadata frames, unknown codecs and
+    /// invalid geometry such as zero dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, VideoFrameBuilder};
+    ///
+    /// let frame = VideoFrameBuilder::with_format(Codec::Bgra, 4, 2)
+    ///     .data(vec![0; 4 * 2 * 4])
+    ///     .build()?;
+    /// let frame = frame.as_media_frame();
+    /// assert_eq!(frame.expected_data_len(), Some(frame.data().len()));
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn expected_data_len(&self) -> Option<usize> {
+        match self.frame_type() {
+            FrameType::VIDEO => {
+                let codec = self.codec()?;
+                let width = usize::try_from(self.ffi.Width).ok()?;
+                let height = usize::try_from(self.ffi.Height).ok()?;
+                let stride = usize::try_from(self.ffi.Stride).ok()?;
+                codec.expected_data_len(width, height, stride)
+            }
+            FrameType::AUDIO => {
+                let channels = usize::try_from(self.ffi.Channels).ok()?;
+                let samples = usize::try_from(self.ffi.SamplesPerChannel).ok()?;
+                channels
+                    .checked_mul(samples)?
+                    .checked_mul(4)
+                    .filter(|&len| len > 0)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks whether the data buffer is large enough for the advertised geometry.
+    ///
+    /// For uncompressed video, the length of [`data()`](Self::data) is compared
+    /// against [`Codec::expected_data_len()`] for the frame's width, height and
+    /// stride. For audio, it is compared against `channels * samples_per_channel * 4`
+    /// bytes of 32-bit float samples. Trailing padding is accepted.
+    ///
+    /// Returns `Some(false)` if the buffer is too short or the geometry itself
+    /// is invalid (e.g. zero dimensions), and `None` if the size is not determined
+    /// by the geometry: for VMX1 video, metadata frames, and unknown codecs.
+    pub fn data_matches_geometry(&self) -> Option<bool> {
+        match self.frame_type() {
+            FrameType::VIDEO => {
+                let codec = self.codec()?;
+                if codec == Codec::Vmx1 || codec == Codec::Fpa1 {
+                    return None;
+                }
+            }
+            FrameType::AUDIO => {}
+            _ => return None,
+        }
+        let expected = self.expected_data_len();
+        Some(expected.is_some_and(|expected| self.data().len() >= expected))
+    }
+
     /// Returns the per-frame metadata as a UTF-8 string if available.
     ///
     /// Returns an empty string if no metadata is present.
@@ -159,6 +329,21 @@ impl<'a> MediaFrame<'a> {
             std::str::from_utf8(&bytes[..end]).unwrap_or("")
         }
     }
+
+    /// Returns the sequence number stamped by the sender, if any.
+    ///
+    /// Senders add `<OMTSeq n="..."/>` to the per-frame metadata when
+    /// [`Sender::enable_sequence_numbers()`](crate::Sender::enable_sequence_numbers)
+    /// is enabled. Returns `None` if the frame carries no valid sequence number.
+    pub fn sequence_number(&self) -> Option<u64> {
+        const PREFIX: &str = "<OMTSeq n=\"";
+
+        let metadata = self.frame_metadata();
+        let start = metadata.rfind(PREFIX)? + PREFIX.len();
+        let rest = &metadata[start..];
+        let end = rest.find('"')?;
+        rest[..end].parse().ok()
+    }
 }
 
 impl<'a> Clone for MediaFrame<'a> {
@@ -231,6 +416,7 @@ impl<'a> Clone for MediaFrame<'a> {
             ffi,
             _marker: PhantomData,
             owns_data: true, // Cloned frame owns its data
+            color_space: self.color_space,
         }
     }
 }
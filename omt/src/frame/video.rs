@@ -1,13 +1,49 @@
 //! Video-specific methods for MediaFrame.
 
+use crate::error::ConvertError;
 use crate::frame::MediaFrame;
-use crate::types::{Codec, ColorSpace, VideoFlags};
+use crate::frame_builder::{OwnedMediaFrame, VideoFrameBuilder};
+use crate::types::{
+    AlphaMode, ChromaUpsample, Codec, ColorSpace, FieldOrder, FrameRate, FrameType, PlaneLayout,
+    VideoFlags,
+};
 use crate::video_conversion::{
-    bgra_to_rgb8, bgra_to_rgba8, get_yuv_matrix, get_yuv_range, nv12_to_rgb8, nv12_to_rgba8,
-    p216_to_rgb16, p216_to_rgba16, pa16_to_rgb16, pa16_to_rgba16, uyva_to_rgb8, uyva_to_rgba8,
-    uyvy_to_rgb8, uyvy_to_rgba8, yuy2_to_rgb8, yuy2_to_rgba8, yv12_to_rgb8, yv12_to_rgba8,
+    UYVY_LAYOUT, YUY2_LAYOUT, bgra_to_luma8, bgra_to_nv12, bgra_to_rgb8, bgra_to_rgba8,
+    expected_data_len, fit_within, get_yuv_matrix, get_yuv_range, nv12_to_nv12, nv12_to_rgb8,
+    nv12_to_rgba8, nv12_to_rgba8_linear, nv21_to_nv12, nv21_to_rgb8, nv21_to_rgba8,
+    nv21_to_rgba8_linear, p216_to_luma16, p216_to_nv12, p216_to_rgb8, p216_to_rgb16,
+    p216_to_rgb16_partial, p216_to_rgba8, p216_to_rgba16, pa16_to_alpha16, pa16_to_rgb8,
+    pa16_to_rgb16, pa16_to_rgba8, pa16_to_rgba16, packed_422_to_luma8, packed_422_to_nv12,
+    packed_422_to_rgba8_linear, planar_to_luma8, premultiply_alpha, resize_nearest,
+    unpremultiply_alpha, uyva_to_rgb8, uyva_to_rgba8, uyva_to_rgba8_linear, uyvy_to_rgb8,
+    uyvy_to_rgba8, yuy2_to_rgb8, yuy2_to_rgba8, yv12_to_nv12, yv12_to_rgb8, yv12_to_rgba8,
+    yv12_to_rgba8_linear,
 };
-use rgb::{RGB8, RGB16, RGBA8, RGBA16};
+use rgb::{RGB8, RGB16, RGBA8, RGBA16, bytemuck};
+use std::io::{self, Write};
+
+/// Start of the per-frame metadata element carrying the field order.
+pub(crate) const FIELD_ORDER_PREFIX: &str = "<OMTField order=\"";
+
+/// Codecs that can be converted to RGB8/RGBA8.
+const SUPPORTED_8BIT_CODECS: &[Codec] = &[
+    Codec::Uyvy,
+    Codec::Yuy2,
+    Codec::Nv12,
+    Codec::Nv21,
+    Codec::Yv12,
+    Codec::Bgra,
+    Codec::Uyva,
+    Codec::P216,
+    Codec::Pa16,
+];
+
+/// Codecs that can be converted to RGB16/RGBA16.
+const SUPPORTED_16BIT_CODECS: &[Codec] = &[Codec::P216, Codec::Pa16];
+
+/// Largest black image returned by [`MediaFrame::to_rgba8_or_black()`], in
+/// bytes: one 8K UHD (7680x4320) RGBA8 frame.
+const MAX_BLACK_FALLBACK_LEN: usize = 7680 * 4320 * 4;
 
 impl<'a> MediaFrame<'a> {
     /// Returns the video width in pixels.
@@ -31,6 +67,44 @@ impl<'a> MediaFrame<'a> {
         self.ffi.Stride
     }
 
+    /// Returns the byte offset, stride and dimensions of each plane.
+    ///
+    /// Useful for uploading planar formats such as NV12, YV12 or P216 to the
+    /// GPU plane by plane. Packed formats are described by a single plane.
+    /// The layout is computed from the codec, dimensions and stride only; use
+    /// [`PlaneLayout::total_size()`] to check it against [`data()`](Self::data)
+    /// before slicing.
+    ///
+    /// Returns `None` for non-video frames, compressed VMX1 frames and frames
+    /// whose dimensions or stride are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(layout) = frame.plane_layout()
+    ///     && frame.data().len() >= layout.total_size()
+    /// {
+    ///     for plane in &layout.planes {
+    ///         let bytes = &frame.data()[plane.offset..plane.end()];
+    ///         // Upload `bytes` as a texture of plane.width x plane.height
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn plane_layout(&self) -> Option<PlaneLayout> {
+        if self.frame_type() != FrameType::VIDEO || self.width() <= 0 || self.height() <= 0 {
+            return None;
+        }
+        PlaneLayout::new(
+            self.codec()?,
+            self.width() as usize,
+            self.height() as usize,
+            usize::try_from(self.stride()).ok()?,
+        )
+    }
+
     /// Returns the video flags.
     ///
     /// This method is only meaningful for video frames.
@@ -38,6 +112,66 @@ impl<'a> MediaFrame<'a> {
         VideoFlags::from_ffi(self.ffi.Flags)
     }
 
+    /// Returns `true` if [`VideoFlags::INTERLACED`] is set.
+    pub fn is_interlaced(&self) -> bool {
+        self.flags().contains(VideoFlags::INTERLACED)
+    }
+
+    /// Returns `true` if [`VideoFlags::ALPHA`] is set.
+    pub fn is_alpha(&self) -> bool {
+        self.flags().contains(VideoFlags::ALPHA)
+    }
+
+    /// Returns `true` if [`VideoFlags::PRE_MULTIPLIED`] is set.
+    ///
+    /// The flag is only meaningful together with [`VideoFlags::ALPHA`]; see
+    /// [`alpha_mode()`](MediaFrame::alpha_mode) for the combined interpretation.
+    pub fn is_premultiplied(&self) -> bool {
+        self.flags().contains(VideoFlags::PRE_MULTIPLIED)
+    }
+
+    /// Returns `true` if [`VideoFlags::HIGH_BIT_DEPTH`] is set.
+    pub fn is_high_bit_depth(&self) -> bool {
+        self.flags().contains(VideoFlags::HIGH_BIT_DEPTH)
+    }
+
+    /// Returns how the frame's alpha channel is to be interpreted.
+    ///
+    /// Without [`VideoFlags::ALPHA`] this is [`AlphaMode::None`], regardless of
+    /// [`VideoFlags::PRE_MULTIPLIED`]. With it, the frame is
+    /// [`AlphaMode::Premultiplied`] if `PRE_MULTIPLIED` is also set and
+    /// [`AlphaMode::Straight`] otherwise.
+    pub fn alpha_mode(&self) -> AlphaMode {
+        let flags = self.flags();
+        if !flags.contains(VideoFlags::ALPHA) {
+            AlphaMode::None
+        } else if flags.contains(VideoFlags::PRE_MULTIPLIED) {
+            AlphaMode::Premultiplied
+        } else {
+            AlphaMode::Straight
+        }
+    }
+
+    /// Returns the field dominance of an interlaced frame.
+    ///
+    /// The field order is read from an `<OMTField order="tff"/>` or
+    /// `<OMTField order="bff"/>` element in the per-frame metadata, as written by
+    /// [`OwnedMediaFrame::set_field_order()`].
+    ///
+    /// Returns `None` for progressive frames (without [`VideoFlags::INTERLACED`])
+    /// and for interlaced frames that do not declare a field order.
+    pub fn field_order(&self) -> Option<FieldOrder> {
+        if !self.flags().contains(VideoFlags::INTERLACED) {
+            return None;
+        }
+
+        let metadata = self.frame_metadata();
+        let start = metadata.rfind(FIELD_ORDER_PREFIX)? + FIELD_ORDER_PREFIX.len();
+        let rest = &metadata[start..];
+        let end = rest.find('"')?;
+        FieldOrder::from_attribute(&rest[..end])
+    }
+
     /// Returns the frame rate numerator.
     ///
     /// This method is only meaningful for video frames.
@@ -63,6 +197,14 @@ impl<'a> MediaFrame<'a> {
         }
     }
 
+    /// Returns the frame rate as a typed [`FrameRate`].
+    ///
+    /// Returns `None` if the numerator or denominator is not positive, as is
+    /// the case for audio and metadata frames.
+    pub fn frame_rate_typed(&self) -> Option<FrameRate> {
+        FrameRate::new(self.ffi.FrameRateN, self.ffi.FrameRateD)
+    }
+
     /// Returns the display aspect ratio.
     ///
     /// This method is only meaningful for video frames.
@@ -72,15 +214,99 @@ impl<'a> MediaFrame<'a> {
 
     /// Returns the color space.
     ///
-    /// This method is only meaningful for video frames.
+    /// This method is only meaningful for video frames. Frames built with
+    /// [`ColorSpace::Bt2020`] report it, although it is sent as undefined.
     pub fn color_space(&self) -> Option<ColorSpace> {
-        ColorSpace::from_ffi(self.ffi.ColorSpace)
+        self.color_space
+            .or_else(|| ColorSpace::from_ffi(self.ffi.ColorSpace))
+    }
+
+    /// Returns `true` if [`to_rgb8()`](MediaFrame::to_rgb8) is expected to succeed.
+    ///
+    /// Checks that the codec is supported and that the dimensions, stride and data
+    /// length are consistent, without performing the conversion.
+    pub fn can_convert_rgb8(&self) -> bool {
+        self.validate(SUPPORTED_8BIT_CODECS)
+    }
+
+    /// Returns `true` if [`to_rgba8()`](MediaFrame::to_rgba8) is expected to succeed.
+    ///
+    /// Checks that the codec is supported and that the dimensions, stride and data
+    /// length are consistent, without performing the conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// let rgba_available = frame.can_convert_rgba8();
+    /// // Enable or disable the RGBA8 option in the UI
+    /// # }
+    /// ```
+    pub fn can_convert_rgba8(&self) -> bool {
+        self.validate(SUPPORTED_8BIT_CODECS)
+    }
+
+    /// Returns `true` if [`to_rgb16()`](MediaFrame::to_rgb16) is expected to succeed.
+    ///
+    /// Checks that the codec is supported and that the dimensions, stride and data
+    /// length are consistent, without performing the conversion.
+    pub fn can_convert_rgb16(&self) -> bool {
+        self.validate(SUPPORTED_16BIT_CODECS)
+    }
+
+    /// Returns `true` if [`to_rgba16()`](MediaFrame::to_rgba16) is expected to succeed.
+    ///
+    /// Checks that the codec is supported and that the dimensions, stride and data
+    /// length are consistent, without performing the conversion.
+    pub fn can_convert_rgba16(&self) -> bool {
+        self.validate(SUPPORTED_16BIT_CODECS)
+    }
+
+    /// Checks that the frame uses one of the `supported` codecs and that its
+    /// data is large enough for the codec's layout.
+    fn validate(&self, supported: &[Codec]) -> bool {
+        let Some(codec) = self.codec() else {
+            return false;
+        };
+        if !supported.contains(&codec) || self.width() <= 0 || self.height() <= 0 {
+            return false;
+        }
+
+        expected_data_len(
+            codec,
+            self.width() as usize,
+            self.height() as usize,
+            self.stride().max(0) as usize,
+        )
+        .is_some_and(|len| self.data().len() >= len)
+    }
+
+    /// Checks that a packed 4:2:2 row covers its trailing macropixel.
+    ///
+    /// An odd width still occupies a whole `width.div_ceil(2) * 4` byte pair
+    /// group per row, so a stride of `width * 2` leaves the last pixel short.
+    /// Other codecs always pass.
+    fn packed_422_width_fits(&self, codec: Codec) -> bool {
+        match codec {
+            Codec::Uyvy | Codec::Yuy2 | Codec::Uyva => {
+                let width = self.width().max(0) as usize;
+                width.is_multiple_of(2)
+                    || width
+                        .div_ceil(2)
+                        .checked_mul(4)
+                        .is_some_and(|row| self.stride().max(0) as usize >= row)
+            }
+            _ => true,
+        }
     }
 
     /// Converts the video frame to RGB8 format.
     ///
     /// Returns a vector of RGB8 pixels if the conversion is supported for the frame's codec,
-    /// or `None` if the codec doesn't support conversion to RGB8.
+    /// or `None` if the codec doesn't support conversion to RGB8. A packed 4:2:2
+    /// frame of odd width whose stride does not cover the final pixel pair is
+    /// also rejected with `None`.
     ///
     /// # Examples
     ///
@@ -93,6 +319,11 @@ impl<'a> MediaFrame<'a> {
     /// # }
     /// ```
     pub fn to_rgb8(&self) -> Option<Vec<RGB8>> {
+        let codec = self.codec()?;
+        if !self.packed_422_width_fits(codec) {
+            return None;
+        }
+
         let width = self.width() as usize;
         let height = self.height() as usize;
         let stride = self.stride() as usize;
@@ -102,22 +333,45 @@ impl<'a> MediaFrame<'a> {
         let yuv_range = get_yuv_range(self);
         let yuv_matrix = get_yuv_matrix(self);
 
-        match self.codec()? {
+        match codec {
             Codec::Uyvy => uyvy_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Yuy2 => yuy2_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Nv12 => nv12_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
+            Codec::Nv21 => nv21_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Yv12 => yv12_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Bgra => bgra_to_rgb8(raw_data, width, height, stride),
             Codec::Uyva => uyva_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
-            Codec::P216 | Codec::Pa16 => None,
+            Codec::P216 => p216_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
+            Codec::Pa16 => pa16_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Vmx1 | Codec::Fpa1 => None,
         }
     }
 
+    /// Returns the size in bytes of this frame converted to RGBA8.
+    ///
+    /// This is `width * height * 4`, the length of the byte output of the
+    /// RGBA8 conversions such as [`try_to_rgba8()`](MediaFrame::try_to_rgba8),
+    /// computed without decoding the frame. Useful to size buffers in advance.
+    ///
+    /// Returns `None` if this is not a video frame, the dimensions are not
+    /// positive, or the size overflows `usize`. Whether the codec can actually
+    /// be converted is not checked.
+    pub fn rgba8_output_len(&self) -> Option<usize> {
+        if self.frame_type() != FrameType::VIDEO {
+            return None;
+        }
+
+        let width = usize::try_from(self.width()).ok().filter(|&w| w > 0)?;
+        let height = usize::try_from(self.height()).ok().filter(|&h| h > 0)?;
+        width.checked_mul(height)?.checked_mul(4)
+    }
+
     /// Converts the video frame to RGBA8 format.
     ///
     /// Returns a vector of RGBA8 pixels if the conversion is supported for the frame's codec,
-    /// or `None` if the codec doesn't support conversion to RGBA8.
+    /// or `None` if the codec doesn't support conversion to RGBA8. A packed 4:2:2
+    /// frame of odd width whose stride does not cover the final pixel pair is
+    /// also rejected with `None`.
     ///
     /// # Examples
     ///
@@ -130,6 +384,11 @@ impl<'a> MediaFrame<'a> {
     /// # }
     /// ```
     pub fn to_rgba8(&self) -> Option<Vec<RGBA8>> {
+        let codec = self.codec()?;
+        if !self.packed_422_width_fits(codec) {
+            return None;
+        }
+
         let width = self.width() as usize;
         let height = self.height() as usize;
         let stride = self.stride() as usize;
@@ -139,18 +398,350 @@ impl<'a> MediaFrame<'a> {
         let yuv_range = get_yuv_range(self);
         let yuv_matrix = get_yuv_matrix(self);
 
-        match self.codec()? {
+        match codec {
             Codec::Uyvy => uyvy_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Yuy2 => yuy2_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Nv12 => nv12_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
+            Codec::Nv21 => nv21_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Yv12 => yv12_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Bgra => bgra_to_rgba8(raw_data, width, height, stride),
             Codec::Uyva => uyva_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
+            Codec::P216 => p216_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
+            Codec::Pa16 => pa16_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix),
+            Codec::Vmx1 | Codec::Fpa1 => None,
+        }
+    }
+
+    /// Converts the video frame to RGBA8 using multiple threads.
+    ///
+    /// Requires the `rayon` feature. The frame is split into bands of rows
+    /// that are converted concurrently on the rayon thread pool. The result is
+    /// identical to [`to_rgba8()`](MediaFrame::to_rgba8).
+    ///
+    /// Frames smaller than 720 lines gain little from threading and are
+    /// converted serially, as are codecs whose rows depend on each other
+    /// (NV12, NV21, YV12, P216 and PA16).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(rgba_pixels) = frame.to_rgba8_parallel() {
+    ///     // Process RGBA8 pixels
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn to_rgba8_parallel(&self) -> Option<Vec<RGBA8>> {
+        const MIN_PARALLEL_HEIGHT: i32 = 720;
+
+        if self.height() < MIN_PARALLEL_HEIGHT {
+            return self.to_rgba8();
+        }
+        crate::video_conversion::to_rgba8_parallel(
+            self.codec()?,
+            self.data(),
+            self.width() as usize,
+            self.height() as usize,
+            self.stride() as usize,
+            get_yuv_range(self),
+            get_yuv_matrix(self),
+        )
+        .or_else(|| self.to_rgba8())
+    }
+
+    /// Converts the video frame to RGBA8 bytes, explaining why if it cannot.
+    ///
+    /// Like [`to_rgba8()`](MediaFrame::to_rgba8), but distinguishes the failure
+    /// cases instead of returning `None`. The result holds 4 bytes per pixel in
+    /// R, G, B, A order without row padding.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConvertError::CompressedOnly`] if the frame was received with
+    ///   [`ReceiveFlags::COMPRESSED_ONLY`](crate::ReceiveFlags::COMPRESSED_ONLY)
+    ///   and carries no decoded data
+    /// - [`ConvertError::UnsupportedCodec`] if this is not a video frame or its
+    ///   codec cannot be converted to RGBA8
+    /// - [`ConvertError::InvalidGeometry`] if the data is too short for the
+    ///   frame's dimensions and stride
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{ConvertError, MediaFrame};
+    /// # fn example(frame: &MediaFrame) {
+    /// match frame.try_to_rgba8() {
+    ///     Ok(pixels) => { /* Display the pixels */ }
+    ///     Err(ConvertError::CompressedOnly) => eprintln!("Decode the VMX1 data first"),
+    ///     Err(e) => eprintln!("Cannot display frame: {}", e),
+    /// }
+    /// # }
+    /// ```
+    pub fn try_to_rgba8(&self) -> std::result::Result<Vec<u8>, ConvertError> {
+        if self.frame_type() != FrameType::VIDEO {
+            return Err(ConvertError::UnsupportedCodec);
+        }
+        if self.data().is_empty() && !self.compressed_data().is_empty() {
+            return Err(ConvertError::CompressedOnly);
+        }
+
+        let codec = self.codec().ok_or(ConvertError::UnsupportedCodec)?;
+        if !SUPPORTED_8BIT_CODECS.contains(&codec) {
+            return Err(ConvertError::UnsupportedCodec);
+        }
+        if self.data_matches_geometry() != Some(true) {
+            return Err(ConvertError::InvalidGeometry);
+        }
+
+        let pixels = self.to_rgba8().ok_or(ConvertError::InvalidGeometry)?;
+        Ok(bytemuck::cast_slice(&pixels).to_vec())
+    }
+
+    /// Converts the video frame to RGBA8 format with the given alpha association.
+    ///
+    /// The frame's own [`alpha_mode()`](MediaFrame::alpha_mode) is converted to
+    /// `output`:
+    ///
+    /// - [`AlphaMode::Straight`]: color is divided by alpha if the frame is
+    ///   premultiplied. This is what most image libraries, including `image`, expect.
+    /// - [`AlphaMode::Premultiplied`]: color is multiplied by alpha if the frame
+    ///   has straight alpha, as expected by many compositors.
+    /// - [`AlphaMode::None`]: the image is flattened onto black and made opaque.
+    ///
+    /// Frames without an alpha channel are returned fully opaque in all modes.
+    /// Returns `None` under the same conditions as [`to_rgba8()`](MediaFrame::to_rgba8).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{AlphaMode, MediaFrame};
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(pixels) = frame.to_rgba8_alpha(AlphaMode::Premultiplied) {
+    ///     // Blend with `dst = src + dst * (1 - src_alpha)`
+    /// }
+    /// # }
+    /// ```
+    pub fn to_rgba8_alpha(&self, output: AlphaMode) -> Option<Vec<RGBA8>> {
+        let mut pixels = self.to_rgba8()?;
+        let source = self.alpha_mode();
+
+        if source == AlphaMode::None {
+            pixels.iter_mut().for_each(|pixel| pixel.a = 255);
+            return Some(pixels);
+        }
+
+        match (source, output) {
+            (AlphaMode::Premultiplied, AlphaMode::Straight) => unpremultiply_alpha(&mut pixels),
+            (AlphaMode::Straight, AlphaMode::Premultiplied | AlphaMode::None) => {
+                premultiply_alpha(&mut pixels)
+            }
+            _ => {}
+        }
+        if output == AlphaMode::None {
+            pixels.iter_mut().for_each(|pixel| pixel.a = 255);
+        }
+        Some(pixels)
+    }
+
+    /// Converts the video frame to RGBA8 format using the given chroma upsampling mode.
+    ///
+    /// With [`ChromaUpsample::Nearest`] this is identical to [`to_rgba8()`](MediaFrame::to_rgba8).
+    /// With [`ChromaUpsample::Linear`], chroma of 4:2:2 (UYVY, YUY2, UYVA) and 4:2:0
+    /// (NV12, NV21, YV12) sources is linearly interpolated between samples before the
+    /// YUV to RGB conversion, avoiding blocky color on sharp edges. BGRA frames are
+    /// converted as usual since they carry full resolution color.
+    ///
+    /// Returns `None` if the codec doesn't support conversion to RGBA8.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{ChromaUpsample, MediaFrame};
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(rgba_pixels) = frame.to_rgba8_with_chroma(ChromaUpsample::Linear) {
+    ///     // Process RGBA8 pixels with smooth chroma
+    /// }
+    /// # }
+    /// ```
+    pub fn to_rgba8_with_chroma(&self, mode: ChromaUpsample) -> Option<Vec<RGBA8>> {
+        if mode == ChromaUpsample::Nearest {
+            return self.to_rgba8();
+        }
+
+        let codec = self.codec()?;
+        if !self.packed_422_width_fits(codec) {
+            return None;
+        }
+
+        let width = usize::try_from(self.width()).ok()?;
+        let height = usize::try_from(self.height()).ok()?;
+        let stride = usize::try_from(self.stride()).ok()?;
+
+        let raw_data = self.data();
+
+        let yuv_range = get_yuv_range(self);
+        let yuv_matrix = get_yuv_matrix(self);
+
+        match codec {
+            Codec::Uyvy => packed_422_to_rgba8_linear(
+                raw_data,
+                width,
+                height,
+                stride,
+                UYVY_LAYOUT,
+                yuv_range,
+                yuv_matrix,
+            ),
+            Codec::Yuy2 => packed_422_to_rgba8_linear(
+                raw_data,
+                width,
+                height,
+                stride,
+                YUY2_LAYOUT,
+                yuv_range,
+                yuv_matrix,
+            ),
+            Codec::Nv12 => {
+                nv12_to_rgba8_linear(raw_data, width, height, stride, yuv_range, yuv_matrix)
+            }
+            Codec::Nv21 => {
+                nv21_to_rgba8_linear(raw_data, width, height, stride, yuv_range, yuv_matrix)
+            }
+            Codec::Yv12 => {
+                yv12_to_rgba8_linear(raw_data, width, height, stride, yuv_range, yuv_matrix)
+            }
+            Codec::Bgra => bgra_to_rgba8(raw_data, width, height, stride),
+            Codec::Uyva => {
+                uyva_to_rgba8_linear(raw_data, width, height, stride, yuv_range, yuv_matrix)
+            }
             Codec::P216 | Codec::Pa16 => None,
             Codec::Vmx1 | Codec::Fpa1 => None,
         }
     }
 
+    /// Converts the video frame to RGBA8 bytes with rows spaced `out_stride` bytes apart.
+    ///
+    /// Each row of `width * 4` converted bytes starts at a multiple of `out_stride`
+    /// and the remainder of the row is zero-filled, matching buffers that require a
+    /// particular row pitch, e.g. for in-place upload into a texture. The result is
+    /// `out_stride * height` bytes long.
+    ///
+    /// Returns `None` if `out_stride` is less than `width * 4` or the conversion
+    /// fails (see [`to_rgba8()`](MediaFrame::to_rgba8)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// // Rows aligned to 256 bytes
+    /// let stride = (frame.width() as u32 * 4).next_multiple_of(256);
+    /// if let Some(pixels) = frame.to_rgba8_with_stride(stride) {
+    ///     assert_eq!(pixels.len(), stride as usize * frame.height() as usize);
+    /// }
+    /// # }
+    /// ```
+    pub fn to_rgba8_with_stride(&self, out_stride: u32) -> Option<Vec<u8>> {
+        let row_bytes = usize::try_from(self.width()).ok()?.checked_mul(4)?;
+        let out_stride = out_stride as usize;
+        if out_stride < row_bytes || row_bytes == 0 {
+            return None;
+        }
+
+        let pixels = self.to_rgba8()?;
+        let tight: &[u8] = bytemuck::cast_slice(&pixels);
+        let mut result = vec![0u8; out_stride * (tight.len() / row_bytes)];
+        for (dst, src) in result
+            .chunks_exact_mut(out_stride)
+            .zip(tight.chunks_exact(row_bytes))
+        {
+            dst[..row_bytes].copy_from_slice(src);
+        }
+        Some(result)
+    }
+
+    /// Converts the video frame to RGBA8 bytes, falling back to a black image.
+    ///
+    /// Returns `(width, height, pixels)` with `pixels` holding `width * height`
+    /// RGBA8 pixels as bytes. If [`to_rgba8()`](MediaFrame::to_rgba8) succeeds,
+    /// these are the converted pixels. Otherwise, e.g. for compressed VMX1 frames
+    /// or malformed data, an opaque black image of the frame's dimensions is
+    /// returned. Non-video frames, frames without valid dimensions and frames
+    /// larger than 8K UHD (7680x4320 pixels) produce a single black pixel, so
+    /// implausible dimensions from a received frame cannot force a huge
+    /// allocation.
+    ///
+    /// This suits display code that always needs something to render.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// let (width, height, pixels) = frame.to_rgba8_or_black();
+    /// assert_eq!(pixels.len(), width as usize * height as usize * 4);
+    /// # }
+    /// ```
+    pub fn to_rgba8_or_black(&self) -> (u32, u32, Vec<u8>) {
+        const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+        // Also covers non-video frames and invalid dimensions
+        let Some(len) = self
+            .rgba8_output_len()
+            .filter(|&len| len <= MAX_BLACK_FALLBACK_LEN)
+        else {
+            return (1, 1, BLACK.to_vec());
+        };
+
+        let width = self.width() as u32;
+        let height = self.height() as u32;
+        match self.to_rgba8() {
+            Some(pixels) => (width, height, bytemuck::cast_slice(&pixels).to_vec()),
+            None => (width, height, BLACK.repeat(len / BLACK.len())),
+        }
+    }
+
+    /// Converts the video frame to RGBA8 bytes no larger than `max_width` x `max_height`.
+    ///
+    /// Frames within the limit are converted at full resolution. Larger frames are
+    /// downscaled with nearest neighbor sampling to the largest size that fits the
+    /// limit while preserving the aspect ratio.
+    ///
+    /// Returns `(width, height, pixels)` with the actual output size, or `None` if
+    /// [`to_rgba8()`](MediaFrame::to_rgba8) fails or a limit is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// // Never hand more than 1080p to the UI
+    /// if let Some((width, height, pixels)) = frame.to_rgba8_capped(1920, 1080) {
+    ///     assert!(width <= 1920 && height <= 1080);
+    /// }
+    /// # }
+    /// ```
+    pub fn to_rgba8_capped(&self, max_width: u32, max_height: u32) -> Option<(u32, u32, Vec<u8>)> {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let (out_width, out_height) =
+            fit_within(width, height, max_width as usize, max_height as usize)?;
+
+        let pixels = self.to_rgba8()?;
+        let pixels = if (out_width, out_height) == (width, height) {
+            pixels
+        } else {
+            resize_nearest(&pixels, width, height, out_width, out_height)?
+        };
+        Some((
+            out_width as u32,
+            out_height as u32,
+            bytemuck::cast_slice(&pixels).to_vec(),
+        ))
+    }
+
     /// Converts the video frame to RGB16 format (16-bit per channel).
     ///
     /// Returns a vector of RGB16 pixels if the conversion is supported for the frame's codec,
@@ -179,7 +770,8 @@ impl<'a> MediaFrame<'a> {
         let yuv_matrix = get_yuv_matrix(self);
 
         match self.codec()? {
-            Codec::Uyvy | Codec::Yuy2 | Codec::Nv12 | Codec::Yv12 | Codec::Bgra => None,
+            Codec::Uyvy | Codec::Yuy2 | Codec::Nv12 | Codec::Nv21 | Codec::Yv12 => None,
+            Codec::Bgra => None,
             Codec::Uyva => None,
             Codec::P216 => p216_to_rgb16(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Pa16 => pa16_to_rgb16(raw_data, width, height, stride, yuv_range, yuv_matrix),
@@ -187,6 +779,34 @@ impl<'a> MediaFrame<'a> {
         }
     }
 
+    /// Converts a possibly truncated P216 or PA16 frame to RGB16, decoding as many rows as possible.
+    ///
+    /// This is for error recovery only, e.g. to show a partially received frame
+    /// instead of nothing when [`to_rgb16()`](MediaFrame::to_rgb16) returns `None`.
+    /// Rows whose luma or chroma data is missing are black; since the chroma
+    /// plane follows the complete luma plane, nothing is decoded unless the whole
+    /// luma plane is present.
+    ///
+    /// Returns `(rows_decoded, pixels)` with `width * height` pixels, or `None`
+    /// for other codecs or invalid dimensions.
+    pub fn to_rgb16_partial(&self) -> Option<(usize, Vec<RGB16>)> {
+        let width = usize::try_from(self.width()).ok()?;
+        let height = usize::try_from(self.height()).ok()?;
+        let stride = usize::try_from(self.stride()).ok()?;
+
+        match self.codec()? {
+            Codec::P216 | Codec::Pa16 => p216_to_rgb16_partial(
+                self.data(),
+                width,
+                height,
+                stride,
+                get_yuv_range(self),
+                get_yuv_matrix(self),
+            ),
+            _ => None,
+        }
+    }
+
     /// Converts the video frame to RGBA16 format (16-bit per channel).
     ///
     /// Returns a vector of RGBA16 pixels if the conversion is supported for the frame's codec,
@@ -215,11 +835,388 @@ impl<'a> MediaFrame<'a> {
         let yuv_matrix = get_yuv_matrix(self);
 
         match self.codec()? {
-            Codec::Uyvy | Codec::Yuy2 | Codec::Nv12 | Codec::Yv12 | Codec::Bgra => None,
+            Codec::Uyvy | Codec::Yuy2 | Codec::Nv12 | Codec::Nv21 | Codec::Yv12 => None,
+            Codec::Bgra => None,
             Codec::Uyva => None,
             Codec::P216 => p216_to_rgba16(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Pa16 => pa16_to_rgba16(raw_data, width, height, stride, yuv_range, yuv_matrix),
             Codec::Vmx1 | Codec::Fpa1 => None,
         }
     }
+
+    /// Converts the video frame to RGB16 format, upscaling 8-bit sources.
+    ///
+    /// Unlike [`to_rgb16()`](MediaFrame::to_rgb16), which only converts codecs that are
+    /// natively 16-bit, this method also accepts 8-bit codecs (UYVY, YUY2, NV12, YV12,
+    /// BGRA, UYVA). These are converted to RGB8 first and each channel is then scaled
+    /// by 257 so that `255` maps to `65535`. No precision is gained by the upscale.
+    ///
+    /// P216 and PA16 frames use the native 16-bit path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(rgb16_pixels) = frame.to_rgb16_upscaled() {
+    ///     // Process RGB16 pixels regardless of source bit depth
+    /// }
+    /// # }
+    /// ```
+    pub fn to_rgb16_upscaled(&self) -> Option<Vec<RGB16>> {
+        match self.codec()? {
+            Codec::P216 | Codec::Pa16 => self.to_rgb16(),
+            _ => Some(
+                self.to_rgb8()?
+                    .into_iter()
+                    .map(|px| RGB16::new(px.r as u16 * 257, px.g as u16 * 257, px.b as u16 * 257))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Extracts the luma (Y) plane as 16-bit values.
+    ///
+    /// For P216 and PA16 the 16-bit Y plane is copied directly. For 8-bit YUV
+    /// codecs (UYVY, YUY2, NV12, YV12, UYVA) the Y plane is copied and each value
+    /// scaled by 257. Y values are not range converted, so limited range sources
+    /// keep their studio range. For BGRA, full range luma is computed using the
+    /// Rec.601/Rec.709 weights matching the frame's color space.
+    ///
+    /// Returns `None` for compressed codecs or if the frame data is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(luma) = frame.to_luma16() {
+    ///     let peak = luma.iter().copied().max().unwrap_or(0);
+    ///     println!("Peak luma: {}", peak);
+    /// }
+    /// # }
+    /// ```
+    pub fn to_luma16(&self) -> Option<Vec<u16>> {
+        match self.codec()? {
+            Codec::P216 | Codec::Pa16 => p216_to_luma16(
+                self.data(),
+                self.width() as usize,
+                self.height() as usize,
+                self.stride() as usize,
+            ),
+            _ => Some(
+                self.to_luma8()?
+                    .into_iter()
+                    .map(|y| y as u16 * 257)
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Extracts the 16-bit alpha plane of a PA16 frame.
+    ///
+    /// Returns `width * height` alpha values decoded from the little-endian
+    /// alpha plane that follows the P216 portion of the frame.
+    ///
+    /// Returns `None` if the frame is not PA16 or the data is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(alpha) = frame.pa16_alpha() {
+    ///     // Feed the key signal to a keyer
+    /// }
+    /// # }
+    /// ```
+    pub fn pa16_alpha(&self) -> Option<Vec<u16>> {
+        if self.codec()? != Codec::Pa16 || self.width() <= 0 || self.height() <= 0 {
+            return None;
+        }
+
+        pa16_to_alpha16(
+            self.data(),
+            self.width() as usize,
+            self.height() as usize,
+            self.stride().max(0) as usize,
+        )
+    }
+
+    /// Converts the video frame to tightly packed NV12, e.g. for hardware encoders.
+    ///
+    /// YUV sources are repacked without a color conversion: 4:2:2 chroma (UYVY,
+    /// YUY2, UYVA, P216, PA16) is subsampled to 4:2:0 by averaging vertically
+    /// adjacent rows and 16-bit samples are truncated to 8 bits. BGRA is converted
+    /// to YUV using the frame's color space and range. Alpha is discarded.
+    ///
+    /// The returned frame has codec NV12 and a stride equal to the width rounded
+    /// up to an even number. Timestamp, frame rate, aspect ratio, color space,
+    /// per-frame metadata and all flags except the alpha flags are preserved.
+    ///
+    /// Returns `None` if the codec is not supported or the data is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) {
+    /// if let Some(nv12) = frame.to_nv12() {
+    ///     // Hand nv12.data() to a hardware encoder
+    /// }
+    /// # }
+    /// ```
+    pub fn to_nv12(&self) -> Option<OwnedMediaFrame> {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let stride = self.stride() as usize;
+
+        let raw_data = self.data();
+
+        let (data, nv12_stride) = match self.codec()? {
+            Codec::Uyvy | Codec::Uyva => {
+                packed_422_to_nv12(raw_data, width, height, stride, UYVY_LAYOUT)
+            }
+            Codec::Yuy2 => packed_422_to_nv12(raw_data, width, height, stride, YUY2_LAYOUT),
+            Codec::Nv12 => nv12_to_nv12(raw_data, width, height, stride),
+            Codec::Nv21 => nv21_to_nv12(raw_data, width, height, stride),
+            Codec::Yv12 => yv12_to_nv12(raw_data, width, height, stride),
+            Codec::Bgra => bgra_to_nv12(
+                raw_data,
+                width,
+                height,
+                stride,
+                get_yuv_range(self),
+                get_yuv_matrix(self),
+            ),
+            Codec::P216 | Codec::Pa16 => p216_to_nv12(raw_data, width, height, stride),
+            Codec::Vmx1 | Codec::Fpa1 => None,
+        }?;
+
+        let alpha_flags = (VideoFlags::ALPHA | VideoFlags::PRE_MULTIPLIED).bits();
+        let mut builder = VideoFrameBuilder::new()
+            .codec(Codec::Nv12)
+            .dimensions(self.width(), self.height())
+            .stride(nv12_stride as i32)
+            .flags(VideoFlags::from_bits(self.flags().bits() & !alpha_flags))
+            .frame_rate(self.frame_rate_numerator(), self.frame_rate_denominator())
+            .aspect_ratio(self.aspect_ratio())
+            .color_space(self.color_space().unwrap_or(ColorSpace::Undefined))
+            .timestamp(self.timestamp())
+            .data(data);
+        if !self.frame_metadata().is_empty() {
+            builder = builder.frame_metadata(self.frame_metadata());
+        }
+
+        builder.build().ok()
+    }
+
+    /// Writes the frame as a binary PPM (P6) image.
+    ///
+    /// The frame is converted with [`to_rgb8()`](MediaFrame::to_rgb8), so alpha is
+    /// discarded. Useful for dumping frames in tests and bug reports without
+    /// pulling in an image crate.
+    ///
+    /// Returns `Ok(false)` without writing anything if the codec cannot be
+    /// converted to RGB8.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) -> std::io::Result<()> {
+    /// let mut file = std::fs::File::create("frame.ppm")?;
+    /// if !frame.write_ppm(&mut file)? {
+    ///     eprintln!("Unsupported codec");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_ppm(&self, w: &mut impl Write) -> io::Result<bool> {
+        let Some(pixels) = self.to_rgb8() else {
+            return Ok(false);
+        };
+
+        write!(w, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        w.write_all(bytemuck::cast_slice(&pixels))?;
+        Ok(true)
+    }
+
+    /// Writes the frame as a PAM (P7) image with an alpha channel.
+    ///
+    /// The frame is converted with [`to_rgba8()`](MediaFrame::to_rgba8) and written
+    /// with the `RGB_ALPHA` tuple type.
+    ///
+    /// Returns `Ok(false)` without writing anything if the codec cannot be
+    /// converted to RGBA8.
+    pub fn write_pam(&self, w: &mut impl Write) -> io::Result<bool> {
+        let Some(pixels) = self.to_rgba8() else {
+            return Ok(false);
+        };
+
+        write!(
+            w,
+            "P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n",
+            self.width(),
+            self.height()
+        )?;
+        w.write_all(bytemuck::cast_slice(&pixels))?;
+        Ok(true)
+    }
+
+    /// Converts the frame to an [`image::RgbImage`], e.g. to save it as PNG.
+    ///
+    /// Requires the `image` feature. The pixels come from
+    /// [`to_rgb8()`](MediaFrame::to_rgb8), so any stride padding is already
+    /// removed and the image has the frame's width and height.
+    ///
+    /// Returns `None` if the codec cannot be converted to RGB8, like the other
+    /// converters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(frame: &MediaFrame) -> image::ImageResult<()> {
+    /// if let Some(image) = frame.to_image_rgb8() {
+    ///     image.save("frame.png")?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn to_image_rgb8(&self) -> Option<image::RgbImage> {
+        let pixels = self.to_rgb8()?;
+        image::RgbImage::from_raw(
+            self.width() as u32,
+            self.height() as u32,
+            bytemuck::cast_slice(&pixels).to_vec(),
+        )
+    }
+
+    /// Converts the frame to an [`image::RgbaImage`], keeping any alpha channel.
+    ///
+    /// Requires the `image` feature. The pixels come from
+    /// [`to_rgba8()`](MediaFrame::to_rgba8) and the image has the frame's
+    /// width and height.
+    ///
+    /// Returns `None` if the codec cannot be converted to RGBA8, like the other
+    /// converters.
+    #[cfg(feature = "image")]
+    pub fn to_image_rgba8(&self) -> Option<image::RgbaImage> {
+        let pixels = self.to_rgba8()?;
+        self.rgba_image(&pixels)
+    }
+
+    /// Converts the frame to an [`image::RgbaImage`] with the requested alpha
+    /// representation.
+    ///
+    /// Requires the `image` feature. The pixels come from
+    /// [`to_rgba8_alpha()`](MediaFrame::to_rgba8_alpha), so
+    /// [`AlphaMode::Straight`] suits PNG output while
+    /// [`AlphaMode::Premultiplied`] suits compositing.
+    ///
+    /// Returns `None` under the same conditions as
+    /// [`to_image_rgba8()`](MediaFrame::to_image_rgba8).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{AlphaMode, MediaFrame};
+    /// # fn example(frame: &MediaFrame) -> image::ImageResult<()> {
+    /// if let Some(image) = frame.to_image_rgba8_with_alpha(AlphaMode::Straight) {
+    ///     image.save("frame.png")?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn to_image_rgba8_with_alpha(&self, alpha: AlphaMode) -> Option<image::RgbaImage> {
+        let pixels = self.to_rgba8_alpha(alpha)?;
+        self.rgba_image(&pixels)
+    }
+
+    #[cfg(feature = "image")]
+    fn rgba_image(&self, pixels: &[RGBA8]) -> Option<image::RgbaImage> {
+        image::RgbaImage::from_raw(
+            self.width() as u32,
+            self.height() as u32,
+            bytemuck::cast_slice(pixels).to_vec(),
+        )
+    }
+
+    /// Computes the absolute per-channel difference to another frame as RGBA8.
+    ///
+    /// Both frames are converted with [`to_rgba8()`](MediaFrame::to_rgba8) and
+    /// each output pixel holds `|self - other|` for red, green and blue. Alpha is
+    /// always 255 so the result can be viewed directly; identical frames give
+    /// an opaque black image. Useful for spotting encoding artifacts when
+    /// comparing two sources.
+    ///
+    /// Returns `None` if the codecs or dimensions differ, or if either frame
+    /// cannot be converted.
+    pub fn diff_rgba8(&self, other: &MediaFrame<'_>) -> Option<Vec<u8>> {
+        if self.codec() != other.codec()
+            || self.width() != other.width()
+            || self.height() != other.height()
+        {
+            return None;
+        }
+
+        let a = self.to_rgba8()?;
+        let b = other.to_rgba8()?;
+        Some(
+            a.iter()
+                .zip(&b)
+                .flat_map(|(a, b)| [a.r.abs_diff(b.r), a.g.abs_diff(b.g), a.b.abs_diff(b.b), 255])
+                .collect(),
+        )
+    }
+
+    /// Extracts the luma (Y) plane as 8-bit values, e.g. for motion detection.
+    ///
+    /// For 8-bit YUV codecs (UYVY, YUY2, NV12, NV21, YV12, UYVA) the Y plane is
+    /// copied directly, without any matrix multiplication. For P216 and PA16 the
+    /// 16-bit Y samples are reduced to their most significant byte. Y values are
+    /// not range converted, so limited range sources keep their studio range.
+    /// For BGRA, full range luma is computed using the Rec.601/Rec.709 weights
+    /// matching the frame's color space.
+    ///
+    /// Returns `width * height` values, or `None` for compressed codecs or if
+    /// the frame data is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::MediaFrame;
+    /// # fn example(previous: &[u8], frame: &MediaFrame) {
+    /// if let Some(luma) = frame.to_luma8() {
+    ///     let changed = luma.iter().zip(previous).filter(|(a, b)| a.abs_diff(**b) > 16).count();
+    ///     println!("{} pixels changed", changed);
+    /// }
+    /// # }
+    /// ```
+    pub fn to_luma8(&self) -> Option<Vec<u8>> {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let stride = self.stride() as usize;
+
+        let raw_data = self.data();
+
+        match self.codec()? {
+            Codec::Uyvy | Codec::Uyva => packed_422_to_luma8(raw_data, width, height, stride, 1),
+            Codec::Yuy2 => packed_422_to_luma8(raw_data, width, height, stride, 0),
+            Codec::Nv12 | Codec::Nv21 | Codec::Yv12 => {
+                planar_to_luma8(raw_data, width, height, stride)
+            }
+            Codec::Bgra => bgra_to_luma8(raw_data, width, height, stride, get_yuv_matrix(self)),
+            Codec::P216 | Codec::Pa16 => Some(
+                p216_to_luma16(raw_data, width, height, stride)?
+                    .into_iter()
+                    .map(|y| (y >> 8) as u8)
+                    .collect(),
+            ),
+            Codec::Vmx1 | Codec::Fpa1 => None,
+        }
+    }
 }
@@ -5,12 +5,20 @@
 //! manage memory allocation.
 
 use crate::error::{Error, Result};
-use crate::frame::MediaFrame;
-use crate::types::{Codec, ColorSpace, FrameType, VideoFlags};
+use crate::frame::{FIELD_ORDER_PREFIX, MAX_CHANNELS, MediaFrame};
+use crate::types::{Codec, ColorRange, ColorSpace, FieldOrder, FrameRate, FrameType, VideoFlags};
+use crate::video_conversion::{rgb8_to_uyvy, rgba8_to_bgra, rgba8_to_uyva, yuv_matrix, yuv_range};
 use std::ffi::CString;
 
+/// Largest per-channel sample count of [`OwnedMediaFrame::silent_audio()`],
+/// one second at 192 kHz.
+const MAX_SILENT_SAMPLES: usize = 192_000;
+
 /// Builder for creating video frames.
 ///
+/// Data shorter than the codec geometry requires is rejected by
+/// [`build`](Self::build), since libomt reads the full frame when sending.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -27,6 +35,20 @@ use std::ffi::CString;
 ///     .build()?;
 /// # Ok::<(), omt::Error>(())
 /// ```
+///
+/// The same frame using [`with_format`](Self::with_format) and the defaults
+/// for stride and aspect ratio:
+///
+/// ```no_run
+/// use omt::{Codec, ColorSpace, FrameRate, VideoFrameBuilder};
+///
+/// let frame = VideoFrameBuilder::with_format(Codec::Uyvy, 1920, 1080)
+///     .frame_rate_typed(FrameRate::fps_30())
+///     .color_space(ColorSpace::Bt709)
+///     .data(vec![0u8; 1920 * 1080 * 2])
+///     .build()?;
+/// # Ok::<(), omt::Error>(())
+/// ```
 #[derive(Debug)]
 pub struct VideoFrameBuilder {
     codec: Option<Codec>,
@@ -36,11 +58,13 @@ pub struct VideoFrameBuilder {
     flags: VideoFlags,
     frame_rate_n: i32,
     frame_rate_d: i32,
-    aspect_ratio: f32,
+    aspect_ratio: Option<f32>,
     color_space: ColorSpace,
     timestamp: i64,
     data: Vec<u8>,
     frame_metadata: Option<String>,
+    allow_short_data: bool,
+    allow_local_codecs: bool,
 }
 
 impl VideoFrameBuilder {
@@ -54,14 +78,23 @@ impl VideoFrameBuilder {
             flags: VideoFlags::NONE,
             frame_rate_n: 30,
             frame_rate_d: 1,
-            aspect_ratio: 16.0 / 9.0,
+            aspect_ratio: None,
             color_space: ColorSpace::Undefined,
             timestamp: -1,
             data: Vec::new(),
             frame_metadata: None,
+            allow_short_data: false,
+            allow_local_codecs: false,
         }
     }
 
+    /// Creates a video frame builder with codec and dimensions already set.
+    ///
+    /// Equivalent to `VideoFrameBuilder::new().codec(codec).dimensions(width, height)`.
+    pub fn with_format(codec: Codec, width: i32, height: i32) -> Self {
+        Self::new().codec(codec).dimensions(width, height)
+    }
+
     /// Sets the video codec.
     ///
     /// Supported codecs for sending:
@@ -89,9 +122,8 @@ impl VideoFrameBuilder {
     /// Sets the stride (row pitch) in bytes.
     ///
     /// If not set, will be automatically calculated based on codec and width:
-    /// - UYVY/YUY2: width * 2
+    /// - UYVY/YUY2/UYVA: width * 2, rounded up to a whole pixel pair
     /// - BGRA: width * 4
-    /// - UYVA: width * 2
     /// - Planar formats: width
     pub fn stride(mut self, stride: i32) -> Self {
         self.stride = Some(stride);
@@ -113,9 +145,16 @@ impl VideoFrameBuilder {
         self
     }
 
+    /// Sets the frame rate from a [`FrameRate`].
+    pub fn frame_rate_typed(self, frame_rate: FrameRate) -> Self {
+        self.frame_rate(frame_rate.numerator(), frame_rate.denominator())
+    }
+
     /// Sets the display aspect ratio (e.g., 16.0/9.0 for 16:9).
+    ///
+    /// If not set, defaults to `width / height` (square pixels).
     pub fn aspect_ratio(mut self, ratio: f32) -> Self {
-        self.aspect_ratio = ratio;
+        self.aspect_ratio = Some(ratio);
         self
     }
 
@@ -142,8 +181,45 @@ impl VideoFrameBuilder {
     }
 
     /// Sets per-frame metadata (UTF-8 string, max 65536 bytes).
-    pub fn frame_metadata(mut self, metadata: String) -> Self {
-        self.frame_metadata = Some(metadata);
+    ///
+    /// The metadata is stored null-terminated and attached to the frame's
+    /// `FrameMetadata` field when the frame is built. Interior null bytes are
+    /// rejected by [`build`](Self::build).
+    pub fn frame_metadata(mut self, metadata: impl AsRef<str>) -> Self {
+        self.frame_metadata = Some(metadata.as_ref().to_string());
+        self
+    }
+
+    /// Skips the stride and data length checks in [`build`](Self::build).
+    ///
+    /// Useful for simulating truncated frames, e.g. to exercise
+    /// [`MediaFrame::data_matches_geometry`].
+    /// [`Sender::send()`](crate::Sender::send) rejects such frames.
+    pub fn allow_short_data(mut self, allow: bool) -> Self {
+        self.allow_short_data = allow;
+        self
+    }
+
+    /// Accepts codecs that libomt does not support, such as [`Codec::Nv21`].
+    ///
+    /// Such frames can be converted locally, e.g. with
+    /// [`MediaFrame::to_rgba8()`], but [`Sender::send()`](crate::Sender::send)
+    /// rejects them. The stride and data length are still checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, VideoFrameBuilder};
+    ///
+    /// let frame = VideoFrameBuilder::with_format(Codec::Nv21, 4, 2)
+    ///     .data(vec![0x80; 4 * 2 * 3 / 2])
+    ///     .allow_local_codecs(true)
+    ///     .build()?;
+    /// assert!(frame.as_media_frame().to_rgba8().is_some());
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn allow_local_codecs(mut self, allow: bool) -> Self {
+        self.allow_local_codecs = allow;
         self
     }
 
@@ -153,15 +229,27 @@ impl VideoFrameBuilder {
     ///
     /// Returns an error if:
     /// - No codec is specified
+    /// - The codec is not supported by libomt, see [`Codec::is_runtime_supported()`],
+    ///   unless [`allow_local_codecs()`](Self::allow_local_codecs) is set
     /// - Width or height is zero
     /// - Data is empty
-    /// - Frame metadata exceeds 65536 bytes
+    /// - The stride is too small for the width of an uncompressed codec
+    /// - Data is shorter than the stride, height and codec require
+    ///   ([`Error::BufferTooSmall`])
+    /// - Frame metadata exceeds 65536 bytes or contains a null byte ([`Error::NulError`])
     pub fn build(self) -> Result<OwnedMediaFrame> {
         let codec = self.codec.ok_or(Error::InvalidParameter {
             parameter: "codec".to_string(),
             reason: "codec must be specified".to_string(),
         })?;
 
+        if !self.allow_local_codecs && !codec.is_runtime_supported() {
+            return Err(Error::InvalidParameter {
+                parameter: "codec".to_string(),
+                reason: format!("{codec} is not supported by libomt"),
+            });
+        }
+
         if self.width <= 0 || self.height <= 0 {
             return Err(Error::InvalidParameter {
                 parameter: "dimensions".to_string(),
@@ -177,24 +265,34 @@ impl VideoFrameBuilder {
         }
 
         // Calculate stride if not specified
-        let stride = self.stride.unwrap_or_else(|| match codec {
-            Codec::Uyvy | Codec::Yuy2 | Codec::Uyva => self.width * 2,
-            Codec::Bgra => self.width * 4,
-            Codec::P216 | Codec::Pa16 => self.width * 2,
-            _ => self.width,
-        });
+        let stride = self
+            .stride
+            .unwrap_or_else(|| default_stride(codec, self.width));
 
-        let frame_metadata_cstring = if let Some(ref metadata) = self.frame_metadata {
-            if metadata.len() > 65535 {
+        // Only uncompressed video has a data length that follows from the geometry
+        if !self.allow_short_data && !matches!(codec, Codec::Vmx1 | Codec::Fpa1) {
+            let required = codec
+                .expected_data_len(
+                    self.width as usize,
+                    self.height as usize,
+                    stride.max(0) as usize,
+                )
+                .ok_or_else(|| Error::InvalidParameter {
+                    parameter: "stride".to_string(),
+                    reason: format!(
+                        "stride {stride} is too small for {codec} at width {}",
+                        self.width
+                    ),
+                })?;
+            if self.data.len() < required {
                 return Err(Error::BufferTooSmall {
-                    required: metadata.len(),
-                    provided: 65536,
+                    required,
+                    provided: self.data.len(),
                 });
             }
-            Some(CString::new(metadata.as_str())?)
-        } else {
-            None
-        };
+        }
+
+        let frame_metadata_cstring = frame_metadata_to_cstring(self.frame_metadata)?;
 
         Ok(OwnedMediaFrame {
             frame_type: FrameType::VIDEO,
@@ -206,17 +304,80 @@ impl VideoFrameBuilder {
             flags: self.flags,
             frame_rate_n: self.frame_rate_n,
             frame_rate_d: self.frame_rate_d,
-            aspect_ratio: self.aspect_ratio,
+            aspect_ratio: self
+                .aspect_ratio
+                .unwrap_or(self.width as f32 / self.height as f32),
             color_space: self.color_space,
             sample_rate: 0,
             channels: 0,
             samples_per_channel: 0,
             data: self.data,
+            compressed_data: Vec::new(),
             frame_metadata: frame_metadata_cstring,
         })
     }
 }
 
+/// Checks that the `parameter` argument holds exactly `width * height`
+/// tightly packed `format` pixels of `bytes_per_pixel` bytes.
+fn check_pixels(
+    width: i32,
+    height: i32,
+    pixels: &[u8],
+    bytes_per_pixel: usize,
+    parameter: &str,
+    format: &str,
+) -> Result<()> {
+    if width <= 0 || height <= 0 {
+        return Err(Error::InvalidParameter {
+            parameter: "dimensions".to_string(),
+            reason: "width and height must be greater than zero".to_string(),
+        });
+    }
+
+    let expected = width as usize * height as usize * bytes_per_pixel;
+    if pixels.len() != expected {
+        return Err(Error::InvalidParameter {
+            parameter: parameter.to_string(),
+            reason: format!(
+                "{width}x{height} {format} requires {expected} bytes, got {}",
+                pixels.len()
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the stride of the first plane for tightly packed rows.
+///
+/// Packed 4:2:2 rows hold whole pixel pairs, so an odd width is rounded up.
+fn default_stride(codec: Codec, width: i32) -> i32 {
+    match codec {
+        Codec::Uyvy | Codec::Yuy2 | Codec::Uyva => (width + 1) / 2 * 4,
+        Codec::Bgra => width * 4,
+        Codec::P216 | Codec::Pa16 => width * 2,
+        _ => width,
+    }
+}
+
+/// Validates per-frame metadata and converts it to a null-terminated string.
+///
+/// The null terminator counts towards the 65536 byte limit imposed by OMT.
+fn frame_metadata_to_cstring(metadata: Option<String>) -> Result<Option<CString>> {
+    match metadata {
+        Some(metadata) => {
+            if metadata.len() > 65535 {
+                return Err(Error::BufferTooSmall {
+                    required: metadata.len(),
+                    provided: 65536,
+                });
+            }
+            Ok(Some(CString::new(metadata)?))
+        }
+        None => Ok(None),
+    }
+}
+
 impl Default for VideoFrameBuilder {
     fn default() -> Self {
         Self::new()
@@ -268,6 +429,96 @@ impl AudioFrameBuilder {
         }
     }
 
+    /// Creates a builder from interleaved 32-bit float samples.
+    ///
+    /// Most capture APIs deliver interleaved audio (`L R L R ...`), while OMT
+    /// expects planar FPA1. The samples are deinterleaved and
+    /// `samples_per_channel` is derived from the slice length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `channels` is not between 1 and 32 or the slice
+    /// length is not a multiple of `channels`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::AudioFrameBuilder;
+    ///
+    /// let frame = AudioFrameBuilder::from_interleaved(48000, 2, &[0.1, -0.1, 0.2, -0.2])?
+    ///     .build()?;
+    /// let planes = frame.as_media_frame().as_f32_planar().unwrap();
+    /// assert_eq!(planes, vec![&[0.1, 0.2][..], &[-0.1, -0.2][..]]);
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn from_interleaved(sample_rate: i32, channels: i32, samples: &[f32]) -> Result<Self> {
+        let channel_count = check_channels(usize::try_from(channels).unwrap_or(0))?;
+        if !samples.len().is_multiple_of(channel_count) {
+            return Err(Error::InvalidParameter {
+                parameter: "samples".to_string(),
+                reason: format!(
+                    "sample count ({}) is not a multiple of {} channels",
+                    samples.len(),
+                    channels
+                ),
+            });
+        }
+        let samples_per_channel = samples.len() / channel_count;
+
+        let mut data = Vec::with_capacity(samples.len() * 4);
+        for channel in 0..channel_count {
+            for sample in samples.iter().skip(channel).step_by(channel_count) {
+                data.extend_from_slice(&sample.to_ne_bytes());
+            }
+        }
+
+        Ok(Self::new()
+            .sample_rate(sample_rate)
+            .channels(channels)
+            .samples_per_channel(samples_per_channel_to_i32(samples_per_channel)?)
+            .data(data))
+    }
+
+    /// Creates a builder from one vector of 32-bit float samples per channel.
+    ///
+    /// The sample rate defaults to 48000 and can be changed with
+    /// [`sample_rate`](Self::sample_rate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no channels or more than 32, or if the
+    /// channel vectors differ in length.
+    pub fn from_planar(planes: Vec<Vec<f32>>) -> Result<Self> {
+        check_channels(planes.len())?;
+        let samples_per_channel = planes[0].len();
+        if let Some((index, plane)) = planes
+            .iter()
+            .enumerate()
+            .find(|(_, plane)| plane.len() != samples_per_channel)
+        {
+            return Err(Error::InvalidParameter {
+                parameter: "planes".to_string(),
+                reason: format!(
+                    "channel {} has {} samples, expected {}",
+                    index,
+                    plane.len(),
+                    samples_per_channel
+                ),
+            });
+        }
+
+        let data = planes
+            .iter()
+            .flatten()
+            .flat_map(|sample| sample.to_ne_bytes())
+            .collect();
+
+        Ok(Self::new()
+            .channels(planes.len() as i32)
+            .samples_per_channel(samples_per_channel_to_i32(samples_per_channel)?)
+            .data(data))
+    }
+
     /// Sets the sample rate (e.g., 48000, 44100).
     pub fn sample_rate(mut self, sample_rate: i32) -> Self {
         self.sample_rate = sample_rate;
@@ -304,8 +555,12 @@ impl AudioFrameBuilder {
     }
 
     /// Sets per-frame metadata (UTF-8 string, max 65536 bytes).
-    pub fn frame_metadata(mut self, metadata: String) -> Self {
-        self.frame_metadata = Some(metadata);
+    ///
+    /// The metadata is stored null-terminated and attached to the frame's
+    /// `FrameMetadata` field when the frame is built. Interior null bytes are
+    /// rejected by [`build`](Self::build).
+    pub fn frame_metadata(mut self, metadata: impl AsRef<str>) -> Self {
+        self.frame_metadata = Some(metadata.as_ref().to_string());
         self
     }
 
@@ -318,7 +573,7 @@ impl AudioFrameBuilder {
     /// - Channels is zero or exceeds 32
     /// - Samples per channel is zero
     /// - Data is empty or size doesn't match samples_per_channel * channels * 4
-    /// - Frame metadata exceeds 65536 bytes
+    /// - Frame metadata exceeds 65536 bytes or contains a null byte ([`Error::NulError`])
     pub fn build(self) -> Result<OwnedMediaFrame> {
         if self.sample_rate <= 0 {
             return Err(Error::InvalidParameter {
@@ -327,12 +582,7 @@ impl AudioFrameBuilder {
             });
         }
 
-        if self.channels <= 0 || self.channels > 32 {
-            return Err(Error::InvalidParameter {
-                parameter: "channels".to_string(),
-                reason: "channels must be between 1 and 32".to_string(),
-            });
-        }
+        check_channels(usize::try_from(self.channels).unwrap_or(0))?;
 
         if self.samples_per_channel <= 0 {
             return Err(Error::InvalidParameter {
@@ -341,7 +591,15 @@ impl AudioFrameBuilder {
             });
         }
 
-        let expected_size = (self.samples_per_channel * self.channels * 4) as usize;
+        // Both counts were validated as positive above; compute in usize so that
+        // large frames cannot overflow i32
+        let expected_size = (self.samples_per_channel as usize)
+            .checked_mul(self.channels as usize)
+            .and_then(|n| n.checked_mul(4))
+            .ok_or_else(|| Error::InvalidParameter {
+                parameter: "samples_per_channel".to_string(),
+                reason: "audio frame size overflows".to_string(),
+            })?;
         if self.data.len() != expected_size {
             return Err(Error::InvalidParameter {
                 parameter: "data".to_string(),
@@ -355,17 +613,7 @@ impl AudioFrameBuilder {
             });
         }
 
-        let frame_metadata_cstring = if let Some(ref metadata) = self.frame_metadata {
-            if metadata.len() > 65535 {
-                return Err(Error::BufferTooSmall {
-                    required: metadata.len(),
-                    provided: 65536,
-                });
-            }
-            Some(CString::new(metadata.as_str())?)
-        } else {
-            None
-        };
+        let frame_metadata_cstring = frame_metadata_to_cstring(self.frame_metadata)?;
 
         Ok(OwnedMediaFrame {
             frame_type: FrameType::AUDIO,
@@ -383,11 +631,31 @@ impl AudioFrameBuilder {
             channels: self.channels,
             samples_per_channel: self.samples_per_channel,
             data: self.data,
+            compressed_data: Vec::new(),
             frame_metadata: frame_metadata_cstring,
         })
     }
 }
 
+/// Checks that an audio channel count is between 1 and [`MAX_CHANNELS`].
+fn check_channels(channels: usize) -> Result<usize> {
+    if channels == 0 || channels > MAX_CHANNELS {
+        return Err(Error::InvalidParameter {
+            parameter: "channels".to_string(),
+            reason: format!("channels must be between 1 and {MAX_CHANNELS}"),
+        });
+    }
+    Ok(channels)
+}
+
+/// Converts a derived per-channel sample count to the FFI integer type.
+fn samples_per_channel_to_i32(samples_per_channel: usize) -> Result<i32> {
+    i32::try_from(samples_per_channel).map_err(|_| Error::InvalidParameter {
+        parameter: "samples_per_channel".to_string(),
+        reason: "audio frame size overflows".to_string(),
+    })
+}
+
 impl Default for AudioFrameBuilder {
     fn default() -> Self {
         Self::new()
@@ -449,26 +717,10 @@ impl MetadataFrameBuilder {
         }
 
         let c_string = CString::new(self.metadata)?;
-        let data = c_string.as_bytes_with_nul().to_vec();
-
-        Ok(OwnedMediaFrame {
-            frame_type: FrameType::METADATA,
-            codec: Codec::Vmx1, // Codec doesn't matter for metadata
-            timestamp: self.timestamp,
-            width: 0,
-            height: 0,
-            stride: 0,
-            flags: VideoFlags::NONE,
-            frame_rate_n: 0,
-            frame_rate_d: 0,
-            aspect_ratio: 0.0,
-            color_space: ColorSpace::Undefined,
-            sample_rate: 0,
-            channels: 0,
-            samples_per_channel: 0,
-            data,
-            frame_metadata: None,
-        })
+        Ok(OwnedMediaFrame::metadata_frame(
+            c_string.into_bytes_with_nul(),
+            self.timestamp,
+        ))
     }
 }
 
@@ -482,6 +734,13 @@ impl Default for MetadataFrameBuilder {
 ///
 /// This frame can be sent via [`Sender::send()`](crate::Sender::send) and
 /// owns all its data, ensuring memory safety.
+///
+/// # Thread Safety
+///
+/// `OwnedMediaFrame` is `Send + Sync` without any unsafe code: it only holds
+/// owned buffers and plain values. The FFI pointers into those buffers are
+/// computed by [`as_media_frame()`](Self::as_media_frame) each time it is
+/// called, so moving the frame to another thread never leaves stale pointers.
 #[derive(Debug)]
 pub struct OwnedMediaFrame {
     frame_type: FrameType,
@@ -499,10 +758,377 @@ pub struct OwnedMediaFrame {
     channels: i32,
     samples_per_channel: i32,
     data: Vec<u8>,
+    compressed_data: Vec<u8>,
     frame_metadata: Option<CString>,
 }
 
 impl OwnedMediaFrame {
+    /// Creates a metadata frame carrying arbitrary bytes.
+    ///
+    /// Unlike [`MetadataFrameBuilder`], the data is neither validated nor null
+    /// terminated: it is sent as-is and `DataLength` is exactly `data.len()`.
+    /// This allows non-XML payloads, including ones containing null bytes.
+    ///
+    /// Receivers must be prepared to handle such frames; use
+    /// [`MediaFrame::data()`] rather than [`MediaFrame::as_utf8()`] to read them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::OwnedMediaFrame;
+    ///
+    /// let frame = OwnedMediaFrame::metadata_bytes(&[0x01, 0x00, 0xFF], -1);
+    /// assert_eq!(frame.data(), &[0x01, 0x00, 0xFF]);
+    /// ```
+    pub fn metadata_bytes(data: &[u8], timestamp: i64) -> Self {
+        Self::metadata_frame(data.to_vec(), timestamp)
+    }
+
+    /// Creates a sendable video frame from tightly packed uncompressed data.
+    ///
+    /// A one-call alternative to [`VideoFrameBuilder`] for simple senders: the
+    /// frame rate defaults to 30fps, the color space is BT.709 for widths of
+    /// 1280 and above and BT.601 otherwise, and the timestamp is `-1` so the
+    /// sender generates one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `codec` is compressed, the
+    /// dimensions are not positive, or `data` is not exactly the size required
+    /// by `codec` at `width` x `height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, OwnedMediaFrame};
+    ///
+    /// let frame = OwnedMediaFrame::into_sendable(Codec::Uyvy, 4, 2, vec![0x80; 4 * 2 * 2])?;
+    /// assert_eq!(frame.as_media_frame().stride(), 8);
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn into_sendable(codec: Codec, width: i32, height: i32, data: Vec<u8>) -> Result<Self> {
+        if width <= 0 || height <= 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "dimensions".to_string(),
+                reason: "width and height must be greater than zero".to_string(),
+            });
+        }
+
+        let stride = default_stride(codec, width);
+        let expected = codec
+            .expected_data_len(width as usize, height as usize, stride as usize)
+            .ok_or_else(|| Error::InvalidParameter {
+                parameter: "codec".to_string(),
+                reason: format!("{codec} is not an uncompressed video codec"),
+            })?;
+        if data.len() != expected {
+            return Err(Error::InvalidParameter {
+                parameter: "data".to_string(),
+                reason: format!(
+                    "{codec} at {width}x{height} requires {expected} bytes, got {}",
+                    data.len()
+                ),
+            });
+        }
+
+        let color_space = ColorSpace::guess_from_dimensions(width, height);
+        VideoFrameBuilder::new()
+            .codec(codec)
+            .dimensions(width, height)
+            .stride(stride)
+            .frame_rate(30, 1)
+            .color_space(color_space)
+            .data(data)
+            .build()
+    }
+
+    /// Creates a UYVA video frame from straight (non-premultiplied) RGBA8 pixels.
+    ///
+    /// Intended for graphics and titles with an alpha key: the color is encoded
+    /// to UYVY 4:2:2, which needs half the bandwidth of BGRA for the color, and
+    /// the alpha is stored at full resolution in the trailing alpha plane. The
+    /// frame has the [`VideoFlags::ALPHA`] flag set.
+    ///
+    /// `rgba` must hold `width * height` tightly packed pixels in R, G, B, A
+    /// order. The color is encoded with the matrix for `color_space`, where
+    /// [`ColorSpace::Undefined`] selects BT.709 for widths of 1280 and above
+    /// and BT.601 otherwise, and limited range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the dimensions are not positive
+    /// or `rgba` has the wrong length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{ColorSpace, OwnedMediaFrame, VideoFlags};
+    ///
+    /// // A half transparent red lower third
+    /// let rgba = [255, 0, 0, 128].repeat(1920 * 270);
+    /// let frame = OwnedMediaFrame::video_uyva_from_rgba8(1920, 270, &rgba, ColorSpace::Bt709)?;
+    /// assert!(frame.as_media_frame().flags().contains(VideoFlags::ALPHA));
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn video_uyva_from_rgba8(
+        width: i32,
+        height: i32,
+        rgba: &[u8],
+        color_space: ColorSpace,
+    ) -> Result<Self> {
+        check_pixels(width, height, rgba, 4, "rgba", "RGBA8")?;
+        let (data, stride) = rgba8_to_uyva(
+            rgba,
+            width as usize,
+            height as usize,
+            yuv_range(ColorRange::Limited),
+            yuv_matrix(color_space.to_matrix(width)),
+        )
+        .ok_or_else(|| Error::other("UYVA conversion failed"))?;
+
+        VideoFrameBuilder::new()
+            .codec(Codec::Uyva)
+            .dimensions(width, height)
+            .stride(stride as i32)
+            .flags(VideoFlags::ALPHA)
+            .color_space(color_space)
+            .data(data)
+            .build()
+    }
+
+    /// Creates a BGRA video frame from RGBA8 pixels, e.g. a rendered framebuffer.
+    ///
+    /// `rgba` must hold `width * height` tightly packed pixels in R, G, B, A
+    /// order. The frame has no [`VideoFlags::ALPHA`] flag, so libomt treats it
+    /// as BGRX and ignores the alpha channel; use
+    /// [`video_uyva_from_rgba8()`](Self::video_uyva_from_rgba8) to send alpha.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the dimensions are not positive
+    /// or `rgba` has the wrong length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, OwnedMediaFrame};
+    ///
+    /// let rgba = [255, 128, 0, 255].repeat(1280 * 720);
+    /// let frame = OwnedMediaFrame::video_bgra_from_rgba8(1280, 720, &rgba)?;
+    /// assert_eq!(frame.as_media_frame().codec(), Some(Codec::Bgra));
+    /// assert_eq!(&frame.data()[..4], &[0, 128, 255, 255]);
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn video_bgra_from_rgba8(width: i32, height: i32, rgba: &[u8]) -> Result<Self> {
+        check_pixels(width, height, rgba, 4, "rgba", "RGBA8")?;
+        let (data, stride) = rgba8_to_bgra(rgba, width as usize, height as usize)
+            .ok_or_else(|| Error::other("BGRA conversion failed"))?;
+
+        VideoFrameBuilder::new()
+            .codec(Codec::Bgra)
+            .dimensions(width, height)
+            .stride(stride as i32)
+            .color_space(ColorSpace::guess_from_dimensions(width, height))
+            .data(data)
+            .build()
+    }
+
+    /// Creates a UYVY video frame from RGB8 pixels.
+    ///
+    /// UYVY needs half the bandwidth of BGRA and is the fastest format for
+    /// libomt to encode. `rgb` must hold `width * height` tightly packed pixels
+    /// in R, G, B order. The color is encoded with the matrix for
+    /// `color_space`, where [`ColorSpace::Undefined`] selects BT.709 for widths
+    /// of 1280 and above and BT.601 otherwise, and limited range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the dimensions are not positive
+    /// or `rgb` has the wrong length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, ColorSpace, OwnedMediaFrame};
+    ///
+    /// let rgb = [16, 32, 64].repeat(1920 * 1080);
+    /// let frame = OwnedMediaFrame::video_uyvy_from_rgb8(1920, 1080, &rgb, ColorSpace::Bt709)?;
+    /// assert_eq!(frame.as_media_frame().codec(), Some(Codec::Uyvy));
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn video_uyvy_from_rgb8(
+        width: i32,
+        height: i32,
+        rgb: &[u8],
+        color_space: ColorSpace,
+    ) -> Result<Self> {
+        check_pixels(width, height, rgb, 3, "rgb", "RGB8")?;
+        let (data, stride) = rgb8_to_uyvy(
+            rgb,
+            width as usize,
+            height as usize,
+            yuv_range(ColorRange::Limited),
+            yuv_matrix(color_space.to_matrix(width)),
+        )
+        .ok_or_else(|| Error::other("UYVY conversion failed"))?;
+
+        VideoFrameBuilder::new()
+            .codec(Codec::Uyvy)
+            .dimensions(width, height)
+            .stride(stride as i32)
+            .color_space(color_space)
+            .data(data)
+            .build()
+    }
+
+    /// Creates a zero-filled (silent) FPA1 audio frame.
+    ///
+    /// Useful as a placeholder when a pipeline needs valid audio but no signal
+    /// is present.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `sample_rate` is not positive,
+    /// `channels` is not between 1 and 32, or `samples` is not between 1 and
+    /// 192000. Parameters are validated before any memory is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::OwnedMediaFrame;
+    ///
+    /// let frame = OwnedMediaFrame::silent_audio(48000, 2, 480, -1)?;
+    /// assert!(frame.data().iter().all(|&b| b == 0));
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn silent_audio(
+        sample_rate: i32,
+        channels: i32,
+        samples: i32,
+        timestamp: i64,
+    ) -> Result<Self> {
+        if sample_rate <= 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "sample_rate".to_string(),
+                reason: "sample rate must be greater than zero".to_string(),
+            });
+        }
+        let channel_count = check_channels(usize::try_from(channels).unwrap_or(0))?;
+        let sample_count = usize::try_from(samples)
+            .ok()
+            .filter(|samples| (1..=MAX_SILENT_SAMPLES).contains(samples))
+            .ok_or_else(|| Error::InvalidParameter {
+                parameter: "samples_per_channel".to_string(),
+                reason: format!("samples per channel must be between 1 and {MAX_SILENT_SAMPLES}"),
+            })?;
+        let data_len = channel_count
+            .checked_mul(sample_count)
+            .and_then(|n| n.checked_mul(4))
+            .ok_or_else(|| Error::InvalidParameter {
+                parameter: "samples_per_channel".to_string(),
+                reason: "audio frame size overflows".to_string(),
+            })?;
+
+        AudioFrameBuilder::new()
+            .sample_rate(sample_rate)
+            .channels(channels)
+            .samples_per_channel(samples)
+            .timestamp(timestamp)
+            .data(vec![0u8; data_len])
+            .build()
+    }
+
+    /// Creates a minimal metadata frame containing `<OMTNull/>`.
+    ///
+    /// Useful as a placeholder on the metadata path, e.g. to signal that no
+    /// metadata is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::OwnedMediaFrame;
+    ///
+    /// let frame = OwnedMediaFrame::empty_metadata(-1);
+    /// assert_eq!(frame.as_media_frame().as_utf8()?, "<OMTNull/>");
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn empty_metadata(timestamp: i64) -> Self {
+        Self::metadata_frame(b"<OMTNull/>\0".to_vec(), timestamp)
+    }
+
+    /// Creates a metadata frame from an already encoded data buffer.
+    fn metadata_frame(data: Vec<u8>, timestamp: i64) -> Self {
+        Self {
+            frame_type: FrameType::METADATA,
+            codec: Codec::Vmx1, // Codec doesn't matter for metadata
+            timestamp,
+            width: 0,
+            height: 0,
+            stride: 0,
+            flags: VideoFlags::NONE,
+            frame_rate_n: 0,
+            frame_rate_d: 0,
+            aspect_ratio: 0.0,
+            color_space: ColorSpace::Undefined,
+            sample_rate: 0,
+            channels: 0,
+            samples_per_channel: 0,
+            data,
+            compressed_data: Vec::new(),
+            frame_metadata: None,
+        }
+    }
+
+    /// Creates an owned deep copy of a borrowed frame.
+    ///
+    /// All properties are copied along with the data, compressed data and
+    /// per-frame metadata buffers, so the result is independent of the
+    /// receiver that produced `frame`: it can be kept across receive calls,
+    /// moved to another thread, or re-sent with [`Sender::send()`](crate::Sender::send).
+    ///
+    /// Unlike [`MediaFrame::clone()`](Clone::clone), the copy is an
+    /// `OwnedMediaFrame`, so its ownership shows in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, OwnedMediaFrame, PreferredVideoFormat, ReceiveFlags};
+    /// # let mut receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let mut keyframes = Vec::new();
+    /// if let Some(frame) = receiver.receive(FrameType::VIDEO, 1000)? {
+    ///     keyframes.push(OwnedMediaFrame::from_received(&frame));
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn from_received(frame: &MediaFrame<'_>) -> Self {
+        let ffi = frame.as_ffi();
+        let frame_metadata = match frame.frame_metadata() {
+            "" => None,
+            metadata => CString::new(metadata).ok(),
+        };
+
+        Self {
+            frame_type: frame.frame_type(),
+            // Codec doesn't matter for metadata frames, which may not carry one
+            codec: frame.codec().unwrap_or(Codec::Vmx1),
+            timestamp: ffi.Timestamp,
+            width: ffi.Width,
+            height: ffi.Height,
+            stride: ffi.Stride,
+            flags: frame.flags(),
+            frame_rate_n: ffi.FrameRateN,
+            frame_rate_d: ffi.FrameRateD,
+            aspect_ratio: ffi.AspectRatio,
+            color_space: frame.color_space().unwrap_or(ColorSpace::Undefined),
+            sample_rate: ffi.SampleRate,
+            channels: ffi.Channels,
+            samples_per_channel: ffi.SamplesPerChannel,
+            data: frame.data().to_vec(),
+            compressed_data: frame.compressed_data().to_vec(),
+            frame_metadata,
+        }
+    }
+
     /// Converts this owned frame to a borrowed `MediaFrame` for sending.
     ///
     /// The returned frame borrows data from this owned frame, so the owned
@@ -534,6 +1160,11 @@ impl OwnedMediaFrame {
             FrameMetadataLength: 0,
         };
 
+        if !self.compressed_data.is_empty() {
+            ffi.CompressedData = self.compressed_data.as_ptr() as *mut _;
+            ffi.CompressedLength = self.compressed_data.len() as i32;
+        }
+
         if let Some(ref metadata) = self.frame_metadata {
             ffi.FrameMetadata = metadata.as_ptr() as *mut _;
             ffi.FrameMetadataLength = metadata.as_bytes_with_nul().len() as i32;
@@ -542,7 +1173,7 @@ impl OwnedMediaFrame {
         // SAFETY: We're creating a MediaFrame from a valid FFI structure.
         // The data is borrowed from self with lifetime 'a tied to &self,
         // ensuring the MediaFrame cannot outlive this OwnedMediaFrame.
-        unsafe { MediaFrame::from_owned_ffi(ffi) }
+        unsafe { MediaFrame::from_owned_ffi(ffi) }.with_color_space(self.color_space)
     }
 
     /// Returns the frame type.
@@ -565,6 +1196,82 @@ impl OwnedMediaFrame {
         self.codec
     }
 
+    /// Sets the display aspect ratio, e.g. to correct a mislabeled source
+    /// before resending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ratio` is not a finite value greater than zero. On
+    /// error the frame is left unchanged.
+    pub fn set_aspect_ratio(&mut self, ratio: f32) -> Result<()> {
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return Err(Error::InvalidParameter {
+                parameter: "aspect_ratio".to_string(),
+                reason: format!("aspect ratio must be greater than zero, got {}", ratio),
+            });
+        }
+        self.aspect_ratio = ratio;
+        Ok(())
+    }
+
+    /// Sets the color space.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Validates the stride of an uncompressed video frame before sending.
+    ///
+    /// A stride smaller than one tightly packed row of the first plane, such
+    /// as `0`, makes libomt read past the rows. Such a stride is replaced with
+    /// the tight stride for the codec and width. Larger strides are kept.
+    /// [`VideoFrameBuilder::build()`] already rejects such strides, so this is
+    /// for frames built with [`allow_short_data()`](VideoFrameBuilder::allow_short_data)
+    /// or copied from elsewhere.
+    ///
+    /// Audio, metadata and compressed video frames have no stride to check and
+    /// are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BufferTooSmall`] if the data is too short for the
+    /// resulting stride. On error the frame is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, VideoFrameBuilder};
+    ///
+    /// let mut frame = VideoFrameBuilder::with_format(Codec::Uyvy, 4, 2)
+    ///     .stride(0)
+    ///     .data(vec![0x80; 4 * 2 * 2])
+    ///     .allow_short_data(true)
+    ///     .build()?;
+    /// frame.fix_stride()?;
+    /// assert_eq!(frame.as_media_frame().stride(), 8);
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn fix_stride(&mut self) -> Result<()> {
+        if self.frame_type != FrameType::VIDEO || self.codec.is_compressed() {
+            return Ok(());
+        }
+
+        let stride = self.stride.max(default_stride(self.codec, self.width));
+        if let Some(required) = self.codec.expected_data_len(
+            self.width.max(0) as usize,
+            self.height.max(0) as usize,
+            stride as usize,
+        ) && self.data.len() < required
+        {
+            return Err(Error::BufferTooSmall {
+                required,
+                provided: self.data.len(),
+            });
+        }
+
+        self.stride = stride;
+        Ok(())
+    }
+
     /// Returns a reference to the frame data.
     pub fn data(&self) -> &[u8] {
         &self.data
@@ -574,8 +1281,286 @@ impl OwnedMediaFrame {
     pub fn data_mut(&mut self) -> &mut [u8] {
         &mut self.data
     }
+
+    /// Returns the per-frame metadata, if any.
+    pub fn frame_metadata(&self) -> Option<&str> {
+        self.frame_metadata.as_ref().and_then(|m| m.to_str().ok())
+    }
+
+    /// Sets or clears the per-frame metadata (UTF-8 string, max 65536 bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata exceeds 65536 bytes (including the
+    /// null terminator) or contains an interior null byte. On error the
+    /// existing metadata is left unchanged.
+    pub fn set_frame_metadata(&mut self, metadata: Option<String>) -> Result<()> {
+        self.frame_metadata = frame_metadata_to_cstring(metadata)?;
+        Ok(())
+    }
+
+    /// Sets or clears the field dominance of an interlaced video frame.
+    ///
+    /// `Some(order)` sets [`VideoFlags::INTERLACED`] and appends an
+    /// `<OMTField order="..."/>` element to the per-frame metadata, replacing any
+    /// previous one. `None` clears the flag and removes the element, marking the
+    /// frame as progressive. Read it back with [`MediaFrame::field_order()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting metadata would exceed 65536 bytes. On
+    /// error the frame is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, FieldOrder, VideoFrameBuilder};
+    ///
+    /// let mut frame = VideoFrameBuilder::new()
+    ///     .codec(Codec::Uyvy)
+    ///     .dimensions(1920, 1080)
+    ///     .data(vec![0u8; 1920 * 1080 * 2])
+    ///     .build()?;
+    /// frame.set_field_order(Some(FieldOrder::TopFirst))?;
+    /// assert_eq!(frame.as_media_frame().field_order(), Some(FieldOrder::TopFirst));
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn set_field_order(&mut self, order: Option<FieldOrder>) -> Result<()> {
+        let mut metadata = self.frame_metadata().unwrap_or_default().to_string();
+        // Remove any previous field order element
+        while let Some(start) = metadata.find(FIELD_ORDER_PREFIX) {
+            let end = metadata[start..]
+                .find("/>")
+                .map_or(metadata.len(), |end| start + end + 2);
+            metadata.replace_range(start..end, "");
+        }
+
+        if let Some(order) = order {
+            metadata.push_str(&format!("{}{}\"/>", FIELD_ORDER_PREFIX, order.as_str()));
+        }
+        self.set_frame_metadata((!metadata.is_empty()).then_some(metadata))?;
+
+        if order.is_some() {
+            self.flags |= VideoFlags::INTERLACED;
+        } else {
+            self.flags = VideoFlags::from_bits(self.flags.bits() & !VideoFlags::INTERLACED.bits());
+        }
+        Ok(())
+    }
 }
 
-// SAFETY: All data is owned and properly synchronized
-unsafe impl Send for OwnedMediaFrame {}
-unsafe impl Sync for OwnedMediaFrame {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn audio_data(channels: i32, samples_per_channel: i32) -> Vec<u8> {
+        vec![0u8; (channels * samples_per_channel * 4) as usize]
+    }
+
+    #[test]
+    fn test_owned_media_frame_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<OwnedMediaFrame>();
+    }
+
+    #[test]
+    fn test_audio_frame_metadata_ffi_length_includes_nul() {
+        let metadata = "<test>audio</test>";
+        let frame = AudioFrameBuilder::new()
+            .channels(2)
+            .samples_per_channel(16)
+            .data(audio_data(2, 16))
+            .frame_metadata(metadata)
+            .build()
+            .unwrap();
+
+        let media_frame = frame.as_media_frame();
+        assert_eq!(media_frame.frame_metadata(), metadata);
+        assert_eq!(
+            media_frame.as_ffi().FrameMetadataLength as usize,
+            metadata.len() + 1
+        );
+    }
+
+    #[test]
+    fn test_video_frame_metadata_ffi_length_includes_nul() {
+        let metadata = "<test>video</test>";
+        let frame = VideoFrameBuilder::with_format(Codec::Bgra, 2, 2)
+            .data(vec![0; 16])
+            .frame_metadata(metadata)
+            .build()
+            .unwrap();
+
+        let media_frame = frame.as_media_frame();
+        assert!(!media_frame.as_ffi().FrameMetadata.is_null());
+        assert_eq!(
+            media_frame.as_ffi().FrameMetadataLength as usize,
+            metadata.len() + 1
+        );
+    }
+
+    #[test]
+    fn test_set_frame_metadata_updates_ffi() {
+        let mut frame = AudioFrameBuilder::new()
+            .channels(1)
+            .samples_per_channel(16)
+            .data(audio_data(1, 16))
+            .build()
+            .unwrap();
+        assert_eq!(frame.as_media_frame().as_ffi().FrameMetadataLength, 0);
+
+        frame.set_frame_metadata(Some("<a/>".to_string())).unwrap();
+        assert_eq!(frame.frame_metadata(), Some("<a/>"));
+        assert_eq!(frame.as_media_frame().as_ffi().FrameMetadataLength, 5);
+
+        frame.set_frame_metadata(None).unwrap();
+        assert_eq!(frame.frame_metadata(), None);
+        assert!(frame.as_media_frame().as_ffi().FrameMetadata.is_null());
+    }
+
+    #[test]
+    fn test_from_received_deep_copy() {
+        let original = VideoFrameBuilder::new()
+            .codec(Codec::Bgra)
+            .dimensions(2, 2)
+            .frame_rate(60000, 1001)
+            .color_space(ColorSpace::Bt709)
+            .timestamp(1234)
+            .data((0..16).collect())
+            .frame_metadata("<m/>")
+            .build()
+            .unwrap();
+
+        let copy = OwnedMediaFrame::from_received(&original.as_media_frame());
+        drop(original);
+
+        let frame = copy.as_media_frame();
+        assert_eq!(frame.frame_type(), FrameType::VIDEO);
+        assert_eq!(frame.codec(), Some(Codec::Bgra));
+        assert_eq!((frame.width(), frame.height(), frame.stride()), (2, 2, 8));
+        assert_eq!(frame.frame_rate_numerator(), 60000);
+        assert_eq!(frame.frame_rate_denominator(), 1001);
+        assert_eq!(frame.color_space(), Some(ColorSpace::Bt709));
+        assert_eq!(frame.timestamp(), 1234);
+        assert_eq!(frame.data(), (0..16).collect::<Vec<u8>>().as_slice());
+        assert_eq!(frame.frame_metadata(), "<m/>");
+    }
+
+    #[test]
+    fn test_set_frame_metadata_too_long_keeps_existing() {
+        let mut frame = AudioFrameBuilder::new()
+            .channels(1)
+            .samples_per_channel(16)
+            .data(audio_data(1, 16))
+            .frame_metadata("<keep/>")
+            .build()
+            .unwrap();
+
+        let result = frame.set_frame_metadata(Some("x".repeat(65536)));
+        assert!(matches!(result, Err(Error::BufferTooSmall { .. })));
+        assert_eq!(frame.frame_metadata(), Some("<keep/>"));
+    }
+
+    #[test]
+    fn test_set_aspect_ratio_and_color_space_update_ffi() {
+        let mut frame = VideoFrameBuilder::with_format(Codec::Uyvy, 720, 576)
+            .color_space(ColorSpace::Bt709)
+            .data(vec![0x80; 720 * 576 * 2])
+            .build()
+            .unwrap();
+
+        frame.set_aspect_ratio(16.0 / 9.0).unwrap();
+        frame.set_color_space(ColorSpace::Bt601);
+
+        let media_frame = frame.as_media_frame();
+        assert_eq!(media_frame.as_ffi().AspectRatio, 16.0 / 9.0);
+        assert_eq!(media_frame.aspect_ratio(), 16.0 / 9.0);
+        assert_eq!(media_frame.as_ffi().ColorSpace, ColorSpace::Bt601.to_ffi());
+        assert_eq!(media_frame.color_space(), Some(ColorSpace::Bt601));
+    }
+
+    #[test]
+    fn test_set_aspect_ratio_rejects_invalid() {
+        let mut frame = VideoFrameBuilder::with_format(Codec::Bgra, 4, 3)
+            .data(vec![0; 4 * 3 * 4])
+            .build()
+            .unwrap();
+
+        for ratio in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let result = frame.set_aspect_ratio(ratio);
+            assert!(matches!(result, Err(Error::InvalidParameter { .. })));
+        }
+        assert_eq!(frame.as_media_frame().aspect_ratio(), 4.0 / 3.0);
+    }
+
+    #[test]
+    fn test_fix_stride_sets_tight_stride() {
+        for (codec, tight) in [(Codec::Uyvy, 8), (Codec::Bgra, 16), (Codec::Nv12, 4)] {
+            let len = codec.expected_data_len(4, 4, tight).unwrap();
+            let mut frame = VideoFrameBuilder::with_format(codec, 4, 4)
+                .stride(0)
+                .data(vec![0; len])
+                .allow_short_data(true)
+                .build()
+                .unwrap();
+            assert_eq!(frame.as_media_frame().stride(), 0);
+
+            frame.fix_stride().unwrap();
+            assert_eq!(frame.as_media_frame().stride(), tight as i32);
+        }
+    }
+
+    #[test]
+    fn test_fix_stride_keeps_padded_stride() {
+        let mut frame = VideoFrameBuilder::with_format(Codec::Uyvy, 4, 2)
+            .stride(16)
+            .data(vec![0; 16 * 2])
+            .build()
+            .unwrap();
+        frame.fix_stride().unwrap();
+        assert_eq!(frame.as_media_frame().stride(), 16);
+    }
+
+    #[test]
+    fn test_fix_stride_rejects_short_buffer() {
+        let mut frame = VideoFrameBuilder::with_format(Codec::Nv12, 4, 4)
+            .stride(0)
+            .data(vec![0; 20])
+            .allow_short_data(true)
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            frame.fix_stride(),
+            Err(Error::BufferTooSmall {
+                required: 24,
+                provided: 20
+            })
+        ));
+        assert_eq!(frame.as_media_frame().stride(), 0);
+    }
+
+    #[test]
+    fn test_fix_stride_ignores_audio() {
+        let mut frame = AudioFrameBuilder::new()
+            .channels(1)
+            .samples_per_channel(4)
+            .data(vec![0; 16])
+            .build()
+            .unwrap();
+        frame.fix_stride().unwrap();
+        assert_eq!(frame.as_media_frame().stride(), 0);
+    }
+
+    #[test]
+    fn test_metadata_bytes_keeps_exact_length() {
+        let bytes = [0x00, 0x01, 0x00, 0xFF];
+        let frame = OwnedMediaFrame::metadata_bytes(&bytes, 42);
+
+        let media_frame = frame.as_media_frame();
+        assert_eq!(media_frame.frame_type(), FrameType::METADATA);
+        assert_eq!(media_frame.timestamp(), 42);
+        assert_eq!(media_frame.as_ffi().DataLength, 4);
+        assert_eq!(media_frame.data(), &bytes);
+    }
+}
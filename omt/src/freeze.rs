@@ -0,0 +1,197 @@
+//! Detection of frozen (static) video sources.
+
+use crate::frame::MediaFrame;
+use crate::types::FrameType;
+
+/// Detects sources whose picture has stopped changing.
+///
+/// Feed the detector one 8-bit luma buffer per received frame with
+/// [`push()`](Self::push), or pass frames directly with
+/// [`push_frame()`](Self::push_frame). Each buffer is compared
+/// with the previous one using the mean absolute difference of the luma values.
+/// Once that difference stays below `threshold` for `frames` consecutive frames,
+/// the source is reported as frozen. The first changing frame clears the state.
+///
+/// A change in buffer length, such as a resolution change, counts as a change.
+///
+/// # Examples
+///
+/// ```
+/// use omt::FreezeDetector;
+///
+/// let mut detector = FreezeDetector::new(0.5, 2);
+/// let still = vec![128u8; 64];
+/// assert!(!detector.push(&still));
+/// assert!(!detector.push(&still));
+/// assert!(detector.push(&still));
+///
+/// let changed = vec![0u8; 64];
+/// assert!(!detector.push(&changed));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FreezeDetector {
+    threshold: f64,
+    frames: u32,
+    previous: Option<Vec<u8>>,
+    still_frames: u32,
+    last_difference: Option<f64>,
+}
+
+impl FreezeDetector {
+    /// Creates a detector reporting a freeze after `frames` consecutive frames whose
+    /// mean absolute luma difference to their predecessor is below `threshold`.
+    ///
+    /// `threshold` is in 8-bit luma steps; a small value such as `0.5` tolerates
+    /// encoder noise on an otherwise static picture. A `frames` value of 0 is
+    /// treated as 1.
+    pub fn new(threshold: f64, frames: u32) -> Self {
+        Self {
+            threshold,
+            frames: frames.max(1),
+            previous: None,
+            still_frames: 0,
+            last_difference: None,
+        }
+    }
+
+    /// Adds the next 8-bit luma buffer and returns whether the source is frozen.
+    pub fn push(&mut self, luma: &[u8]) -> bool {
+        self.last_difference = self
+            .previous
+            .as_deref()
+            .and_then(|previous| mean_abs_diff(previous, luma));
+
+        match self.last_difference {
+            Some(difference) if difference < self.threshold => {
+                self.still_frames = self.still_frames.saturating_add(1);
+            }
+            _ => self.still_frames = 0,
+        }
+
+        match &mut self.previous {
+            Some(previous) => {
+                previous.clear();
+                previous.extend_from_slice(luma);
+            }
+            None => self.previous = Some(luma.to_vec()),
+        }
+        self.is_frozen()
+    }
+
+    /// Adds the luma of a video frame and returns whether the source is frozen.
+    ///
+    /// Returns `None` without updating the detector if luma cannot be extracted,
+    /// e.g. for non-video frames or compressed codecs.
+    pub fn push_frame(&mut self, frame: &MediaFrame<'_>) -> Option<bool> {
+        if frame.frame_type() != FrameType::VIDEO {
+            return None;
+        }
+        let luma = frame.to_luma8()?;
+        Some(self.push(&luma))
+    }
+
+    /// Returns whether the last `frames` frames were unchanged.
+    pub fn is_frozen(&self) -> bool {
+        self.still_frames >= self.frames
+    }
+
+    /// Returns the mean absolute luma difference between the last two frames.
+    ///
+    /// Returns `None` before the second frame or after a change in buffer length.
+    pub fn last_difference(&self) -> Option<f64> {
+        self.last_difference
+    }
+
+    /// Forgets all previous frames.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.still_frames = 0;
+        self.last_difference = None;
+    }
+}
+
+/// Computes the mean absolute difference of two equally sized buffers.
+fn mean_abs_diff(a: &[u8], b: &[u8]) -> Option<f64> {
+    if a.len() != b.len() || a.is_empty() {
+        return None;
+    }
+    let sum: u64 = a.iter().zip(b).map(|(&a, &b)| a.abs_diff(b) as u64).sum();
+    Some(sum as f64 / a.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_abs_diff() {
+        assert_eq!(mean_abs_diff(&[0, 10, 20, 30], &[0, 10, 20, 30]), Some(0.0));
+        assert_eq!(mean_abs_diff(&[0, 10, 20, 30], &[4, 6, 20, 30]), Some(2.0));
+        assert_eq!(mean_abs_diff(&[0, 10], &[0, 10, 20]), None);
+        assert_eq!(mean_abs_diff(&[], &[]), None);
+    }
+
+    #[test]
+    fn test_identical_frames_freeze_after_n() {
+        let mut detector = FreezeDetector::new(1.0, 3);
+        let frame = vec![100u8; 32];
+
+        // The first frame has nothing to compare with
+        assert!(!detector.push(&frame));
+        assert_eq!(detector.last_difference(), None);
+        assert!(!detector.push(&frame));
+        assert!(!detector.push(&frame));
+        assert!(detector.push(&frame));
+        assert_eq!(detector.last_difference(), Some(0.0));
+        assert!(detector.is_frozen());
+    }
+
+    #[test]
+    fn test_changing_frames_clear_freeze() {
+        let mut detector = FreezeDetector::new(1.0, 2);
+        let frame = vec![100u8; 32];
+        for _ in 0..3 {
+            detector.push(&frame);
+        }
+        assert!(detector.is_frozen());
+
+        // Small noise stays frozen
+        let noisy: Vec<u8> = (0..32).map(|i| 100 + (i % 2) as u8).collect();
+        assert!(detector.push(&noisy));
+
+        // A changing sequence clears it
+        for value in [0u8, 50, 150, 200] {
+            assert!(!detector.push(&[value; 32]));
+        }
+
+        // A resolution change is not a freeze
+        detector.push(&[1u8; 16]);
+        assert!(!detector.push(&[1u8; 8]));
+        assert_eq!(detector.last_difference(), None);
+
+        detector.reset();
+        assert!(!detector.is_frozen());
+    }
+
+    #[test]
+    fn test_push_frame() {
+        use crate::{Codec, MetadataFrameBuilder, VideoFrameBuilder};
+
+        let frame = VideoFrameBuilder::new()
+            .codec(Codec::Uyvy)
+            .dimensions(4, 2)
+            .data(vec![128u8; 4 * 2 * 2])
+            .build()
+            .unwrap();
+        let mut detector = FreezeDetector::new(1.0, 1);
+        assert_eq!(detector.push_frame(&frame.as_media_frame()), Some(false));
+        assert_eq!(detector.push_frame(&frame.as_media_frame()), Some(true));
+
+        let metadata = MetadataFrameBuilder::new()
+            .metadata("<a/>")
+            .build()
+            .unwrap();
+        assert_eq!(detector.push_frame(&metadata.as_media_frame()), None);
+        assert!(detector.is_frozen());
+    }
+}
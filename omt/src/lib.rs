@@ -97,31 +97,51 @@
 #![warn(rust_2018_idioms)]
 
 mod discovery;
+mod dual_receiver;
 mod error;
 mod frame;
 mod frame_builder;
+mod freeze;
+pub mod metadata;
+mod placeholder;
+mod proxy;
 mod receiver;
+mod receiver_channels;
+mod recording;
 mod sender;
 mod settings;
 mod statistics;
 mod tally;
+mod thread_options;
+mod time;
+mod timestamp_monitor;
 mod types;
 mod video_conversion;
 
-pub use discovery::Discovery;
-pub use error::{Error, Result};
-pub use frame::MediaFrame;
+pub use discovery::{Discovery, DiscoveryOptions, DiscoverySession};
+pub use dual_receiver::DualReceiver;
+pub use error::{ConvertError, Error, Result};
+pub use frame::{AudioFrames, MediaFrame, SampleFrame};
 pub use frame_builder::{
     AudioFrameBuilder, MetadataFrameBuilder, OwnedMediaFrame, VideoFrameBuilder,
 };
-pub use receiver::Receiver;
-pub use sender::Sender;
-pub use settings::Settings;
-pub use statistics::Statistics;
+pub use freeze::FreezeDetector;
+pub use placeholder::{PlaceholderPattern, PlaceholderSender};
+pub use proxy::Proxy;
+pub use receiver::{ConnectionState, ReceivedFrames, Receiver};
+pub use receiver_channels::{FrameReceiver, ReceiverChannels, ReceiverHandle};
+pub use recording::{CompressedPlayer, Recorder};
+pub use sender::{SendReceipt, Sender, SenderStatus};
+pub use settings::{Settings, SettingsBuilder};
+pub use statistics::{Statistics, StatisticsDelta};
 pub use tally::Tally;
+pub use thread_options::{ThreadOptions, ThreadPriority};
+pub use time::{OMT_TIME_BASE, SenderClock, duration_from_ticks, ticks_from_duration};
+pub use timestamp_monitor::{TimestampEvent, TimestampMonitor};
 pub use types::{
-    Codec, ColorSpace, FrameType, PreferredVideoFormat, Quality, ReceiveFlags, SenderInfo,
-    VideoFlags,
+    AlphaMode, ChromaUpsample, Codec, ColorMatrix, ColorRange, ColorSpace, FieldOrder, FrameRate,
+    FrameType, Plane, PlaneLayout, PreferredVideoFormat, Quality, ReceiveFlags, SenderInfo,
+    SenderInfoChange, VideoFlags,
 };
 
 /// Maximum length for string fields in OMT structures.
@@ -0,0 +1,45 @@
+//! Builders for the metadata XML defined by the OMT metadata specification.
+//!
+//! The generated strings can be sent as metadata frames, see
+//! [`MetadataFrameBuilder`](crate::MetadataFrameBuilder), or advertised to
+//! every receiver with
+//! [`Sender::add_connection_metadata()`](crate::Sender::add_connection_metadata).
+//!
+//! With the `metadata` feature, received metadata can be read back with
+//! `parse()`.
+
+#[cfg(feature = "metadata")]
+mod parse;
+pub mod ptz;
+
+#[cfg(feature = "metadata")]
+pub use parse::{AncillaryPacket, MetadataElement, PtzElement, parse};
+
+/// Escapes a value for use inside a double-quoted XML attribute.
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_attribute() {
+        assert_eq!(escape_attribute("plain"), "plain");
+        assert_eq!(
+            escape_attribute(r#"a&b<c>"d""#),
+            "a&amp;b&lt;c&gt;&quot;d&quot;"
+        );
+    }
+}
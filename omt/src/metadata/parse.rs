@@ -0,0 +1,339 @@
+//! Parser for incoming metadata XML.
+
+use crate::error::{Error, Result};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use std::collections::BTreeMap;
+
+/// Name of the element wrapping several metadata elements in one frame.
+const GROUP: &[u8] = b"OMTGroup";
+
+/// A metadata element recognized by [`parse()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataElement {
+    /// PTZ control (`<OMTPTZ>`).
+    Ptz(PtzElement),
+    /// Web management interface of the sender (`<OMTWeb>`).
+    Web {
+        /// Address to browse to.
+        url: String,
+    },
+    /// Raw SDI ancillary data packets (`<AncillaryData>`).
+    AncillaryData(Vec<AncillaryPacket>),
+    /// Any other element, or a known element with missing or malformed
+    /// attributes, as its raw XML.
+    Unknown(String),
+}
+
+/// A PTZ control element, see [`Ptz`](super::ptz::Ptz) for building them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PtzElement {
+    /// The camera is controlled by standard VISCA over IP.
+    ViscaOverIp {
+        /// The `visca://host:port` URL of the camera.
+        url: String,
+    },
+    /// A VISCA command sent from controller to camera.
+    ViscaCommand {
+        /// Sequence number of the VISCA over IP message.
+        sequence: u16,
+        /// Decoded command bytes.
+        command: Vec<u8>,
+    },
+    /// A VISCA reply sent from camera to controller.
+    ViscaReply {
+        /// Sequence number of the VISCA over IP message.
+        sequence: u16,
+        /// Decoded reply bytes.
+        reply: Vec<u8>,
+    },
+}
+
+/// A single `<Packet>` of an `<AncillaryData>` element.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AncillaryPacket {
+    /// Packet attributes such as `did`, `sdid`, `line` or `stream`, unparsed.
+    pub attributes: BTreeMap<String, String>,
+    /// Decoded payload bytes; empty if the packet has no `<Payload>`.
+    pub payload: Vec<u8>,
+}
+
+impl AncillaryPacket {
+    /// Returns the value of the attribute `name`, if present.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+}
+
+/// Parses the XML of a metadata frame into its elements.
+///
+/// Both a single element and several elements wrapped in `<OMTGroup>` are
+/// accepted, as recommended by the OMT metadata specification. Elements are
+/// returned in document order.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if `xml` is not well-formed.
+///
+/// # Examples
+///
+/// ```
+/// use omt::metadata::{MetadataElement, parse};
+///
+/// let elements = parse(r#"<OMTWeb URL="http://10.0.0.5/" />"#)?;
+/// assert_eq!(
+///     elements,
+///     [MetadataElement::Web { url: "http://10.0.0.5/".to_string() }]
+/// );
+/// # Ok::<(), omt::Error>(())
+/// ```
+pub fn parse(xml: &str) -> Result<Vec<MetadataElement>> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+    let mut elements = Vec::new();
+    loop {
+        let start = position(&reader);
+        match reader.read_event().map_err(invalid_xml)? {
+            Event::Start(e) if e.name().as_ref() == GROUP => {}
+            Event::End(e) if e.name().as_ref() == GROUP => {}
+            Event::Empty(e) => {
+                let raw = xml[start..position(&reader)].trim();
+                elements.push(interpret(&e, "", raw)?);
+            }
+            Event::Start(e) => {
+                let inner = reader.read_to_end(e.name()).map_err(invalid_xml)?;
+                let inner = &xml[inner.start as usize..inner.end as usize];
+                let raw = xml[start..position(&reader)].trim();
+                elements.push(interpret(&e, inner, raw)?);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(elements)
+}
+
+/// Interprets one top-level element with the given content and raw XML.
+fn interpret(e: &BytesStart<'_>, inner: &str, raw: &str) -> Result<MetadataElement> {
+    let element = match e.name().as_ref() {
+        b"OMTPTZ" => ptz(&attributes(e)?).map(MetadataElement::Ptz),
+        b"OMTWeb" => attributes(e)?
+            .remove("URL")
+            .map(|url| MetadataElement::Web { url }),
+        b"AncillaryData" => Some(MetadataElement::AncillaryData(packets(inner)?)),
+        _ => None,
+    };
+    Ok(element.unwrap_or_else(|| MetadataElement::Unknown(raw.to_string())))
+}
+
+fn ptz(attributes: &BTreeMap<String, String>) -> Option<PtzElement> {
+    let attribute = |name: &str| attributes.get(name).map(String::as_str);
+    match attribute("Protocol")? {
+        "VISCAoverIP" => Some(PtzElement::ViscaOverIp {
+            url: attribute("URL")?.to_string(),
+        }),
+        "VISCA" => {
+            let sequence = attribute("Sequence")?.parse().ok()?;
+            if let Some(command) = attribute("Command") {
+                Some(PtzElement::ViscaCommand {
+                    sequence,
+                    command: decode_hex(command)?,
+                })
+            } else {
+                Some(PtzElement::ViscaReply {
+                    sequence,
+                    reply: decode_hex(attribute("Reply")?)?,
+                })
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parses the `<Packet>` children of an `<AncillaryData>` element.
+fn packets(inner: &str) -> Result<Vec<AncillaryPacket>> {
+    let mut reader = Reader::from_str(inner);
+    reader.config_mut().trim_text(true);
+    let mut packets = Vec::new();
+    loop {
+        match reader.read_event().map_err(invalid_xml)? {
+            Event::Empty(e) if e.name().as_ref() == b"Packet" => {
+                packets.push(AncillaryPacket {
+                    attributes: attributes(&e)?,
+                    payload: Vec::new(),
+                });
+            }
+            Event::Start(e) if e.name().as_ref() == b"Packet" => {
+                let mut packet = AncillaryPacket {
+                    attributes: attributes(&e)?,
+                    payload: Vec::new(),
+                };
+                loop {
+                    match reader.read_event().map_err(invalid_xml)? {
+                        Event::Start(child) if child.name().as_ref() == b"Payload" => {
+                            let text = reader.read_text(child.name()).map_err(invalid_xml)?;
+                            packet.payload = decode_hex(text.trim()).ok_or_else(|| {
+                                invalid_xml(format!("invalid hex payload '{}'", text.trim()))
+                            })?;
+                        }
+                        Event::Start(child) => {
+                            reader.read_to_end(child.name()).map_err(invalid_xml)?;
+                        }
+                        Event::End(_) | Event::Eof => break,
+                        _ => {}
+                    }
+                }
+                packets.push(packet);
+            }
+            Event::Start(e) => {
+                reader.read_to_end(e.name()).map_err(invalid_xml)?;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(packets)
+}
+
+/// Collects the unescaped attributes of an element.
+fn attributes(e: &BytesStart<'_>) -> Result<BTreeMap<String, String>> {
+    let mut attributes = BTreeMap::new();
+    for attribute in e.attributes() {
+        let attribute = attribute.map_err(invalid_xml)?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(invalid_xml)?;
+        attributes.insert(key, value.into_owned());
+    }
+    Ok(attributes)
+}
+
+/// Decodes a hex string, ignoring case. Returns `None` if it is malformed.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn position(reader: &Reader<&[u8]>) -> usize {
+    reader.buffer_position() as usize
+}
+
+fn invalid_xml(error: impl std::fmt::Display) -> Error {
+    Error::InvalidParameter {
+        parameter: "xml".to_string(),
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grouped_example() {
+        let xml = r#"<OMTGroup>
+<OMTPTZ Protocol="VISCA" Sequence="22" Reply="0011AABBCC" />
+<AncillaryData xmns="urn:anc:1.0">
+<Packet did="45" sdid="01" field="1" line="21" horizOffset="0" st2110Channel="0" pts90k="32109876" link="A" stream="VANC">
+<Payload>81010A011E0000</Payload>
+</Packet>
+</AncillaryData>
+</OMTGroup>"#;
+        let elements = parse(xml).unwrap();
+        assert_eq!(elements.len(), 2);
+        assert_eq!(
+            elements[0],
+            MetadataElement::Ptz(PtzElement::ViscaReply {
+                sequence: 22,
+                reply: vec![0x00, 0x11, 0xAA, 0xBB, 0xCC],
+            })
+        );
+        let MetadataElement::AncillaryData(packets) = &elements[1] else {
+            panic!("expected ancillary data, got {:?}", elements[1]);
+        };
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].attribute("did"), Some("45"));
+        assert_eq!(packets[0].attribute("stream"), Some("VANC"));
+        assert_eq!(packets[0].attributes.len(), 9);
+        assert_eq!(
+            packets[0].payload,
+            [0x81, 0x01, 0x0A, 0x01, 0x1E, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_parse_single_elements() {
+        assert_eq!(
+            parse(r#"<OMTPTZ Protocol="VISCAoverIP" URL="visca://10.0.0.5:52381"  />"#).unwrap(),
+            [MetadataElement::Ptz(PtzElement::ViscaOverIp {
+                url: "visca://10.0.0.5:52381".to_string(),
+            })]
+        );
+        assert_eq!(
+            parse(r#"<OMTPTZ Protocol="VISCA" Sequence="7" Command="8101040700ff" />"#).unwrap(),
+            [MetadataElement::Ptz(PtzElement::ViscaCommand {
+                sequence: 7,
+                command: vec![0x81, 0x01, 0x04, 0x07, 0x00, 0xFF],
+            })]
+        );
+        assert_eq!(
+            parse(r#"<OMTWeb URL="http://a/?x=1&amp;y=2"></OMTWeb>"#).unwrap(),
+            [MetadataElement::Web {
+                url: "http://a/?x=1&y=2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_roundtrips_ptz_builder() {
+        let xml = super::super::ptz::Ptz::visca_inband(22, &[0x81, 0x01, 0xFF]).unwrap();
+        assert_eq!(
+            parse(&xml).unwrap(),
+            [MetadataElement::Ptz(PtzElement::ViscaCommand {
+                sequence: 22,
+                command: vec![0x81, 0x01, 0xFF],
+            })]
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_elements() {
+        let xml = r#"<OMTGroup><Custom a="1"><b/></Custom><OMTPTZ Protocol="VISCA" Sequence="x" Reply="00" /></OMTGroup>"#;
+        assert_eq!(
+            parse(xml).unwrap(),
+            [
+                MetadataElement::Unknown(r#"<Custom a="1"><b/></Custom>"#.to_string()),
+                MetadataElement::Unknown(
+                    r#"<OMTPTZ Protocol="VISCA" Sequence="x" Reply="00" />"#.to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("<OMTGroup></OMTGroup>").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_xml() {
+        let err = parse("<OMTGroup><OMTWeb URL=\"x\" /></Group>").unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter { ref parameter, .. } if parameter == "xml"));
+        assert!(
+            parse("<AncillaryData><Packet><Payload>8</Payload></Packet></AncillaryData>").is_err()
+        );
+    }
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("00aAfF"), Some(vec![0x00, 0xAA, 0xFF]));
+        assert_eq!(decode_hex(""), Some(vec![]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+}
@@ -0,0 +1,162 @@
+//! PTZ control metadata (`<OMTPTZ>` elements).
+
+use super::escape_attribute;
+use crate::error::{Error, Result};
+use std::fmt::Write;
+
+/// Builds `<OMTPTZ>` elements for controlling PTZ cameras.
+///
+/// A camera advertises how it can be controlled with
+/// [`visca_over_ip()`](Self::visca_over_ip) or accepts VISCA commands sent
+/// inside OMT metadata, built with [`visca_inband()`](Self::visca_inband) and
+/// answered with [`visca_reply()`](Self::visca_reply).
+///
+/// # Examples
+///
+/// ```
+/// use omt::MetadataFrameBuilder;
+/// use omt::metadata::ptz::Ptz;
+///
+/// // Recall preset 0
+/// let command = Ptz::visca_inband(22, &[0x81, 0x01, 0x04, 0x3F, 0x02, 0x00, 0xFF])?;
+/// assert_eq!(
+///     command,
+///     r#"<OMTPTZ Protocol="VISCA" Sequence="22" Command="8101043F0200FF" />"#
+/// );
+/// let frame = MetadataFrameBuilder::new().metadata(command).build()?;
+/// # Ok::<(), omt::Error>(())
+/// ```
+pub struct Ptz;
+
+impl Ptz {
+    /// Advertises a camera controlled by standard VISCA over IP.
+    ///
+    /// `url` must use the `visca://` scheme, e.g. `visca://192.168.1.10:52381`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the URL has another scheme, no
+    /// host, or contains whitespace.
+    pub fn visca_over_ip(url: &str) -> Result<String> {
+        let host = url
+            .strip_prefix("visca://")
+            .ok_or_else(|| invalid_url(url))?;
+        if host.is_empty() || url.chars().any(char::is_whitespace) {
+            return Err(invalid_url(url));
+        }
+        Ok(format!(
+            r#"<OMTPTZ Protocol="VISCAoverIP" URL="{}" />"#,
+            escape_attribute(url)
+        ))
+    }
+
+    /// Sends a VISCA command from controller to camera inside OMT metadata.
+    ///
+    /// `sequence` is the sequence number of the VISCA over IP message and
+    /// `command` its payload, which is hex encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `command` is empty.
+    pub fn visca_inband(sequence: u16, command: &[u8]) -> Result<String> {
+        Ok(format!(
+            r#"<OMTPTZ Protocol="VISCA" Sequence="{}" Command="{}" />"#,
+            sequence,
+            hex_payload("command", command)?
+        ))
+    }
+
+    /// Sends a VISCA reply from camera back to controller inside OMT metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `reply` is empty.
+    pub fn visca_reply(sequence: u16, reply: &[u8]) -> Result<String> {
+        Ok(format!(
+            r#"<OMTPTZ Protocol="VISCA" Sequence="{}" Reply="{}" />"#,
+            sequence,
+            hex_payload("reply", reply)?
+        ))
+    }
+}
+
+fn invalid_url(url: &str) -> Error {
+    Error::InvalidParameter {
+        parameter: "url".to_string(),
+        reason: format!("'{}' is not a visca://host:port URL", url),
+    }
+}
+
+/// Encodes `bytes` as uppercase hex, as in the specification's examples.
+fn hex_payload(parameter: &str, bytes: &[u8]) -> Result<String> {
+    if bytes.is_empty() {
+        return Err(Error::InvalidParameter {
+            parameter: parameter.to_string(),
+            reason: "payload cannot be empty".to_string(),
+        });
+    }
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        // Writing to a String cannot fail
+        let _ = write!(hex, "{:02X}", byte);
+    }
+    Ok(hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples from omt-sys/docs/METADATA.md
+
+    #[test]
+    fn test_visca_inband_matches_spec() {
+        assert_eq!(
+            Ptz::visca_inband(22, &[0x81, 0x01, 0x04, 0x07, 0x00, 0xFF]).unwrap(),
+            r#"<OMTPTZ Protocol="VISCA" Sequence="22" Command="8101040700FF" />"#
+        );
+    }
+
+    #[test]
+    fn test_visca_reply_matches_spec() {
+        assert_eq!(
+            Ptz::visca_reply(22, &[0x00, 0x11, 0xAA, 0xBB, 0xCC]).unwrap(),
+            r#"<OMTPTZ Protocol="VISCA" Sequence="22" Reply="0011AABBCC" />"#
+        );
+    }
+
+    #[test]
+    fn test_visca_over_ip_matches_spec() {
+        assert_eq!(
+            Ptz::visca_over_ip("visca://192.168.1.10:52381").unwrap(),
+            r#"<OMTPTZ Protocol="VISCAoverIP" URL="visca://192.168.1.10:52381" />"#
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        for url in ["http://camera:52381", "visca://", "visca://camera :1", ""] {
+            assert!(
+                matches!(Ptz::visca_over_ip(url), Err(Error::InvalidParameter { .. })),
+                "{}",
+                url
+            );
+        }
+        assert!(matches!(
+            Ptz::visca_inband(1, &[]),
+            Err(Error::InvalidParameter { .. })
+        ));
+        assert!(matches!(
+            Ptz::visca_reply(1, &[]),
+            Err(Error::InvalidParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_visca_over_ip_escapes_url() {
+        assert_eq!(
+            Ptz::visca_over_ip(r#"visca://cam"&<x>"#).unwrap(),
+            r#"<OMTPTZ Protocol="VISCAoverIP" URL="visca://cam&quot;&amp;&lt;x&gt;" />"#
+        );
+    }
+}
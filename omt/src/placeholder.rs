@@ -0,0 +1,219 @@
+//! Placeholder source that keeps sending a fixed pattern.
+
+use crate::error::{Error, Result};
+use crate::frame_builder::{OwnedMediaFrame, VideoFrameBuilder};
+use crate::sender::Sender;
+use crate::thread_options::ThreadOptions;
+use crate::types::{Codec, FrameRate, Quality};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// 75% color bars in BGRA order: white, yellow, cyan, green, magenta, red, blue, black.
+const COLOR_BARS: [[u8; 4]; 8] = [
+    [191, 191, 191, 255],
+    [0, 191, 191, 255],
+    [191, 191, 0, 255],
+    [0, 191, 0, 255],
+    [191, 0, 191, 255],
+    [0, 0, 191, 255],
+    [191, 0, 0, 255],
+    [0, 0, 0, 255],
+];
+
+/// Picture shown by a [`PlaceholderSender`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PlaceholderPattern {
+    /// Eight vertical 75% color bars.
+    #[default]
+    ColorBars,
+    /// A black picture.
+    Black,
+}
+
+impl PlaceholderPattern {
+    fn to_u8(self) -> u8 {
+        match self {
+            PlaceholderPattern::ColorBars => 0,
+            PlaceholderPattern::Black => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => PlaceholderPattern::ColorBars,
+            _ => PlaceholderPattern::Black,
+        }
+    }
+
+    /// Renders the pattern as tightly packed BGRA pixels.
+    fn render(self, width: usize, height: usize) -> Vec<u8> {
+        let mut row = Vec::with_capacity(width * 4);
+        for x in 0..width {
+            let pixel = match self {
+                PlaceholderPattern::ColorBars => COLOR_BARS[x * COLOR_BARS.len() / width],
+                PlaceholderPattern::Black => [0, 0, 0, 255],
+            };
+            row.extend_from_slice(&pixel);
+        }
+        row.repeat(height)
+    }
+}
+
+/// A source that is always present, sending a "no signal" picture.
+///
+/// Created with [`Sender::placeholder()`]. A background thread sends the
+/// current [`PlaceholderPattern`] at the configured frame rate, so the source
+/// stays discoverable and receivers see a picture without any frames being
+/// sent by the application. Switch the picture with
+/// [`set_pattern()`](Self::set_pattern).
+///
+/// Dropping the placeholder stops the thread and waits for it to finish, which
+/// can take up to one frame interval.
+pub struct PlaceholderSender {
+    sender: Arc<Sender>,
+    pattern: Arc<AtomicU8>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PlaceholderSender {
+    /// Creates the sender and starts sending color bars.
+    pub(crate) fn spawn(
+        name: &str,
+        frame_rate: FrameRate,
+        width: i32,
+        height: i32,
+        options: &ThreadOptions,
+    ) -> Result<Self> {
+        if width <= 0 || height <= 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "dimensions".to_string(),
+                reason: "width and height must be greater than zero".to_string(),
+            });
+        }
+
+        // Indexed by PlaceholderPattern::to_u8()
+        let build = |pattern: PlaceholderPattern| {
+            VideoFrameBuilder::with_format(Codec::Bgra, width, height)
+                .frame_rate_typed(frame_rate)
+                .data(pattern.render(width as usize, height as usize))
+                .build()
+        };
+        let frames = [
+            build(PlaceholderPattern::ColorBars)?,
+            build(PlaceholderPattern::Black)?,
+        ];
+
+        let sender = Arc::new(Sender::new(name, Quality::Default)?);
+        let pattern = Arc::new(AtomicU8::new(PlaceholderPattern::ColorBars.to_u8()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let interval = Duration::from_secs_f64(1.0 / frame_rate.value());
+
+        let thread = {
+            let sender = Arc::clone(&sender);
+            let pattern = Arc::clone(&pattern);
+            let stop = Arc::clone(&stop);
+            options.spawn(move || send_loop(&sender, &pattern, &stop, &frames, interval))
+        };
+
+        Ok(Self {
+            sender,
+            pattern,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Switches the picture sent from the next frame on.
+    pub fn set_pattern(&self, pattern: PlaceholderPattern) {
+        self.pattern.store(pattern.to_u8(), Ordering::Relaxed);
+    }
+
+    /// Returns the picture currently being sent.
+    pub fn pattern(&self) -> PlaceholderPattern {
+        PlaceholderPattern::from_u8(self.pattern.load(Ordering::Relaxed))
+    }
+
+    /// Returns the sender, e.g. to query its address or connections.
+    ///
+    /// Frames sent through it are interleaved with the placeholder frames.
+    pub fn sender(&self) -> &Sender {
+        &self.sender
+    }
+
+    /// Stops sending and waits for the thread to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            // A panic in the thread has already been reported; nothing left to clean up
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for PlaceholderSender {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Sends the frame of the current pattern once per `interval` until stopped.
+fn send_loop(
+    sender: &Sender,
+    pattern: &AtomicU8,
+    stop: &AtomicBool,
+    frames: &[OwnedMediaFrame; 2],
+    interval: Duration,
+) {
+    let mut next = Instant::now();
+    while !stop.load(Ordering::Relaxed) {
+        let frame = &frames[pattern.load(Ordering::Relaxed) as usize];
+        // A failed send only drops this frame; keep the source alive
+        let _ = sender.send(&frame.as_media_frame());
+
+        next += interval;
+        let now = Instant::now();
+        if next > now {
+            thread::sleep(next - now);
+        } else {
+            // Fell behind, e.g. while encoding; don't try to catch up
+            next = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_color_bars() {
+        let data = PlaceholderPattern::ColorBars.render(16, 2);
+        assert_eq!(data.len(), 16 * 2 * 4);
+
+        let row = &data[..16 * 4];
+        for (x, pixel) in row.chunks_exact(4).enumerate() {
+            assert_eq!(pixel, COLOR_BARS[x / 2]);
+        }
+        assert_eq!(&data[16 * 4..], row);
+    }
+
+    #[test]
+    fn test_render_black() {
+        let data = PlaceholderPattern::Black.render(3, 3);
+        assert!(data.chunks_exact(4).all(|pixel| pixel == [0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_pattern_round_trip() {
+        for pattern in [PlaceholderPattern::ColorBars, PlaceholderPattern::Black] {
+            assert_eq!(PlaceholderPattern::from_u8(pattern.to_u8()), pattern);
+        }
+    }
+}
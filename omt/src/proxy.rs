@@ -0,0 +1,78 @@
+//! OMT proxy for relaying a source under a new name.
+
+use crate::error::Result;
+use crate::receiver::Receiver;
+use crate::sender::Sender;
+use crate::types::{FrameType, PreferredVideoFormat, Quality, ReceiveFlags};
+
+/// Relays video, audio, and metadata from an existing source through a new sender.
+///
+/// Frames are received from the source and immediately handed to the internal
+/// [`Sender`] while still borrowed from the [`Receiver`], so no frame data is
+/// copied on the Rust side.
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::{Proxy, Quality};
+///
+/// let mut proxy = Proxy::new("omt://hostname:6400", "Relayed Camera", Quality::Default)?;
+/// loop {
+///     proxy.forward(1000)?;
+/// }
+/// # Ok::<(), omt::Error>(())
+/// ```
+pub struct Proxy {
+    receiver: Receiver,
+    sender: Sender,
+}
+
+impl Proxy {
+    /// Creates a proxy receiving from `source` and re-sending as `dest_name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Address of the source. Either the full name from discovery
+    ///   or a URL in the format `omt://hostname:port`
+    /// * `dest_name` - Name of the relayed source (not including hostname)
+    /// * `quality` - Encoding quality of the relayed source
+    pub fn new(source: &str, dest_name: &str, quality: Quality) -> Result<Self> {
+        let receiver = Receiver::new(
+            source,
+            FrameType::ALL,
+            PreferredVideoFormat::UyvyOrBgra,
+            ReceiveFlags::NONE,
+        )?;
+        let sender = Sender::new(dest_name, quality)?;
+
+        Ok(Self { receiver, sender })
+    }
+
+    /// Receives one frame from the source and forwards it to the proxy's output.
+    ///
+    /// Waits up to `timeout_ms` for a video, audio, or metadata frame.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some(frame_type))` with the type of the forwarded frame, or
+    /// `Ok(None)` if no frame arrived before the timeout.
+    pub fn forward(&mut self, timeout_ms: i32) -> Result<Option<FrameType>> {
+        match self.receiver.receive(FrameType::ALL, timeout_ms)? {
+            Some(frame) => {
+                self.sender.send(&frame)?;
+                Ok(Some(frame.frame_type()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the receiver connected to the source.
+    pub fn receiver(&self) -> &Receiver {
+        &self.receiver
+    }
+
+    /// Returns the sender publishing the relayed source.
+    pub fn sender(&self) -> &Sender {
+        &self.sender
+    }
+}
@@ -2,11 +2,18 @@
 
 use crate::error::{Error, Result};
 use crate::frame::MediaFrame;
+use crate::frame_builder::OwnedMediaFrame;
+use crate::receiver_channels::{FrameReceiver, ReceiverChannels, ReceiverHandle};
 use crate::statistics::Statistics;
 use crate::tally::Tally;
+use crate::thread_options::ThreadOptions;
 use crate::types::{FrameType, PreferredVideoFormat, Quality, ReceiveFlags, SenderInfo};
 use std::ffi::CString;
+use std::net::{TcpStream, ToSocketAddrs};
 use std::ptr::NonNull;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 /// Receiver for connecting to and receiving media from an OMT sender.
 ///
@@ -28,6 +35,25 @@ use std::ptr::NonNull;
 /// For most use cases, prefer `receive` for compile-time safety.
 pub struct Receiver {
     handle: NonNull<omt_sys::omt_receive_t>,
+    // Readings subtracted from the statistics, set by the reset methods
+    video_baseline: Mutex<Statistics>,
+    audio_baseline: Mutex<Statistics>,
+    resolution: Mutex<ResolutionTracker>,
+    // Set once sender information was seen, to tell a lost connection from a pending one
+    was_connected: AtomicBool,
+}
+
+/// Connection state of a [`Receiver`], see [`Receiver::connection_state()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// The receiver has not connected to the sender yet.
+    Connecting,
+    /// The receiver is connected to the sender.
+    Connected,
+    /// The receiver was connected, but the connection was lost.
+    ///
+    /// libomt keeps trying to reconnect in the background.
+    Disconnected,
 }
 
 impl Receiver {
@@ -72,10 +98,80 @@ impl Receiver {
         };
 
         NonNull::new(handle as *mut _)
-            .map(|handle| Self { handle })
+            .map(|handle| Self {
+                handle,
+                video_baseline: Mutex::default(),
+                audio_baseline: Mutex::default(),
+                resolution: Mutex::default(),
+                was_connected: AtomicBool::new(false),
+            })
             .ok_or(Error::ReceiverCreateFailed)
     }
 
+    /// Creates a receiver for the first reachable of several addresses.
+    ///
+    /// A source may be reachable through more than one address, e.g. one URL
+    /// per network interface. The addresses are tried in order:
+    ///
+    /// - URLs in the format `omt://hostname:port` are probed with a TCP
+    ///   connection attempt that may take up to `probe_timeout` per resolved
+    ///   socket address. Unreachable URLs are skipped.
+    /// - Discovery names in the format `"HOSTNAME (NAME)"` can only be resolved
+    ///   by libomt and are accepted without probing.
+    ///
+    /// The receiver is created for the first accepted address and connects in
+    /// the background like [`new()`](Self::new). Put preferred addresses first
+    /// and discovery names last, since they end the search.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `addresses` is empty, and
+    /// [`Error::ConnectFailed`] with the last failure if no address could be
+    /// reached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::{FrameType, PreferredVideoFormat, ReceiveFlags, Receiver};
+    /// use std::time::Duration;
+    ///
+    /// let receiver = Receiver::create_any(
+    ///     &["omt://10.0.0.5:6400", "omt://192.168.1.5:6400"],
+    ///     FrameType::VIDEO,
+    ///     PreferredVideoFormat::Uyvy,
+    ///     ReceiveFlags::NONE,
+    ///     Duration::from_millis(500),
+    /// )?;
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn create_any(
+        addresses: &[&str],
+        frame_types: FrameType,
+        format: PreferredVideoFormat,
+        flags: ReceiveFlags,
+        probe_timeout: Duration,
+    ) -> Result<Self> {
+        let mut last_error = Error::InvalidParameter {
+            parameter: "addresses".to_string(),
+            reason: "at least one address is required".to_string(),
+        };
+
+        for address in addresses {
+            if address.starts_with("omt://")
+                && let Err(reason) = probe_url(address, probe_timeout)
+            {
+                last_error = Error::ConnectFailed {
+                    address: address.to_string(),
+                    reason,
+                };
+                continue;
+            }
+            return Self::new(address, frame_types, format, flags);
+        }
+
+        Err(last_error)
+    }
+
     /// Receives a frame of the specified type(s) - safe version.
     ///
     /// This is the recommended API that requires mutable access to the receiver.
@@ -128,7 +224,51 @@ impl Receiver {
         // SAFETY: The C API guarantees the frame data is valid until the next call to omt_receive.
         // The lifetime bound to &mut self ensures the frame cannot outlive this receiver instance
         // and prevents calling receive again while a frame exists (enforced by borrow checker).
-        Ok(unsafe { MediaFrame::from_ffi_ptr(ptr) })
+        let frame = unsafe { MediaFrame::from_ffi_ptr(ptr) };
+        self.track_resolution(frame.as_ref());
+        Ok(frame)
+    }
+
+    /// Receives a video frame.
+    ///
+    /// Like [`receive(FrameType::VIDEO, timeout_ms)`](Self::receive): only video
+    /// frames are requested, so a returned frame is always a video frame. The
+    /// frame is valid until the next call to any receive method on this receiver.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let mut receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO | FrameType::AUDIO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// if let Some(frame) = receiver.receive_video(1000)? {
+    ///     println!("Received {}x{}", frame.width(), frame.height());
+    /// }
+    /// if let Some(frame) = receiver.receive_audio(0)? {
+    ///     println!("Received {} samples", frame.samples_per_channel());
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn receive_video(&mut self, timeout_ms: i32) -> Result<Option<MediaFrame<'_>>> {
+        self.receive(FrameType::VIDEO, timeout_ms)
+    }
+
+    /// Receives an audio frame.
+    ///
+    /// Like [`receive(FrameType::AUDIO, timeout_ms)`](Self::receive): only audio
+    /// frames are requested, so a returned frame is always an audio frame. The
+    /// frame is valid until the next call to any receive method on this receiver.
+    pub fn receive_audio(&mut self, timeout_ms: i32) -> Result<Option<MediaFrame<'_>>> {
+        self.receive(FrameType::AUDIO, timeout_ms)
+    }
+
+    /// Receives a metadata frame.
+    ///
+    /// Like [`receive(FrameType::METADATA, timeout_ms)`](Self::receive): only
+    /// metadata frames are requested, so a returned frame is always a metadata
+    /// frame. The frame is valid until the next call to any receive method on
+    /// this receiver.
+    pub fn receive_metadata(&mut self, timeout_ms: i32) -> Result<Option<MediaFrame<'_>>> {
+        self.receive(FrameType::METADATA, timeout_ms)
     }
 
     /// Receives a frame of the specified type(s) - unsafe version.
@@ -231,15 +371,283 @@ impl Receiver {
 
         // SAFETY: Caller must ensure no previous frame from this receiver is still alive.
         // The C API reuses the frame buffer on each call to omt_receive.
-        Ok(unsafe { MediaFrame::from_ffi_ptr(ptr) })
+        let frame = unsafe { MediaFrame::from_ffi_ptr(ptr) };
+        self.track_resolution(frame.as_ref());
+        Ok(frame)
     }
 
-    /// Sends a metadata frame to the sender.
+    /// Receives a frame of the specified type(s) as an owned copy.
+    ///
+    /// Unlike [`receive`](Self::receive), the returned frame does not borrow the
+    /// receiver, so it remains valid across further receive calls and can be
+    /// stored or sent to another thread. This is the simplest way to receive
+    /// frames when the copy is affordable.
+    ///
+    /// # Performance
     ///
-    /// Only metadata frames are supported for sending from a receiver.
-    pub fn send_metadata(&self, _frame: &MediaFrame<'_>) -> Result<bool> {
-        // TODO: Implement when we have a MediaFrame builder
-        Ok(false)
+    /// The frame data, compressed data and per-frame metadata are deep-copied
+    /// on every call, which for uncompressed 4K video is tens of megabytes per
+    /// frame. Use [`receive`](Self::receive) in hot loops that process frames
+    /// in place.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some(frame))` if a frame was received, `Ok(None)` if timed out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let mut receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let first = receiver.receive_owned(FrameType::VIDEO, 1000)?;
+    /// let second = receiver.receive_owned(FrameType::VIDEO, 1000)?;
+    /// if let (Some(first), Some(second)) = (first, second) {
+    ///     println!("Frames {} ticks apart", second.timestamp() - first.timestamp());
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn receive_owned(
+        &mut self,
+        frame_types: FrameType,
+        timeout_ms: i32,
+    ) -> Result<Option<OwnedMediaFrame>> {
+        Ok(self
+            .receive(frame_types, timeout_ms)?
+            .map(|frame| OwnedMediaFrame::from_received(&frame)))
+    }
+
+    /// Returns a streaming iterator over received frames.
+    ///
+    /// Replaces a `loop` around [`receive()`](Self::receive): each call to
+    /// [`ReceivedFrames::next_frame()`] waits for the next frame of the given
+    /// type(s), retrying every `timeout_ms` until one arrives. Receiving ends
+    /// after the first error, which is yielded before `None`.
+    ///
+    /// Each frame is only valid until the next call to `next_frame()`, so
+    /// `ReceivedFrames` cannot implement [`Iterator`]. Use a `while let` loop
+    /// and copy frames with [`OwnedMediaFrame::from_received()`] to keep them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let mut receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let mut frames = receiver.frames(FrameType::VIDEO, 100);
+    /// while let Some(frame) = frames.next_frame() {
+    ///     let frame = frame?;
+    ///     if frame.width() == 0 {
+    ///         break; // Stop early; the receiver can be used again afterwards
+    ///     }
+    ///     println!("Received {}x{}", frame.width(), frame.height());
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn frames(&mut self, frame_types: FrameType, timeout_ms: i32) -> ReceivedFrames<'_> {
+        ReceivedFrames {
+            receiver: self,
+            frame_types,
+            timeout_ms,
+            done: false,
+        }
+    }
+
+    /// Receives a batch of frames of the specified type(s).
+    ///
+    /// Waits up to `timeout_ms` for the first frame, then drains frames that are
+    /// already buffered without waiting, until `max` frames have been collected
+    /// or no further frame is immediately available. This amortizes per-call
+    /// overhead for bursty sources.
+    ///
+    /// Each frame is deep-copied into an [`OwnedMediaFrame`] so that all frames
+    /// in the batch can be held at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_types` - The frame types to receive. Can combine multiple types.
+    /// * `timeout_ms` - Maximum time to wait for the first frame in milliseconds.
+    /// * `max` - Maximum number of frames to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns the received frames in arrival order. The vector is empty if the
+    /// first receive timed out or `max` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let mut receiver = Receiver::new("omt://localhost:6400", FrameType::AUDIO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let frames = receiver.recv_batch(FrameType::AUDIO, 100, 16)?;
+    /// for frame in &frames {
+    ///     println!("Received {} bytes", frame.data().len());
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn recv_batch(
+        &mut self,
+        frame_types: FrameType,
+        timeout_ms: i32,
+        max: usize,
+    ) -> Result<Vec<OwnedMediaFrame>> {
+        let mut frames = Vec::new();
+        let mut timeout = timeout_ms;
+
+        while frames.len() < max {
+            match self.receive(frame_types, timeout)? {
+                Some(frame) => frames.push(OwnedMediaFrame::from_received(&frame)),
+                None => break,
+            }
+            // Only the first frame is waited for; the rest must already be buffered
+            timeout = 0;
+        }
+
+        Ok(frames)
+    }
+
+    /// Moves the receiver onto a background thread that sorts frames by type.
+    ///
+    /// The thread receives video, audio, and metadata frames, waiting up to
+    /// `timeout_ms` per call so that it notices a stop request in time, and sends
+    /// each frame to the matching channel of the returned [`ReceiverChannels`].
+    /// This lets separate threads process each type. Use the returned
+    /// [`ReceiverHandle`] to stop the thread; dropping it does the same.
+    ///
+    /// Only the frame types the receiver was created with are delivered. A type
+    /// whose channel receiver is dropped is discarded, and the thread stops once
+    /// all three are dropped.
+    ///
+    /// # Performance
+    ///
+    /// Every frame is deep-copied into an [`OwnedMediaFrame`] before it is sent,
+    /// since received frames are only valid until the next receive call. For
+    /// uncompressed 4K video this is tens of megabytes per frame. Channels are
+    /// unbounded, so a consumer that falls behind causes frames to accumulate.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let receiver = Receiver::new("omt://localhost:6400", FrameType::ALL, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let (handle, channels) = receiver.into_channels(100);
+    ///
+    /// let audio = channels.audio;
+    /// let audio_thread = std::thread::spawn(move || {
+    ///     for frame in audio {
+    ///         println!("Audio frame at {}", frame.timestamp());
+    ///     }
+    /// });
+    ///
+    /// for frame in channels.video.iter().take(100) {
+    ///     println!("Video frame with {} bytes", frame.data().len());
+    /// }
+    ///
+    /// handle.stop();
+    /// audio_thread.join().unwrap();
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn into_channels(self, timeout_ms: i32) -> (ReceiverHandle, ReceiverChannels) {
+        self.into_channels_with_options(timeout_ms, ThreadOptions::default())
+    }
+
+    /// Like [`into_channels()`](Self::into_channels), with priority and CPU
+    /// affinity hints for the receive thread.
+    ///
+    /// The hints are advisory, see [`ThreadOptions`].
+    pub fn into_channels_with_options(
+        self,
+        timeout_ms: i32,
+        options: ThreadOptions,
+    ) -> (ReceiverHandle, ReceiverChannels) {
+        ReceiverHandle::spawn(self, timeout_ms, &options)
+    }
+
+    /// Moves the receiver onto a background thread feeding a bounded queue.
+    ///
+    /// The thread receives `frame_types`, copies each frame into an
+    /// [`OwnedMediaFrame`] and queues it on the returned [`FrameReceiver`],
+    /// which holds up to `buffer` frames (at least one). When the queue is
+    /// full, the oldest frame is dropped, so a slow consumer never stalls
+    /// receiving. This suits async runtimes: receive on a blocking task or
+    /// poll with [`FrameReceiver::try_recv()`].
+    ///
+    /// Dropping the [`ReceiverHandle`] or the `FrameReceiver` stops the
+    /// thread, within about 100 milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let (handle, frames) = receiver.into_channel(FrameType::VIDEO, 4);
+    ///
+    /// for frame in frames.iter().take(100) {
+    ///     println!("Video frame at {}", frame.timestamp());
+    /// }
+    /// println!("Dropped {} frames", frames.dropped());
+    ///
+    /// handle.stop();
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn into_channel(
+        self,
+        frame_types: FrameType,
+        buffer: usize,
+    ) -> (ReceiverHandle, FrameReceiver) {
+        ReceiverHandle::spawn_queue(self, frame_types, buffer)
+    }
+
+    /// Sends a metadata frame back to the sender.
+    ///
+    /// Returns `true` if the frame was sent, `false` if it was not, e.g.
+    /// because the receiver is not connected yet. See [`send()`](Self::send)
+    /// for the errors.
+    pub fn send_metadata(&self, frame: &MediaFrame<'_>) -> Result<bool> {
+        Ok(self.send(frame)? > 0)
+    }
+
+    /// Sends a metadata frame back to the sender, returning the native result.
+    ///
+    /// Returns the number of bytes sent, which is `0` if the frame was not
+    /// sent, e.g. because the receiver is not connected yet. The sender reads
+    /// the frame with [`Sender::receive_metadata()`](crate::Sender::receive_metadata).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFrameType`] for anything but a metadata frame,
+    /// as only metadata can flow from receiver to sender.
+    ///
+    /// Returns [`Error::Native`] with the return code if libomt reports a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, MetadataFrameBuilder, PreferredVideoFormat, ReceiveFlags};
+    /// # let receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// let request = MetadataFrameBuilder::new()
+    ///     .metadata(r#"<ptz pan="10" />"#)
+    ///     .build()?;
+    /// match receiver.send(&request.as_media_frame()) {
+    ///     Ok(0) => println!("Not connected, request dropped"),
+    ///     Ok(bytes) => println!("Sent {} bytes", bytes),
+    ///     Err(e) => eprintln!("Send failed ({}): {}", e.name(), e),
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn send(&self, frame: &MediaFrame<'_>) -> Result<usize> {
+        if frame.frame_type() != FrameType::METADATA {
+            return Err(Error::InvalidFrameType);
+        }
+
+        let mut ffi = *frame.as_ffi();
+        // SAFETY: The handle is valid for the lifetime of self, and the frame
+        // data referenced by ffi is borrowed from frame for the whole call.
+        let result = unsafe {
+            omt_sys::omt_receive_send(self.handle.as_ptr() as *mut _, &mut ffi as *mut _)
+        };
+        if result < 0 {
+            return Err(Error::Native(result));
+        }
+        Ok(result as usize)
     }
 
     /// Sets the tally state for this receiver.
@@ -297,6 +705,48 @@ impl Receiver {
         }
     }
 
+    /// Returns whether the receiver is currently connected to the sender.
+    ///
+    /// See [`connection_state()`](Self::connection_state) for how this is
+    /// determined.
+    pub fn is_connected(&self) -> bool {
+        self.connection_state() == ConnectionState::Connected
+    }
+
+    /// Returns the current connection state.
+    ///
+    /// libomt connects on demand and does not report connection changes, so
+    /// this polls [`get_sender_information()`](Self::get_sender_information):
+    /// the receiver counts as connected while sender information is available.
+    /// Call it periodically, e.g. once per UI frame, to drive a connection
+    /// indicator. Senders that never publish sender information therefore
+    /// remain [`Connecting`](ConnectionState::Connecting).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{ConnectionState, Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// match receiver.connection_state() {
+    ///     ConnectionState::Connecting => println!("Connecting..."),
+    ///     ConnectionState::Connected => println!("Live"),
+    ///     ConnectionState::Disconnected => println!("Signal lost, reconnecting..."),
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn connection_state(&self) -> ConnectionState {
+        // Information that is not valid UTF-8 still comes from a connected sender
+        let connected = !matches!(self.get_sender_information(), Ok(None));
+        if connected {
+            self.was_connected.store(true, Ordering::Relaxed);
+            ConnectionState::Connected
+        } else if self.was_connected.load(Ordering::Relaxed) {
+            ConnectionState::Disconnected
+        } else {
+            ConnectionState::Connecting
+        }
+    }
+
     /// Retrieves information about the sender.
     ///
     /// Returns `None` if disconnected or no sender information is available.
@@ -318,7 +768,97 @@ impl Receiver {
     }
 
     /// Retrieves video statistics.
+    ///
+    /// Totals count from the last call to
+    /// [`reset_video_statistics()`](Self::reset_video_statistics), or from
+    /// connection start if it was never called.
     pub fn get_video_statistics(&self) -> Statistics {
+        let baseline = *self
+            .video_baseline
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        self.raw_video_statistics().relative_to(&baseline)
+    }
+
+    /// Retrieves audio statistics.
+    ///
+    /// Totals count from the last call to
+    /// [`reset_audio_statistics()`](Self::reset_audio_statistics), or from
+    /// connection start if it was never called.
+    pub fn get_audio_statistics(&self) -> Statistics {
+        let baseline = *self
+            .audio_baseline
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        self.raw_audio_statistics().relative_to(&baseline)
+    }
+
+    /// Restarts the video statistics totals from zero.
+    ///
+    /// libomt cannot reset its counters, so the current reading is stored and
+    /// subtracted from subsequent [`get_video_statistics()`](Self::get_video_statistics)
+    /// results. Note that reading the statistics also resets the library's
+    /// `*_since_last` counters.
+    pub fn reset_video_statistics(&self) {
+        let current = self.raw_video_statistics();
+        *self
+            .video_baseline
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = current;
+    }
+
+    /// Restarts the audio statistics totals from zero.
+    ///
+    /// See [`reset_video_statistics()`](Self::reset_video_statistics).
+    pub fn reset_audio_statistics(&self) {
+        let current = self.raw_audio_statistics();
+        *self
+            .audio_baseline
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = current;
+    }
+
+    /// Returns `true` if the video resolution changed since the last call.
+    ///
+    /// The flag is set when a received video frame's width or height differs
+    /// from the previous video frame's, and cleared by reading it. Renderers can
+    /// poll it after each receive to reallocate conversion buffers and textures
+    /// only when needed. The first video frame does not set it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Receiver, FrameType, PreferredVideoFormat, ReceiveFlags};
+    /// # let mut receiver = Receiver::new("omt://localhost:6400", FrameType::VIDEO, PreferredVideoFormat::Uyvy, ReceiveFlags::NONE)?;
+    /// while let Some(frame) = receiver.receive_owned(FrameType::VIDEO, 1000)? {
+    ///     let frame = frame.as_media_frame();
+    ///     if receiver.resolution_changed() {
+    ///         println!("Reallocating for {}x{}", frame.width(), frame.height());
+    ///     }
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn resolution_changed(&self) -> bool {
+        self.resolution
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take_changed()
+    }
+
+    /// Records the dimensions of a received video frame.
+    fn track_resolution(&self, frame: Option<&MediaFrame<'_>>) {
+        if let Some(frame) = frame
+            && frame.frame_type() == FrameType::VIDEO
+        {
+            self.resolution
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .update(frame.width(), frame.height());
+        }
+    }
+
+    /// Retrieves video statistics as reported by the library.
+    fn raw_video_statistics(&self) -> Statistics {
         let mut ffi_stats = unsafe { std::mem::zeroed() };
         unsafe {
             omt_sys::omt_receive_getvideostatistics(
@@ -329,8 +869,8 @@ impl Receiver {
         Statistics::from_ffi(&ffi_stats)
     }
 
-    /// Retrieves audio statistics.
-    pub fn get_audio_statistics(&self) -> Statistics {
+    /// Retrieves audio statistics as reported by the library.
+    fn raw_audio_statistics(&self) -> Statistics {
         let mut ffi_stats = unsafe { std::mem::zeroed() };
         unsafe {
             omt_sys::omt_receive_getaudiostatistics(
@@ -342,6 +882,28 @@ impl Receiver {
     }
 }
 
+/// Dimensions of the last received video frame and whether they changed.
+#[derive(Debug, Default)]
+struct ResolutionTracker {
+    last: Option<(i32, i32)>,
+    changed: bool,
+}
+
+impl ResolutionTracker {
+    /// Records a frame's dimensions, setting the flag if they differ from the last.
+    fn update(&mut self, width: i32, height: i32) {
+        if self.last.is_some_and(|last| last != (width, height)) {
+            self.changed = true;
+        }
+        self.last = Some((width, height));
+    }
+
+    /// Returns the flag and clears it.
+    fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+}
+
 impl Drop for Receiver {
     fn drop(&mut self) {
         unsafe {
@@ -353,3 +915,140 @@ impl Drop for Receiver {
 // SAFETY: The underlying C library is thread-safe
 unsafe impl Send for Receiver {}
 unsafe impl Sync for Receiver {}
+
+/// Streaming iterator over the frames of a [`Receiver`].
+///
+/// Returned by [`Receiver::frames()`].
+pub struct ReceivedFrames<'a> {
+    receiver: &'a mut Receiver,
+    frame_types: FrameType,
+    timeout_ms: i32,
+    done: bool,
+}
+
+impl ReceivedFrames<'_> {
+    /// Waits for the next frame, skipping timeouts.
+    ///
+    /// Returns `Some(Err(_))` once if receiving fails and `None` afterwards.
+    /// The frame is valid until the next call.
+    pub fn next_frame(&mut self) -> Option<Result<MediaFrame<'_>>> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            // SAFETY: `self` holds the only (mutable) borrow of the receiver, and
+            // the returned frame borrows `self` mutably, so no other frame from
+            // this receiver can be alive when receiving again.
+            match unsafe {
+                self.receiver
+                    .receive_unchecked(self.frame_types, self.timeout_ms)
+            } {
+                Ok(Some(frame)) => return Some(Ok(frame)),
+                Ok(None) => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Checks that an address in the format `omt://hostname:port` accepts TCP
+/// connections within `timeout` per resolved socket address.
+pub(crate) fn probe_url(url: &str, timeout: Duration) -> std::result::Result<(), String> {
+    let host_port = url
+        .strip_prefix("omt://")
+        .ok_or_else(|| "address must be in the format omt://hostname:port".to_string())?
+        .trim_end_matches('/');
+
+    let socket_addrs = host_port
+        .to_socket_addrs()
+        .map_err(|e| format!("cannot resolve '{}': {}", host_port, e))?;
+
+    let mut last_error = format!("'{}' did not resolve to any address", host_port);
+    for addr in socket_addrs {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(_) => return Ok(()),
+            Err(e) => last_error = format!("cannot connect to {}: {}", addr, e),
+        }
+    }
+
+    Err(last_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolution_tracker() {
+        let mut tracker = ResolutionTracker::default();
+        tracker.update(1920, 1080);
+        assert!(!tracker.take_changed());
+
+        tracker.update(1920, 1080);
+        assert!(!tracker.take_changed());
+
+        tracker.update(1280, 720);
+        tracker.update(1280, 720);
+        assert!(tracker.take_changed());
+        assert!(!tracker.take_changed());
+    }
+
+    #[test]
+    fn test_probe_url_malformed() {
+        let timeout = Duration::from_millis(100);
+        assert!(probe_url("server:6400", timeout).is_err());
+        assert!(probe_url("omt://no-port", timeout).is_err());
+    }
+
+    #[test]
+    fn test_probe_url_reachable() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let url = format!("omt://127.0.0.1:{}", port);
+        assert!(probe_url(&url, Duration::from_millis(100)).is_ok());
+    }
+
+    #[test]
+    fn test_create_any_without_addresses() {
+        let result = Receiver::create_any(
+            &[],
+            FrameType::VIDEO,
+            PreferredVideoFormat::Uyvy,
+            ReceiveFlags::NONE,
+            Duration::from_millis(100),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::InvalidParameter { ref parameter, .. }) if parameter == "addresses"
+        ));
+    }
+
+    #[test]
+    fn test_create_any_reports_last_unreachable_address() {
+        let closed_url = || {
+            let listener =
+                std::net::TcpListener::bind("127.0.0.1:0").expect("Failed to bind listener");
+            let port = listener.local_addr().expect("No local address").port();
+            format!("omt://127.0.0.1:{}", port)
+        };
+        let first = closed_url();
+        let last = closed_url();
+
+        let result = Receiver::create_any(
+            &[&first, &last],
+            FrameType::VIDEO,
+            PreferredVideoFormat::Uyvy,
+            ReceiveFlags::NONE,
+            Duration::from_millis(100),
+        );
+        match result {
+            Err(Error::ConnectFailed { address, .. }) => assert_eq!(address, last),
+            Err(e) => panic!("Expected ConnectFailed, got {:?}", e),
+            Ok(_) => panic!("Expected ConnectFailed, got a receiver"),
+        }
+    }
+}
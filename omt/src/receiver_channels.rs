@@ -0,0 +1,460 @@
+//! Background receive thread routing frames onto channels.
+
+use crate::frame_builder::OwnedMediaFrame;
+use crate::receiver::Receiver;
+use crate::thread_options::ThreadOptions;
+use crate::types::FrameType;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvError, RecvTimeoutError, Sender, TryRecvError};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Receive timeout of the [`Receiver::into_channel()`] thread, bounding how
+/// long stopping it takes.
+const QUEUE_POLL_MS: i32 = 100;
+
+/// Channels carrying the frames received by a background receive thread.
+///
+/// Returned by [`Receiver::into_channels()`] together with a
+/// [`ReceiverHandle`]. Each channel disconnects once the thread stops.
+#[derive(Debug)]
+pub struct ReceiverChannels {
+    /// Received video frames.
+    pub video: mpsc::Receiver<OwnedMediaFrame>,
+    /// Received audio frames.
+    pub audio: mpsc::Receiver<OwnedMediaFrame>,
+    /// Received metadata frames.
+    pub metadata: mpsc::Receiver<OwnedMediaFrame>,
+}
+
+/// Controls the background receive thread started by [`Receiver::into_channels()`]
+/// or [`Receiver::into_channel()`].
+///
+/// Dropping the handle stops the thread and waits for it to finish, which can
+/// take up to the receive timeout.
+pub struct ReceiverHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ReceiverHandle {
+    /// Starts a thread receiving from `receiver` and routing frames by type.
+    pub(crate) fn spawn(
+        receiver: Receiver,
+        timeout_ms: i32,
+        options: &ThreadOptions,
+    ) -> (Self, ReceiverChannels) {
+        let (video_tx, video) = mpsc::channel();
+        let (audio_tx, audio) = mpsc::channel();
+        let (metadata_tx, metadata) = mpsc::channel();
+
+        let handle = Self::start(receiver, options, move |receiver, stop| {
+            receive_loop(receiver, stop, timeout_ms, video_tx, audio_tx, metadata_tx)
+        });
+
+        (
+            handle,
+            ReceiverChannels {
+                video,
+                audio,
+                metadata,
+            },
+        )
+    }
+
+    /// Starts a thread receiving `frame_types` from `receiver` into a queue
+    /// holding at most `capacity` frames.
+    pub(crate) fn spawn_queue(
+        receiver: Receiver,
+        frame_types: FrameType,
+        capacity: usize,
+    ) -> (Self, FrameReceiver) {
+        let queue = Arc::new(FrameQueue {
+            state: Mutex::new(QueueState {
+                frames: VecDeque::with_capacity(capacity.max(1)),
+                capacity: capacity.max(1),
+                dropped: 0,
+                producer_done: false,
+                consumer_done: false,
+            }),
+            ready: Condvar::new(),
+        });
+
+        let handle = {
+            let queue = Arc::clone(&queue);
+            Self::start(
+                receiver,
+                &ThreadOptions::default(),
+                move |receiver, stop| queue_loop(receiver, stop, frame_types, &queue),
+            )
+        };
+
+        (handle, FrameReceiver { queue })
+    }
+
+    /// Spawns `body` on a thread that takes ownership of the receiver and
+    /// shares the stop flag.
+    fn start(
+        receiver: Receiver,
+        options: &ThreadOptions,
+        body: impl FnOnce(&Receiver, &AtomicBool) + Send + 'static,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = Arc::clone(&stop);
+            options.spawn(move || body(&receiver, &stop))
+        };
+
+        Self {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// Returns whether the receive thread is still running.
+    ///
+    /// The thread ends when stopped, when all channels have been dropped, or
+    /// when receiving fails.
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
+
+    /// Stops the receive thread and waits for it to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            // A panic in the thread has already been reported; nothing left to clean up
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ReceiverHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Receives frames until stopped, copying each one onto the channel for its type.
+fn receive_loop(
+    receiver: &Receiver,
+    stop: &AtomicBool,
+    timeout_ms: i32,
+    video: Sender<OwnedMediaFrame>,
+    audio: Sender<OwnedMediaFrame>,
+    metadata: Sender<OwnedMediaFrame>,
+) {
+    let mut open = [true; 3];
+
+    while !stop.load(Ordering::Relaxed) && open.contains(&true) {
+        // SAFETY: This thread owns the receiver and no reference to it is
+        // handed out, so no other receive call can run concurrently. Each
+        // frame is copied and dropped before the next call.
+        let frame = match unsafe { receiver.receive_unchecked(FrameType::ALL, timeout_ms) } {
+            Ok(Some(frame)) => frame,
+            Ok(None) => continue,
+            Err(_) => break,
+        };
+
+        let (index, channel) = match frame.frame_type() {
+            FrameType::VIDEO => (0, &video),
+            FrameType::AUDIO => (1, &audio),
+            FrameType::METADATA => (2, &metadata),
+            _ => continue,
+        };
+        if open[index] {
+            // A dropped channel receiver just means nobody wants this type
+            open[index] = channel.send(OwnedMediaFrame::from_received(&frame)).is_ok();
+        }
+    }
+}
+
+/// Receiving end of the bounded queue filled by [`Receiver::into_channel()`].
+///
+/// Mirrors [`std::sync::mpsc::Receiver`], except that the queue never blocks
+/// the receive thread: when it is full, the oldest frame is dropped to make
+/// room, so a slow consumer always sees the most recent frames. Once the
+/// thread stops, the remaining frames can still be received, after which
+/// receiving fails with a disconnect error.
+///
+/// Dropping the `FrameReceiver` ends the receive thread.
+#[derive(Debug)]
+pub struct FrameReceiver {
+    queue: Arc<FrameQueue>,
+}
+
+impl FrameReceiver {
+    /// Blocks until a frame is available.
+    ///
+    /// Fails once the queue is empty and the receive thread has stopped.
+    pub fn recv(&self) -> Result<OwnedMediaFrame, RecvError> {
+        let mut state = self.queue.lock();
+        loop {
+            if let Some(frame) = state.frames.pop_front() {
+                return Ok(frame);
+            }
+            if state.producer_done {
+                return Err(RecvError);
+            }
+            state = self
+                .queue
+                .ready
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    /// Returns a frame if one is available, without blocking.
+    pub fn try_recv(&self) -> Result<OwnedMediaFrame, TryRecvError> {
+        let mut state = self.queue.lock();
+        match state.frames.pop_front() {
+            Some(frame) => Ok(frame),
+            None if state.producer_done => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Waits up to `timeout` for a frame.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<OwnedMediaFrame, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.queue.lock();
+        loop {
+            if let Some(frame) = state.frames.pop_front() {
+                return Ok(frame);
+            }
+            if state.producer_done {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            state = self
+                .queue
+                .ready
+                .wait_timeout(state, remaining)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+    }
+
+    /// Returns an iterator blocking for each frame, ending once the receive
+    /// thread has stopped and the queue is drained.
+    pub fn iter(&self) -> impl Iterator<Item = OwnedMediaFrame> + '_ {
+        std::iter::from_fn(|| self.recv().ok())
+    }
+
+    /// Returns the number of frames dropped so far because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.queue.lock().dropped
+    }
+}
+
+impl Drop for FrameReceiver {
+    fn drop(&mut self) {
+        self.queue.lock().consumer_done = true;
+    }
+}
+
+/// Queue shared between the receive thread and its [`FrameReceiver`].
+#[derive(Debug)]
+struct FrameQueue {
+    state: Mutex<QueueState>,
+    ready: Condvar,
+}
+
+#[derive(Debug)]
+struct QueueState {
+    frames: VecDeque<OwnedMediaFrame>,
+    capacity: usize,
+    dropped: u64,
+    producer_done: bool,
+    consumer_done: bool,
+}
+
+impl FrameQueue {
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        // The state stays consistent even if a holder panicked
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Appends a frame, dropping the oldest one if full.
+    ///
+    /// Returns `false` once the consumer is gone.
+    fn push(&self, frame: OwnedMediaFrame) -> bool {
+        let mut state = self.lock();
+        if state.consumer_done {
+            return false;
+        }
+        if state.frames.len() >= state.capacity {
+            state.frames.pop_front();
+            state.dropped += 1;
+        }
+        state.frames.push_back(frame);
+        self.ready.notify_one();
+        true
+    }
+
+    /// Returns whether the consumer is gone.
+    fn is_closed(&self) -> bool {
+        self.lock().consumer_done
+    }
+
+    /// Wakes up the consumer for good once no more frames will be pushed.
+    fn finish(&self) {
+        self.lock().producer_done = true;
+        self.ready.notify_all();
+    }
+}
+
+/// Marks the queue finished when the receive thread exits, even by panic.
+struct FinishOnDrop<'a>(&'a FrameQueue);
+
+impl Drop for FinishOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.finish();
+    }
+}
+
+/// Receives frames until stopped, copying each one into the queue.
+fn queue_loop(receiver: &Receiver, stop: &AtomicBool, frame_types: FrameType, queue: &FrameQueue) {
+    fill_queue(stop, queue, || {
+        // SAFETY: This thread is the only one receiving from `receiver`, see
+        // `receive_loop`. Each frame is copied and dropped before the next call.
+        unsafe { receiver.receive_unchecked(frame_types, QUEUE_POLL_MS) }
+            .map(|frame| frame.map(|frame| OwnedMediaFrame::from_received(&frame)))
+    });
+}
+
+/// Pushes the frames returned by `receive` into the queue until stopped, the
+/// consumer is gone or receiving fails.
+fn fill_queue(
+    stop: &AtomicBool,
+    queue: &FrameQueue,
+    mut receive: impl FnMut() -> crate::Result<Option<OwnedMediaFrame>>,
+) {
+    let _finish = FinishOnDrop(queue);
+
+    // Checked after timeouts too, so an idle source cannot keep the thread alive
+    while !stop.load(Ordering::Relaxed) && !queue.is_closed() {
+        match receive() {
+            Ok(Some(frame)) => {
+                if !queue.push(frame) {
+                    break;
+                }
+            }
+            Ok(None) => {}
+            Err(_) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame_builder::MetadataFrameBuilder;
+
+    fn queue(capacity: usize) -> FrameReceiver {
+        FrameReceiver {
+            queue: Arc::new(FrameQueue {
+                state: Mutex::new(QueueState {
+                    frames: VecDeque::new(),
+                    capacity,
+                    dropped: 0,
+                    producer_done: false,
+                    consumer_done: false,
+                }),
+                ready: Condvar::new(),
+            }),
+        }
+    }
+
+    fn frame(timestamp: i64) -> OwnedMediaFrame {
+        MetadataFrameBuilder::new()
+            .metadata("<test/>")
+            .timestamp(timestamp)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_queue_drops_oldest_when_full() {
+        let receiver = queue(2);
+        for timestamp in 0..5 {
+            assert!(receiver.queue.push(frame(timestamp)));
+        }
+        assert_eq!(receiver.dropped(), 3);
+        assert_eq!(receiver.try_recv().unwrap().timestamp(), 3);
+        assert_eq!(receiver.try_recv().unwrap().timestamp(), 4);
+        assert_eq!(receiver.try_recv().unwrap_err(), TryRecvError::Empty);
+        assert_eq!(
+            receiver
+                .recv_timeout(Duration::from_millis(10))
+                .unwrap_err(),
+            RecvTimeoutError::Timeout
+        );
+    }
+
+    #[test]
+    fn test_queue_drains_after_finish() {
+        let receiver = queue(4);
+        receiver.queue.push(frame(1));
+        receiver.queue.finish();
+
+        assert_eq!(receiver.recv().unwrap().timestamp(), 1);
+        assert_eq!(receiver.recv().unwrap_err(), RecvError);
+        assert_eq!(receiver.try_recv().unwrap_err(), TryRecvError::Disconnected);
+    }
+
+    #[test]
+    fn test_queue_wakes_blocked_consumer() {
+        let receiver = queue(1);
+        let queue = Arc::clone(&receiver.queue);
+        let producer = std::thread::spawn(move || {
+            queue.push(frame(7));
+            queue.finish();
+        });
+
+        let frames: Vec<_> = receiver.iter().map(|f| f.timestamp()).collect();
+        producer.join().unwrap();
+        assert_eq!(frames, [7]);
+    }
+
+    #[test]
+    fn test_fill_queue_stops_when_idle_consumer_dropped() {
+        let receiver = queue(1);
+        let queue = Arc::clone(&receiver.queue);
+        let producer = std::thread::spawn(move || {
+            let stop = AtomicBool::new(false);
+            // An idle source: every receive times out
+            fill_queue(&stop, &queue, || {
+                std::thread::sleep(Duration::from_millis(10));
+                Ok(None)
+            });
+        });
+
+        drop(receiver);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !producer.is_finished() {
+            assert!(Instant::now() < deadline, "thread kept running");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        producer.join().unwrap();
+    }
+
+    #[test]
+    fn test_push_fails_after_consumer_dropped() {
+        let receiver = queue(1);
+        let queue = Arc::clone(&receiver.queue);
+        drop(receiver);
+        assert!(!queue.push(frame(0)));
+    }
+}
@@ -0,0 +1,356 @@
+//! Recording and playback of compressed VMX1 video.
+//!
+//! [`Recorder`] writes the VMX1 bitstream of received video frames to any
+//! [`Write`] implementation without decoding it, and [`CompressedPlayer`] reads
+//! such a recording back as frames that can be sent again.
+
+use crate::error::{Error, Result};
+use crate::frame::MediaFrame;
+use crate::frame_builder::{OwnedMediaFrame, VideoFrameBuilder};
+use crate::receiver::Receiver;
+use crate::types::{Codec, FrameType, VideoFlags};
+use std::io::{self, Read, Write};
+
+/// Magic bytes at the start of every recording.
+const MAGIC: &[u8; 4] = b"OMTR";
+
+/// Current version of the recording format.
+const VERSION: u8 = 1;
+
+/// Size of a record header in bytes.
+const RECORD_HEADER_LEN: usize = 8 + 4 * 5 + 4;
+
+/// Writes compressed VMX1 video frames to a recording.
+///
+/// Frames are taken from a [`Receiver`] created with
+/// [`ReceiveFlags::INCLUDE_COMPRESSED`](crate::ReceiveFlags::INCLUDE_COMPRESSED),
+/// which provides the original VMX1 data alongside the decoded frame, or from
+/// frames whose codec is [`Codec::Vmx1`]. Recordings are read back with
+/// [`CompressedPlayer`].
+///
+/// # File Format
+///
+/// A recording starts with the 4 byte magic `OMTR` followed by a format
+/// version byte (currently `1`). It is followed by one record per frame, with
+/// all integers in little-endian byte order:
+///
+/// | Field        | Type  | Description                             |
+/// |--------------|-------|-----------------------------------------|
+/// | `timestamp`  | `i64` | Frame timestamp in OMT ticks            |
+/// | `width`      | `i32` | Frame width in pixels                   |
+/// | `height`     | `i32` | Frame height in pixels                  |
+/// | `rate_n`     | `i32` | Frame rate numerator                    |
+/// | `rate_d`     | `i32` | Frame rate denominator                  |
+/// | `flags`      | `u32` | [`VideoFlags`] bits                     |
+/// | `length`     | `u32` | Number of VMX1 bytes that follow        |
+/// | `data`       | bytes | VMX1 bitstream of the frame             |
+///
+/// The length prefix allows skipping over frames without parsing them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::{FrameType, PreferredVideoFormat, ReceiveFlags, Receiver, Recorder};
+/// use std::fs::File;
+///
+/// let mut receiver = Receiver::new(
+///     "omt://hostname:6400",
+///     FrameType::VIDEO,
+///     PreferredVideoFormat::Uyvy,
+///     ReceiveFlags::INCLUDE_COMPRESSED,
+/// )?;
+/// let mut recorder = Recorder::new(File::create("camera.omtr")?)?;
+/// while recorder.frames_written() < 250 {
+///     recorder.record(&mut receiver, 1000)?;
+/// }
+/// # Ok::<(), omt::Error>(())
+/// ```
+pub struct Recorder<W: Write> {
+    writer: W,
+    frames_written: u64,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a recorder writing to `writer` and writes the recording header.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(Self {
+            writer,
+            frames_written: 0,
+        })
+    }
+
+    /// Writes the compressed data of a video frame.
+    ///
+    /// Uses the frame's [`compressed_data()`](MediaFrame::compressed_data) if
+    /// present, or its [`data()`](MediaFrame::data) if the frame's codec is VMX1.
+    ///
+    /// Returns `Ok(false)` without writing anything if the frame is not a video
+    /// frame or carries no VMX1 data.
+    pub fn write_frame(&mut self, frame: &MediaFrame<'_>) -> io::Result<bool> {
+        if frame.frame_type() != FrameType::VIDEO {
+            return Ok(false);
+        }
+        let data = match frame.compressed_data() {
+            [] if frame.codec() == Some(Codec::Vmx1) => frame.data(),
+            [] => return Ok(false),
+            compressed => compressed,
+        };
+        let length = u32::try_from(data.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+
+        let mut header = Vec::with_capacity(RECORD_HEADER_LEN);
+        header.extend_from_slice(&frame.timestamp().to_le_bytes());
+        header.extend_from_slice(&frame.width().to_le_bytes());
+        header.extend_from_slice(&frame.height().to_le_bytes());
+        header.extend_from_slice(&frame.frame_rate_numerator().to_le_bytes());
+        header.extend_from_slice(&frame.frame_rate_denominator().to_le_bytes());
+        header.extend_from_slice(&frame.flags().bits().to_le_bytes());
+        header.extend_from_slice(&length.to_le_bytes());
+
+        self.writer.write_all(&header)?;
+        self.writer.write_all(data)?;
+        self.frames_written += 1;
+        Ok(true)
+    }
+
+    /// Receives one video frame from `receiver` and writes it.
+    ///
+    /// Waits up to `timeout_ms` for a frame. Returns `Ok(true)` if a frame was
+    /// written, or `Ok(false)` if none arrived in time or it carried no VMX1 data.
+    pub fn record(&mut self, receiver: &mut Receiver, timeout_ms: i32) -> Result<bool> {
+        match receiver.receive(FrameType::VIDEO, timeout_ms)? {
+            Some(frame) => self.write_frame(&frame).map_err(Error::from),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the number of frames written so far.
+    pub fn frames_written(&self) -> u64 {
+        self.frames_written
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads compressed VMX1 video frames from a recording made by [`Recorder`].
+///
+/// See [`Recorder`] for the file format.
+///
+/// Frames are returned as VMX1 video frames with their original timestamps,
+/// ready to be passed to [`Sender::send()`](crate::Sender::send). Pacing
+/// playback according to the timestamps is left to the caller.
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::{CompressedPlayer, Quality, Sender};
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// let sender = Sender::new("Playback", Quality::Default)?;
+/// let player = CompressedPlayer::new(BufReader::new(File::open("camera.omtr")?))?;
+/// for frame in player {
+///     sender.send(&frame?.as_media_frame())?;
+/// }
+/// # Ok::<(), omt::Error>(())
+/// ```
+pub struct CompressedPlayer<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> CompressedPlayer<R> {
+    /// Creates a player reading from `reader` and validates the recording header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the data is not
+    /// a recording or uses an unsupported format version.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut header = [0u8; 5];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(invalid_data("not an OMT recording"));
+        }
+        if header[4] != VERSION {
+            return Err(invalid_data("unsupported recording version"));
+        }
+        Ok(Self { reader })
+    }
+
+    /// Reads the next frame.
+    ///
+    /// Returns `Ok(None)` at the end of the recording.
+    pub fn next_frame(&mut self) -> io::Result<Option<OwnedMediaFrame>> {
+        let mut header = [0u8; RECORD_HEADER_LEN];
+        // Distinguish a clean end of the recording from a truncated record
+        let read = read_fully(&mut self.reader, &mut header)?;
+        if read == 0 {
+            return Ok(None);
+        }
+        if read < header.len() {
+            return Err(invalid_data("truncated record header"));
+        }
+
+        let field = |offset: usize| {
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(&header[offset..offset + 4]);
+            bytes
+        };
+        let mut timestamp = [0u8; 8];
+        timestamp.copy_from_slice(&header[..8]);
+        let timestamp = i64::from_le_bytes(timestamp);
+        let width = i32::from_le_bytes(field(8));
+        let height = i32::from_le_bytes(field(12));
+        let frame_rate_n = i32::from_le_bytes(field(16));
+        let frame_rate_d = i32::from_le_bytes(field(20));
+        let flags = VideoFlags::from_bits(u32::from_le_bytes(field(24)));
+        let length = u32::from_le_bytes(field(28));
+
+        // Grow the buffer as data arrives, so a corrupt length cannot force a
+        // 4 GiB allocation up front
+        let mut data = Vec::new();
+        self.reader
+            .by_ref()
+            .take(u64::from(length))
+            .read_to_end(&mut data)?;
+        if data.len() as u64 != u64::from(length) {
+            return Err(invalid_data("truncated record data"));
+        }
+
+        VideoFrameBuilder::new()
+            .codec(Codec::Vmx1)
+            .dimensions(width, height)
+            .flags(flags)
+            .frame_rate(frame_rate_n, frame_rate_d)
+            .timestamp(timestamp)
+            .data(data)
+            .build()
+            .map(Some)
+            .map_err(|e| invalid_data(&e.to_string()))
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for CompressedPlayer<R> {
+    type Item = io::Result<OwnedMediaFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+/// Reads until `buf` is full or the reader is exhausted, returning the bytes read.
+fn read_fully(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut read = 0;
+    while read < buf.len() {
+        match reader.read(&mut buf[read..]) {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(read)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_rejects_invalid_header() {
+        let err = CompressedPlayer::new(&b"RIFF\x01"[..]).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = CompressedPlayer::new(&b"OMTR\x02"[..]).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_player_truncated_record() {
+        let mut data = b"OMTR\x01".to_vec();
+        data.extend_from_slice(&[0u8; 10]);
+        let mut player = CompressedPlayer::new(&data[..]).unwrap();
+        assert_eq!(
+            player.next_frame().err().unwrap().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_player_length_beyond_data() {
+        let mut data = b"OMTR\x01".to_vec();
+        data.extend_from_slice(&[0u8; RECORD_HEADER_LEN - 4]);
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+        data.extend_from_slice(&[0xAB; 16]);
+        let mut player = CompressedPlayer::new(&data[..]).unwrap();
+        let err = player.next_frame().err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "truncated record data");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut recorder = Recorder::new(Vec::new()).unwrap();
+        let frames: Vec<_> = (0..3)
+            .map(|i| {
+                VideoFrameBuilder::new()
+                    .codec(Codec::Vmx1)
+                    .dimensions(1920, 1080)
+                    .flags(VideoFlags::INTERLACED)
+                    .frame_rate(30000, 1001)
+                    .timestamp(i * 333_667)
+                    .data(vec![i as u8; 100 + i as usize])
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        for frame in &frames {
+            assert!(recorder.write_frame(&frame.as_media_frame()).unwrap());
+        }
+
+        let recording = recorder.into_inner();
+        let mut player = CompressedPlayer::new(&recording[..]).unwrap();
+        for frame in &frames {
+            let replayed = player.next_frame().unwrap().unwrap();
+            let (a, b) = (frame.as_media_frame(), replayed.as_media_frame());
+            assert_eq!(b.data(), a.data());
+            assert_eq!(b.timestamp(), a.timestamp());
+            assert_eq!((b.width(), b.height()), (1920, 1080));
+            assert_eq!(b.flags(), VideoFlags::INTERLACED);
+            assert_eq!(b.frame_rate_denominator(), 1001);
+        }
+        assert!(player.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_recorder_skips_uncompressed_frames() {
+        let frame = VideoFrameBuilder::new()
+            .codec(Codec::Uyvy)
+            .dimensions(2, 2)
+            .data(vec![0u8; 8])
+            .build()
+            .unwrap();
+        let mut recorder = Recorder::new(Vec::new()).unwrap();
+        assert!(!recorder.write_frame(&frame.as_media_frame()).unwrap());
+        assert_eq!(recorder.frames_written(), 0);
+        assert_eq!(recorder.into_inner(), b"OMTR\x01");
+    }
+}
@@ -3,11 +3,18 @@
 use crate::MAX_STRING_LENGTH;
 use crate::error::{Error, Result};
 use crate::frame::MediaFrame;
+use crate::placeholder::PlaceholderSender;
 use crate::statistics::Statistics;
 use crate::tally::Tally;
-use crate::types::{Quality, SenderInfo};
+use crate::thread_options::ThreadOptions;
+use crate::types::{Codec, FrameRate, FrameType, Quality, SenderInfo};
 use std::ffi::CString;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Maximum length of per-frame metadata including the null terminator.
+const MAX_FRAME_METADATA_LENGTH: usize = 65536;
 
 /// Sender for broadcasting media streams to receivers.
 ///
@@ -25,6 +32,60 @@ use std::ptr::NonNull;
 /// For most use cases, prefer `receive_metadata` for compile-time safety.
 pub struct Sender {
     handle: NonNull<omt_sys::omt_send_t>,
+    name: String,
+    quality: Quality,
+    // Codec, width and height of the most recently sent video frame
+    last_video: Mutex<Option<(Codec, i32, i32)>>,
+    source_id: OnceLock<String>,
+    sequence_numbers: AtomicBool,
+    next_sequence: AtomicU64,
+    allowed_codecs: Mutex<Option<Vec<Codec>>>,
+}
+
+/// A snapshot of what a [`Sender`] is currently transmitting.
+///
+/// Returned by [`Sender::status()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SenderStatus {
+    /// Name of the source as passed to [`Sender::new()`].
+    pub name: String,
+    /// Encoding quality the sender was created with.
+    ///
+    /// This is not necessarily the quality currently used for encoding, which
+    /// libomt does not report. When this is `Quality::Default`, libomt uses the
+    /// highest quality suggested by the connected receivers.
+    pub configured_quality: Quality,
+    /// Total number of connections to the sender.
+    pub connection_count: i32,
+    /// Codec of the most recently sent video frame, if any.
+    pub codec: Option<Codec>,
+    /// Width and height of the most recently sent video frame, if any.
+    pub dimensions: Option<(i32, i32)>,
+}
+
+/// The outcome of a single [`Sender::send()`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SendReceipt {
+    /// Number of connections to the sender, taken just before the frame was sent.
+    ///
+    /// This is a snapshot from a separate query, not part of the send itself,
+    /// so receivers may connect or disconnect in between. When this is zero,
+    /// encoding further frames can usually be skipped until a receiver connects.
+    pub receivers: i32,
+    /// Number of bytes sent, as reported by libomt.
+    ///
+    /// For video this is the size after encoding. Zero if the frame was not
+    /// sent, e.g. because no receiver is connected.
+    pub bytes: usize,
+    /// Raw return value of the native `omt_send` call.
+    pub return_code: i32,
+}
+
+impl SendReceipt {
+    /// Returns whether any data was sent.
+    pub fn is_delivered(&self) -> bool {
+        self.bytes > 0
+    }
 }
 
 impl Sender {
@@ -49,10 +110,96 @@ impl Sender {
         let handle = unsafe { omt_sys::omt_send_create(c_name.as_ptr(), quality.to_ffi()) };
 
         NonNull::new(handle as *mut _)
-            .map(|handle| Self { handle })
+            .map(|handle| Self {
+                handle,
+                name: name.to_string(),
+                quality,
+                last_video: Mutex::new(None),
+                source_id: OnceLock::new(),
+                sequence_numbers: AtomicBool::new(false),
+                next_sequence: AtomicU64::new(0),
+                allowed_codecs: Mutex::new(None),
+            })
             .ok_or(Error::SenderCreateFailed)
     }
 
+    /// Creates a new sender that advertises the given product information.
+    ///
+    /// Like [`new()`](Self::new) followed by
+    /// [`set_sender_information()`](Self::set_sender_information), but the
+    /// information is validated before the sender is created, so receivers can
+    /// read it with [`Receiver::get_sender_information()`](crate::Receiver::get_sender_information)
+    /// from the first connection on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BufferTooSmall`] if a field of `info` does not fit into
+    /// [`MAX_STRING_LENGTH`] bytes including the null terminator,
+    /// [`Error::NulError`] if a field or `name` contains a null byte, or
+    /// [`Error::SenderCreateFailed`] if the sender cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::{Quality, Sender, SenderInfo};
+    ///
+    /// let info = SenderInfo::new("Switcher".into(), "Acme".into(), "1.2.0".into());
+    /// let sender = Sender::new_with_info("Program", Quality::Default, info)?;
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn new_with_info(name: &str, quality: Quality, info: SenderInfo) -> Result<Self> {
+        // Validate before creating the sender so an invalid info never starts one
+        info.to_ffi()?;
+        let sender = Self::new(name, quality)?;
+        sender.set_sender_information(&info)?;
+        Ok(sender)
+    }
+
+    /// Creates a "no signal" source that keeps sending a placeholder picture.
+    ///
+    /// A background thread sends BGRA color bars of `width` x `height` at
+    /// `frame_rate` until the returned [`PlaceholderSender`] is dropped, so the
+    /// source is always discoverable and connectable. Switch to black with
+    /// [`PlaceholderSender::set_pattern()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions are not positive or the sender cannot
+    /// be created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use omt::{FrameRate, PlaceholderPattern, Sender};
+    ///
+    /// let placeholder = Sender::placeholder("No Signal", FrameRate::fps_25(), 1280, 720)?;
+    /// println!("Placeholder at {}", placeholder.sender().get_address()?);
+    /// placeholder.set_pattern(PlaceholderPattern::Black);
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn placeholder(
+        name: &str,
+        frame_rate: FrameRate,
+        width: i32,
+        height: i32,
+    ) -> Result<PlaceholderSender> {
+        Self::placeholder_with_options(name, frame_rate, width, height, ThreadOptions::default())
+    }
+
+    /// Like [`placeholder()`](Self::placeholder), with priority and CPU
+    /// affinity hints for the send thread.
+    ///
+    /// The hints are advisory, see [`ThreadOptions`].
+    pub fn placeholder_with_options(
+        name: &str,
+        frame_rate: FrameRate,
+        width: i32,
+        height: i32,
+        options: ThreadOptions,
+    ) -> Result<PlaceholderSender> {
+        PlaceholderSender::spawn(name, frame_rate, width, height, &options)
+    }
+
     /// Sets information describing this sender.
     ///
     /// This information is sent to receivers upon connection.
@@ -72,6 +219,8 @@ impl Sender {
     /// ```
     pub fn set_sender_information(&self, info: &SenderInfo) -> Result<()> {
         let mut ffi_info = info.to_ffi()?;
+        // SAFETY: The sender handle is valid and ffi_info is a fully initialized
+        // struct that outlives the call; libomt copies the strings.
         unsafe {
             omt_sys::omt_send_setsenderinformation(
                 self.handle.as_ptr() as *mut _,
@@ -144,42 +293,342 @@ impl Sender {
             return Ok(String::new());
         }
 
-        let bytes: Vec<u8> = buffer[..len as usize].iter().map(|&b| b as u8).collect();
+        // The returned length includes the null terminator
+        let bytes: Vec<u8> = buffer[..len as usize]
+            .iter()
+            .take_while(|&&b| b != 0)
+            .map(|&b| b as u8)
+            .collect();
         String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
     }
 
+    /// Returns a stable identifier for this sender.
+    ///
+    /// The id is a 16 character lowercase hex string derived from the sender's
+    /// discovery address (see [`get_address()`](Self::get_address)) and name.
+    /// libomt does not provide an id of its own.
+    ///
+    /// # Stability
+    ///
+    /// The id is computed on the first call and cached, so it never changes for the
+    /// lifetime of this sender. Senders created with the same name on the same host
+    /// get the same id, which makes it suitable for automation that recreates senders.
+    /// Two senders with different names or on different hosts get different ids
+    /// (barring hash collisions). The hash algorithm is not guaranteed to remain the
+    /// same across versions of this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Sender, Quality};
+    /// let sender = Sender::new("My Camera", Quality::High)?;
+    /// println!("Source id: {}", sender.source_id());
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn source_id(&self) -> String {
+        self.source_id
+            .get_or_init(|| {
+                let address = self.get_address().unwrap_or_default();
+                let mut input = address.into_bytes();
+                input.push(0);
+                input.extend_from_slice(self.name.as_bytes());
+                format!("{:016x}", fnv1a_64(&input))
+            })
+            .clone()
+    }
+
     /// Sends a frame to all connected receivers.
     ///
     /// Supports video, audio, and metadata frames.
     ///
+    /// Returns a [`SendReceipt`] with the number of bytes sent, the raw libomt
+    /// return code and the number of connected receivers. The receiver count is
+    /// queried just before sending, so it is a snapshot that can be out of date
+    /// by the time the frame goes out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CodecNotAllowed`] if the frame is a video frame whose
+    /// codec is not in the set configured with
+    /// [`set_allowed_codecs()`](Self::set_allowed_codecs) or that libomt does not
+    /// support, see [`Codec::is_runtime_supported()`]. The frame is not sent.
+    ///
+    /// Returns [`Error::BufferTooSmall`] or [`Error::InvalidParameter`] if the
+    /// frame's data does not cover its geometry, see
+    /// [`MediaFrame::data_matches_geometry()`]. The frame is not sent.
+    ///
+    /// Returns [`Error::Native`] with the return code if libomt reports a failure.
+    ///
     /// # Examples
     ///
     /// ```no_run
     /// # use omt::{Sender, Quality};
     /// # let sender = Sender::new("My Camera", Quality::High)?;
-    /// // Send a frame
     /// // let frame = ...; // Create frame
-    /// // sender.send(&frame)?;
+    /// // let receipt = sender.send(&frame)?;
+    /// // if receipt.receivers == 0 {
+    /// //     // Nobody is watching, skip rendering the next frame
+    /// // }
     /// # Ok::<(), omt::Error>(())
     /// ```
-    pub fn send(&self, frame: &MediaFrame<'_>) -> Result<bool> {
-        let result = unsafe {
-            omt_sys::omt_send(
-                self.handle.as_ptr() as *mut _,
-                frame.as_ffi() as *const _ as *mut _,
-            )
+    pub fn send(&self, frame: &MediaFrame<'_>) -> Result<SendReceipt> {
+        // libomt reads as much data as the geometry describes
+        if frame.data_matches_geometry() == Some(false) {
+            return Err(match frame.expected_data_len() {
+                Some(required) => Error::BufferTooSmall {
+                    required,
+                    provided: frame.data().len(),
+                },
+                None => Error::InvalidParameter {
+                    parameter: "frame".to_string(),
+                    reason: "stride or dimensions are invalid for the codec".to_string(),
+                },
+            });
+        }
+
+        if frame.frame_type() == FrameType::VIDEO
+            && let Some(codec) = frame.codec()
+            && (!codec.is_runtime_supported()
+                || self
+                    .allowed_codecs
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .as_ref()
+                    .is_some_and(|allowed| !allowed.contains(&codec)))
+        {
+            return Err(Error::CodecNotAllowed(codec));
+        }
+
+        // Keeps the stamped metadata alive until the frame has been sent
+        let sequence_metadata = self.sequence_metadata(frame);
+        let mut ffi = *frame.as_ffi();
+        if let Some(metadata) = &sequence_metadata {
+            ffi.FrameMetadata = metadata.as_ptr() as *mut _;
+            ffi.FrameMetadataLength = metadata.as_bytes_with_nul().len() as i32;
+        }
+
+        let receivers = self.connection_count();
+        let result =
+            unsafe { omt_sys::omt_send(self.handle.as_ptr() as *mut _, &mut ffi as *mut _) };
+        if result < 0 {
+            return Err(Error::Native(result));
+        }
+
+        if result > 0
+            && frame.frame_type() == FrameType::VIDEO
+            && let Some(codec) = frame.codec()
+        {
+            let mut last_video = self.last_video.lock().unwrap_or_else(|e| e.into_inner());
+            *last_video = Some((codec, frame.width(), frame.height()));
+        }
+
+        Ok(SendReceipt {
+            receivers,
+            bytes: result as usize,
+            return_code: result,
+        })
+    }
+
+    /// Forwards a received video frame as compressed VMX1 without re-encoding.
+    ///
+    /// The frame must carry valid VMX1 data in
+    /// [`compressed_data()`](MediaFrame::compressed_data), i.e. it was received
+    /// with [`ReceiveFlags::INCLUDE_COMPRESSED`](crate::ReceiveFlags::INCLUDE_COMPRESSED)
+    /// or [`ReceiveFlags::COMPRESSED_ONLY`](crate::ReceiveFlags::COMPRESSED_ONLY),
+    /// or in [`data()`](MediaFrame::data) if the frame's codec is VMX1, e.g. a
+    /// frame read by [`CompressedPlayer`](crate::CompressedPlayer).
+    /// The compressed data is sent as-is, so its contents are not validated.
+    /// Width, height, frame rate, aspect ratio, flags, color space, timestamp
+    /// and per-frame metadata are preserved; uncompressed data is ignored.
+    ///
+    /// This is otherwise identical to [`send()`](Self::send), including the
+    /// [`Codec::Vmx1`] check against the allowed codecs and sequence numbers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFrameType`] if `frame` is not a video frame, and
+    /// [`Error::InvalidParameter`] if it carries no compressed data.
+    /// Otherwise returns the errors of [`send()`](Self::send).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{FrameType, PreferredVideoFormat, Quality, ReceiveFlags, Receiver, Sender};
+    /// let mut receiver = Receiver::new(
+    ///     "HOST (Camera 1)",
+    ///     FrameType::VIDEO,
+    ///     PreferredVideoFormat::Uyvy,
+    ///     ReceiveFlags::COMPRESSED_ONLY,
+    /// )?;
+    /// let relay = Sender::new("Camera 1 Relay", Quality::Default)?;
+    /// while let Some(frame) = receiver.receive(FrameType::VIDEO, 1000)? {
+    ///     relay.send_compressed(&frame)?;
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn send_compressed(&self, frame: &MediaFrame<'_>) -> Result<SendReceipt> {
+        if frame.frame_type() != FrameType::VIDEO {
+            return Err(Error::InvalidFrameType);
+        }
+        let compressed = match frame.compressed_data() {
+            [] if frame.codec() == Some(Codec::Vmx1) => frame.data(),
+            compressed => compressed,
         };
-        Ok(result != 0)
+        if compressed.is_empty() {
+            return Err(Error::InvalidParameter {
+                parameter: "frame".to_string(),
+                reason: "frame carries no compressed VMX1 data".to_string(),
+            });
+        }
+
+        let mut ffi = *frame.as_ffi();
+        ffi.Codec = Codec::Vmx1.to_ffi();
+        ffi.Data = compressed.as_ptr() as *mut _;
+        ffi.DataLength = compressed.len() as i32;
+        ffi.Stride = 0;
+        ffi.CompressedData = std::ptr::null_mut();
+        ffi.CompressedLength = 0;
+        // SAFETY: All pointers in `ffi` are borrowed from `frame`, which
+        // outlives the passthrough frame.
+        let passthrough = unsafe { MediaFrame::from_owned_ffi(ffi) };
+        self.send(&passthrough)
     }
 
-    /// Returns the total number of connections to this sender.
+    /// Enables or disables automatic sequence numbers on outgoing frames.
+    ///
+    /// When enabled, every video and audio frame passed to [`send()`](Self::send) gets
+    /// `<OMTSeq n="..."/>` appended to its per-frame metadata, with a number that
+    /// increases by one for each stamped frame. Receivers can read it with
+    /// [`MediaFrame::sequence_number()`] to detect dropped or reordered frames.
+    ///
+    /// The frame passed to `send()` is not modified. Frames whose metadata would
+    /// exceed the 65536 byte limit are sent without a sequence number.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Sender, Quality};
+    /// let sender = Sender::new("My Camera", Quality::High)?;
+    /// sender.enable_sequence_numbers(true);
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn enable_sequence_numbers(&self, enabled: bool) {
+        self.sequence_numbers.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Restricts the codecs of video frames this sender accepts.
+    ///
+    /// libomt offers no way to limit which codecs a sender transmits, so the
+    /// restriction is enforced by [`send()`](Self::send): video frames with a codec
+    /// outside `codecs` are rejected with [`Error::CodecNotAllowed`]. Audio and
+    /// metadata frames are not affected. Pass `None` to allow all codecs again,
+    /// which is the default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Codec, Sender, Quality};
+    /// let sender = Sender::new("My Camera", Quality::High)?;
+    /// // Never send uncompressed BGRA
+    /// sender.set_allowed_codecs(Some(&[Codec::Uyvy, Codec::Nv12]));
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn set_allowed_codecs(&self, codecs: Option<&[Codec]>) {
+        *self
+            .allowed_codecs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = codecs.map(<[Codec]>::to_vec);
+    }
+
+    /// Returns the codecs configured with [`set_allowed_codecs()`](Self::set_allowed_codecs),
+    /// or `None` if all codecs are allowed.
+    pub fn allowed_codecs(&self) -> Option<Vec<Codec>> {
+        self.allowed_codecs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Builds the per-frame metadata of `frame` with the next sequence number
+    /// appended, or returns `None` if the frame should be sent unchanged.
+    fn sequence_metadata(&self, frame: &MediaFrame<'_>) -> Option<CString> {
+        if !self.sequence_numbers.load(Ordering::Relaxed)
+            || !(frame.frame_type() == FrameType::VIDEO || frame.frame_type() == FrameType::AUDIO)
+        {
+            return None;
+        }
+
+        let existing = frame.frame_metadata();
+        // Existing metadata that is not valid UTF-8 would be lost
+        if existing.is_empty() && frame.as_ffi().FrameMetadataLength > 0 {
+            return None;
+        }
+
+        let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+        let metadata = format!("{}<OMTSeq n=\"{}\"/>", existing, sequence);
+        if metadata.len() >= MAX_FRAME_METADATA_LENGTH {
+            return None;
+        }
+        CString::new(metadata).ok()
+    }
+
+    /// Returns the number of connections currently open to this sender.
+    ///
+    /// Returns 0 when no receiver is connected, so encoding can be skipped
+    /// until someone is watching. The count is queried from libomt on every
+    /// call and follows receivers as they connect and disconnect.
     ///
     /// Note: Receivers establish one connection for video/metadata and
     /// a second for audio.
-    pub fn connections(&self) -> i32 {
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Sender, Quality};
+    /// let sender = Sender::new("My Camera", Quality::Default)?;
+    /// if sender.connection_count() == 0 {
+    ///     // Nobody is watching, skip rendering this frame
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn connection_count(&self) -> i32 {
+        // SAFETY: The handle is valid for the lifetime of the sender.
         unsafe { omt_sys::omt_send_connections(self.handle.as_ptr() as *mut _) }
     }
 
+    /// Returns the total number of connections to this sender.
+    ///
+    /// Same as [`connection_count()`](Self::connection_count).
+    pub fn connections(&self) -> i32 {
+        self.connection_count()
+    }
+
+    /// Returns a snapshot of the sender's name, configured quality, connection
+    /// count and the format of the most recently sent video frame.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Sender, Quality};
+    /// # let sender = Sender::new("My Camera", Quality::High)?;
+    /// let status = sender.status();
+    /// if let (Some(codec), Some((width, height))) = (status.codec, status.dimensions) {
+    ///     println!("{}: sending {}x{} {} to {} connections",
+    ///         status.name, width, height, codec, status.connection_count);
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn status(&self) -> SenderStatus {
+        let last_video = *self.last_video.lock().unwrap_or_else(|e| e.into_inner());
+        SenderStatus {
+            name: self.name.clone(),
+            configured_quality: self.quality,
+            connection_count: self.connection_count(),
+            codec: last_video.map(|(codec, _, _)| codec),
+            dimensions: last_video.map(|(_, width, height)| (width, height)),
+        }
+    }
+
     /// Receives metadata from receivers - safe version.
     ///
     /// This is the recommended API that requires mutable access to the sender.
@@ -297,9 +746,33 @@ impl Sender {
 
     /// Gets the current tally state across all connections.
     ///
-    /// Returns the last known state if timed out.
+    /// The state aggregates the tally set by all connected receivers with
+    /// [`Receiver::set_tally()`](crate::Receiver::set_tally): the sender is on
+    /// program if any receiver has it on program, and likewise for preview.
+    ///
+    /// Waits up to `timeout_ms` for the state to change. Returns the state
+    /// together with whether it changed; on timeout this is the last known
+    /// state and `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use omt::{Sender, Quality};
+    /// let sender = Sender::new("My Camera", Quality::Default)?;
+    /// loop {
+    ///     let (tally, changed) = sender.get_tally(1000)?;
+    ///     if changed {
+    ///         // Update the tally lamp of the camera
+    ///         println!("Program: {}, preview: {}", tally.program, tally.preview);
+    ///     }
+    /// }
+    /// # Ok::<(), omt::Error>(())
+    /// ```
     pub fn get_tally(&self, timeout_ms: i32) -> Result<(Tally, bool)> {
+        // SAFETY: OMTTally is a plain C struct for which all zeroes is valid.
         let mut ffi_tally = unsafe { std::mem::zeroed() };
+        // SAFETY: The handle is valid for the lifetime of the sender and
+        // ffi_tally is a valid, writable OMTTally.
         let changed = unsafe {
             omt_sys::omt_send_gettally(
                 self.handle.as_ptr() as *mut _,
@@ -347,3 +820,25 @@ impl Drop for Sender {
 // SAFETY: The underlying C library is thread-safe
 unsafe impl Send for Sender {}
 unsafe impl Sync for Sender {}
+
+/// 64-bit FNV-1a hash, used for stable source ids.
+fn fnv1a_64(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    data.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_64_known_values() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
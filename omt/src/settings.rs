@@ -9,6 +9,11 @@
 use crate::MAX_STRING_LENGTH;
 use crate::error::{Error, Result};
 use std::ffi::CString;
+use std::ops::RangeInclusive;
+use std::sync::Mutex;
+
+/// Serializes [`SettingsBuilder::apply()`] calls.
+static APPLY_LOCK: Mutex<()> = Mutex::new(());
 
 /// Configuration settings manager.
 ///
@@ -44,7 +49,12 @@ impl Settings {
             return Ok(String::new());
         }
 
-        let bytes: Vec<u8> = buffer[..len as usize].iter().map(|&b| b as u8).collect();
+        // The returned length includes the null terminator
+        let bytes: Vec<u8> = buffer[..len as usize]
+            .iter()
+            .take_while(|&&b| b != 0)
+            .map(|&b| b as u8)
+            .collect();
 
         String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
     }
@@ -184,10 +194,135 @@ impl Settings {
     }
 }
 
+/// Collects several settings and applies them in one step.
+///
+/// Nothing is written until [`apply()`](Self::apply), which validates every
+/// value first, so a failed apply leaves all settings untouched. Concurrent
+/// `apply()` calls are serialized and never interleave, although the
+/// individual [`Settings`] setters can still run in between.
+///
+/// There is no logging level: libomt only lets the log file be chosen, see
+/// [`logging_filename()`](Self::logging_filename).
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::SettingsBuilder;
+///
+/// SettingsBuilder::new()
+///     .discovery_server("omt://server:6400")
+///     .network_port_range(7000..=7200)
+///     .apply()?;
+/// # Ok::<(), omt::Error>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SettingsBuilder {
+    discovery_server: Option<String>,
+    network_port_range: Option<RangeInclusive<i32>>,
+    logging_filename: Option<Option<String>>,
+}
+
+impl SettingsBuilder {
+    /// Creates a builder that changes no settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the discovery server URL, see [`Settings::set_discovery_server()`].
+    pub fn discovery_server(mut self, url: impl AsRef<str>) -> Self {
+        self.discovery_server = Some(url.as_ref().to_string());
+        self
+    }
+
+    /// Sets the network port range start and end.
+    ///
+    /// Both ends must lie in `1..=65535` and the start must not exceed the end.
+    pub fn network_port_range(mut self, ports: RangeInclusive<i32>) -> Self {
+        self.network_port_range = Some(ports);
+        self
+    }
+
+    /// Sets the log file, or disables logging with `None`, see
+    /// [`Settings::set_logging_filename()`].
+    pub fn logging_filename(mut self, filename: Option<&str>) -> Self {
+        self.logging_filename = Some(filename.map(str::to_string));
+        self
+    }
+
+    /// Validates all values and writes them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first invalid field, in the order the
+    /// builder methods are listed. No setting is changed in that case.
+    pub fn apply(self) -> Result<()> {
+        let discovery_server = self.discovery_server.map(CString::new).transpose()?;
+        if let Some(ports) = &self.network_port_range {
+            validate_port_range(ports)?;
+        }
+        let logging_filename = self
+            .logging_filename
+            .map(|filename| filename.map(CString::new).transpose())
+            .transpose()?;
+
+        // A poisoned lock only means another apply panicked; nothing to recover
+        let _guard = APPLY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(url) = discovery_server {
+            let c_name = CString::new("DiscoveryServer")?;
+            // SAFETY: Both pointers are valid null-terminated strings that
+            // outlive the call; the library copies the value.
+            unsafe {
+                omt_sys::omt_settings_set_string(c_name.as_ptr(), url.as_ptr());
+            }
+        }
+        if let Some(ports) = self.network_port_range {
+            Settings::set_network_port_start(*ports.start());
+            Settings::set_network_port_end(*ports.end());
+        }
+        if let Some(filename) = logging_filename {
+            // SAFETY: The pointer is either null, which disables logging, or a
+            // valid null-terminated string that outlives the call.
+            unsafe {
+                omt_sys::omt_setloggingfilename(
+                    filename.as_ref().map_or(std::ptr::null(), |f| f.as_ptr()),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_port_range(ports: &RangeInclusive<i32>) -> Result<()> {
+    const VALID: RangeInclusive<i32> = 1..=65535;
+
+    let (start, end) = (*ports.start(), *ports.end());
+    let reason = if !VALID.contains(&start) {
+        format!("start port {} is outside 1..=65535", start)
+    } else if !VALID.contains(&end) {
+        format!("end port {} is outside 1..=65535", end)
+    } else if start > end {
+        format!("start port {} is greater than end port {}", start, end)
+    } else {
+        return Ok(());
+    };
+    Err(Error::InvalidParameter {
+        parameter: "network_port_range".to_string(),
+        reason,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_settings_string_excludes_null_terminator() {
+        Settings::set_string("TestStringSetting", "value").unwrap();
+        assert_eq!(Settings::get_string("TestStringSetting").unwrap(), "value");
+    }
+
     #[test]
     fn test_settings_integer() {
         // Test setting and getting an integer value
@@ -202,4 +337,55 @@ mod tests {
         let retrieved_end_port = Settings::network_port_end();
         assert_eq!(retrieved_end_port, test_end_port);
     }
+
+    #[test]
+    fn test_validate_port_range() {
+        assert!(validate_port_range(&(6400..=6600)).is_ok());
+        assert!(validate_port_range(&(1..=65535)).is_ok());
+        assert!(validate_port_range(&(7000..=7000)).is_ok());
+
+        for ports in [
+            0..=6600,
+            6400..=65536,
+            RangeInclusive::new(6600, 6400),
+            -1..=10,
+        ] {
+            assert!(matches!(
+                validate_port_range(&ports),
+                Err(Error::InvalidParameter { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_builder_apply() {
+        // The only test touching DiscoveryServer, as tests share the settings
+        Settings::set_string("DiscoveryServer", "omt://before:6400").unwrap();
+
+        let result = SettingsBuilder::new()
+            .discovery_server("omt://after:6400")
+            .network_port_range(RangeInclusive::new(6600, 6400))
+            .apply();
+        match result {
+            Err(Error::InvalidParameter { reason, .. }) => {
+                assert_eq!(reason, "start port 6600 is greater than end port 6400")
+            }
+            other => panic!("expected InvalidParameter, got {:?}", other),
+        }
+        assert_eq!(Settings::discovery_server().unwrap(), "omt://before:6400");
+
+        assert!(matches!(
+            SettingsBuilder::new()
+                .discovery_server("omt://bad\0:6400")
+                .apply(),
+            Err(Error::NulError(_))
+        ));
+        assert_eq!(Settings::discovery_server().unwrap(), "omt://before:6400");
+
+        SettingsBuilder::new()
+            .discovery_server("omt://after:6400")
+            .apply()
+            .unwrap();
+        assert_eq!(Settings::discovery_server().unwrap(), "omt://after:6400");
+    }
 }
@@ -6,7 +6,11 @@ use std::time::Duration;
 ///
 /// Provides metrics about data transfer, frame counts, codec performance,
 /// and other operational statistics.
+///
+/// With the `serde` feature enabled, statistics can be serialized, e.g. to
+/// export them to a metrics system. Fields are named as in this struct.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statistics {
     /// Total bytes sent.
     pub bytes_sent: i64,
@@ -30,6 +34,24 @@ pub struct Statistics {
     pub codec_time_since_last: i64,
 }
 
+/// Differences between two [`Statistics`] readings.
+///
+/// Returned by [`Statistics::delta()`]. Every counter is zero or positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatisticsDelta {
+    /// Bytes sent in between.
+    pub bytes_sent: i64,
+    /// Bytes received in between.
+    pub bytes_received: i64,
+    /// Frames processed in between.
+    pub frames: i64,
+    /// Frames dropped in between.
+    pub frames_dropped: i64,
+    /// Time in milliseconds spent encoding/decoding in between.
+    pub codec_time: i64,
+}
+
 impl Statistics {
     /// Creates a new statistics instance with all values set to zero.
     pub fn new() -> Self {
@@ -67,6 +89,33 @@ impl Statistics {
         self.bytes_sent_since_last + self.bytes_received_since_last
     }
 
+    /// Returns the average receive bitrate in megabits per second.
+    ///
+    /// Divides `bytes_received` by `elapsed`, the interval over which the
+    /// bytes were counted, e.g. since the statistics were last reset. Returns
+    /// `0.0` if `elapsed` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::Statistics;
+    /// use std::time::Duration;
+    ///
+    /// let stats = Statistics {
+    ///     bytes_received: 2_500_000,
+    ///     ..Statistics::new()
+    /// };
+    /// assert_eq!(stats.bitrate_mbps(Duration::from_secs(2)), 10.0);
+    /// ```
+    pub fn bitrate_mbps(&self, elapsed: Duration) -> f64 {
+        let seconds = elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            self.bytes_received as f64 * 8.0 / seconds / 1_000_000.0
+        } else {
+            0.0
+        }
+    }
+
     /// Returns the frame drop rate as a percentage.
     ///
     /// Returns `None` if no frames have been processed.
@@ -79,6 +128,51 @@ impl Statistics {
         }
     }
 
+    /// Returns the change of the cumulative counters since `previous`.
+    ///
+    /// Useful for rate graphs fed by periodic polling. A counter that is lower
+    /// than in `previous`, e.g. because the library restarted it after a
+    /// reconnect, yields zero for that interval rather than a negative value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::Statistics;
+    ///
+    /// let previous = Statistics { frames: 100, bytes_received: 4000, ..Statistics::new() };
+    /// let current = Statistics { frames: 130, bytes_received: 5000, ..Statistics::new() };
+    ///
+    /// let delta = current.delta(&previous);
+    /// assert_eq!(delta.frames, 30);
+    /// assert_eq!(delta.bytes_received, 1000);
+    /// ```
+    pub fn delta(&self, previous: &Statistics) -> StatisticsDelta {
+        StatisticsDelta {
+            bytes_sent: counter_delta(self.bytes_sent, previous.bytes_sent),
+            bytes_received: counter_delta(self.bytes_received, previous.bytes_received),
+            frames: counter_delta(self.frames, previous.frames),
+            frames_dropped: counter_delta(self.frames_dropped, previous.frames_dropped),
+            codec_time: counter_delta(self.codec_time, previous.codec_time),
+        }
+    }
+
+    /// Returns these statistics relative to an earlier reading.
+    ///
+    /// Cumulative totals are reduced by the values in `baseline`, saturating
+    /// at zero should the library's counters have been restarted in between.
+    /// The `*_since_last` fields are kept as they are.
+    pub(crate) fn relative_to(&self, baseline: &Statistics) -> Self {
+        let delta = self.delta(baseline);
+        Self {
+            bytes_sent: delta.bytes_sent,
+            bytes_received: delta.bytes_received,
+            frames: delta.frames,
+            frames_dropped: delta.frames_dropped,
+            codec_time: delta.codec_time,
+            ..*self
+        }
+    }
+
     /// Converts from FFI representation.
     pub(crate) fn from_ffi(ffi: &omt_sys::OMTStatistics) -> Self {
         Self {
@@ -95,6 +189,11 @@ impl Statistics {
     }
 }
 
+/// Difference of a cumulative counter, saturating at zero if it was restarted.
+fn counter_delta(current: i64, previous: i64) -> i64 {
+    current.saturating_sub(previous).max(0)
+}
+
 impl std::fmt::Display for Statistics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -144,6 +243,134 @@ mod tests {
         assert_eq!(stats.drop_rate(), Some(10.0));
     }
 
+    #[test]
+    fn test_bitrate_mbps() {
+        let stats = Statistics {
+            bytes_received: 1_250_000,
+            bytes_sent: 999,
+            ..Statistics::new()
+        };
+        assert_eq!(stats.bitrate_mbps(Duration::from_secs(1)), 10.0);
+        assert_eq!(stats.bitrate_mbps(Duration::from_millis(500)), 20.0);
+        assert_eq!(stats.bitrate_mbps(Duration::ZERO), 0.0);
+        assert_eq!(Statistics::new().bitrate_mbps(Duration::from_secs(1)), 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let stats = Statistics {
+            bytes_received: 1500,
+            frames: 14,
+            frames_dropped: 2,
+            codec_time: 70,
+            ..Statistics::new()
+        };
+
+        let json = serde_json::to_string(&stats).unwrap();
+        assert!(json.contains("\"bytes_received\":1500"));
+        assert_eq!(serde_json::from_str::<Statistics>(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn test_delta() {
+        let previous = Statistics {
+            bytes_sent: 100,
+            bytes_received: 10_000,
+            frames: 50,
+            frames_since_last: 5,
+            frames_dropped: 1,
+            codec_time: 200,
+            ..Statistics::new()
+        };
+        let current = Statistics {
+            bytes_sent: 150,
+            bytes_received: 12_500,
+            frames: 60,
+            frames_since_last: 10,
+            frames_dropped: 3,
+            codec_time: 240,
+            ..Statistics::new()
+        };
+
+        assert_eq!(
+            current.delta(&previous),
+            StatisticsDelta {
+                bytes_sent: 50,
+                bytes_received: 2500,
+                frames: 10,
+                frames_dropped: 2,
+                codec_time: 40,
+            }
+        );
+        assert_eq!(current.delta(&current), StatisticsDelta::default());
+    }
+
+    #[test]
+    fn test_delta_after_counter_reset() {
+        let previous = Statistics {
+            bytes_received: 10_000,
+            frames: 50,
+            frames_dropped: 4,
+            codec_time: 200,
+            ..Statistics::new()
+        };
+        // Counters restarted after a reconnect, frames_dropped kept counting
+        let current = Statistics {
+            bytes_received: 300,
+            frames: 3,
+            frames_dropped: 6,
+            codec_time: 10,
+            ..Statistics::new()
+        };
+
+        let delta = current.delta(&previous);
+        assert_eq!(delta.bytes_received, 0);
+        assert_eq!(delta.frames, 0);
+        assert_eq!(delta.codec_time, 0);
+        assert_eq!(delta.frames_dropped, 2);
+
+        let extreme = Statistics {
+            bytes_received: i64::MIN,
+            ..Statistics::new()
+        };
+        let max = Statistics {
+            bytes_received: i64::MAX,
+            ..Statistics::new()
+        };
+        assert_eq!(extreme.delta(&max).bytes_received, 0);
+        assert_eq!(max.delta(&extreme).bytes_received, i64::MAX);
+    }
+
+    #[test]
+    fn test_relative_to() {
+        let baseline = Statistics {
+            bytes_received: 1000,
+            frames: 10,
+            frames_dropped: 2,
+            codec_time: 50,
+            ..Statistics::new()
+        };
+        let current = Statistics {
+            bytes_received: 1500,
+            frames: 14,
+            frames_since_last: 4,
+            frames_dropped: 2,
+            codec_time: 70,
+            ..Statistics::new()
+        };
+
+        let relative = current.relative_to(&baseline);
+        assert_eq!(relative.bytes_received, 500);
+        assert_eq!(relative.frames, 4);
+        assert_eq!(relative.frames_since_last, 4);
+        assert_eq!(relative.frames_dropped, 0);
+        assert_eq!(relative.codec_time, 20);
+
+        // Counters restarted by the library never go negative
+        assert_eq!(Statistics::new().relative_to(&baseline).frames, 0);
+    }
+
     #[test]
     fn test_codec_duration() {
         let mut stats = Statistics::new();
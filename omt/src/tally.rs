@@ -6,7 +6,11 @@
 ///
 /// Indicates whether a source is in preview or program mode.
 /// Values: 0 = off, 1 = on.
+///
+/// With the `serde` feature enabled, tally states can be serialized, e.g. for
+/// logging or REST APIs, as `{"preview": bool, "program": bool}`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tally {
     /// Preview tally state (off-air monitoring).
     pub preview: bool,
@@ -64,6 +68,20 @@ impl Tally {
         !self.preview && !self.program
     }
 
+    /// Returns true if the source is on air, i.e. program is active.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::Tally;
+    ///
+    /// assert!(Tally::new(true, true).is_on_air());
+    /// assert!(!Tally::preview_only().is_on_air());
+    /// ```
+    pub fn is_on_air(&self) -> bool {
+        self.program
+    }
+
     /// Converts to FFI representation.
     pub(crate) fn to_ffi(&self) -> omt_sys::OMTTally {
         omt_sys::OMTTally {
@@ -81,13 +99,14 @@ impl Tally {
     }
 }
 
+/// Formats the tally as `off`, `preview`, `program` or `program+preview`.
 impl std::fmt::Display for Tally {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match (self.preview, self.program) {
-            (false, false) => write!(f, "Off"),
-            (true, false) => write!(f, "Preview"),
-            (false, true) => write!(f, "Program"),
-            (true, true) => write!(f, "Preview+Program"),
+            (false, false) => write!(f, "off"),
+            (true, false) => write!(f, "preview"),
+            (false, true) => write!(f, "program"),
+            (true, true) => write!(f, "program+preview"),
         }
     }
 }
@@ -133,9 +152,27 @@ mod tests {
 
     #[test]
     fn test_tally_display() {
-        assert_eq!(Tally::off().to_string(), "Off");
-        assert_eq!(Tally::preview_only().to_string(), "Preview");
-        assert_eq!(Tally::program_only().to_string(), "Program");
-        assert_eq!(Tally::new(true, true).to_string(), "Preview+Program");
+        assert_eq!(Tally::off().to_string(), "off");
+        assert_eq!(Tally::preview_only().to_string(), "preview");
+        assert_eq!(Tally::program_only().to_string(), "program");
+        assert_eq!(Tally::new(true, true).to_string(), "program+preview");
+    }
+
+    #[test]
+    fn test_tally_is_on_air() {
+        assert!(!Tally::off().is_on_air());
+        assert!(!Tally::preview_only().is_on_air());
+        assert!(Tally::program_only().is_on_air());
+        assert!(Tally::new(true, true).is_on_air());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let tally = Tally::program_only();
+
+        let json = serde_json::to_string(&tally).unwrap();
+        assert_eq!(json, r#"{"preview":false,"program":true}"#);
+        assert_eq!(serde_json::from_str::<Tally>(&json).unwrap(), tally);
     }
 }
@@ -0,0 +1,212 @@
+//! Scheduling hints for threads spawned by the crate.
+
+use std::thread::{self, JoinHandle};
+
+/// Relative scheduling priority of a thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThreadPriority {
+    /// Below the default priority, for background work.
+    Low,
+    /// The default priority.
+    Normal,
+    /// Above the default priority, for latency sensitive work.
+    ///
+    /// Usually requires elevated privileges, e.g. `CAP_SYS_NICE` on Linux.
+    High,
+}
+
+/// Priority and CPU affinity hints for threads spawned by the crate.
+///
+/// Accepted by APIs that start background threads, such as
+/// [`Receiver::into_channels_with_options()`](crate::Receiver::into_channels_with_options).
+///
+/// The hints are advisory: they are applied on a best-effort basis when the
+/// thread starts and are silently ignored if the platform does not support
+/// them or the process lacks the required privileges. Currently they are
+/// applied on Linux only, where the priority maps to the thread's nice value
+/// (`Low` = 10, `Normal` = 0, `High` = -10).
+///
+/// # Examples
+///
+/// ```no_run
+/// use omt::{FrameType, PreferredVideoFormat, ReceiveFlags, Receiver, ThreadOptions, ThreadPriority};
+///
+/// let receiver = Receiver::new(
+///     "omt://hostname:6400",
+///     FrameType::VIDEO,
+///     PreferredVideoFormat::Uyvy,
+///     ReceiveFlags::NONE,
+/// )?;
+/// let (handle, channels) = receiver.into_channels_with_options(
+///     100,
+///     ThreadOptions {
+///         priority: Some(ThreadPriority::High),
+///         affinity: Some(vec![2, 3]),
+///     },
+/// );
+/// # Ok::<(), omt::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadOptions {
+    /// Scheduling priority of the thread.
+    ///
+    /// Default: `None` (inherit from the spawning thread)
+    pub priority: Option<ThreadPriority>,
+    /// Indices of the CPU cores the thread may run on.
+    ///
+    /// Default: `None` (any core)
+    pub affinity: Option<Vec<usize>>,
+}
+
+impl ThreadOptions {
+    /// Applies the hints to the calling thread.
+    ///
+    /// Returns whether all requested hints were applied. Options without any
+    /// hints always succeed.
+    pub fn apply_to_current_thread(&self) -> bool {
+        let priority_applied = self.priority.is_none_or(platform::set_priority);
+        let affinity_applied = self.affinity.as_deref().is_none_or(platform::set_affinity);
+        priority_applied && affinity_applied
+    }
+
+    /// Spawns a thread that applies the hints before running `f`.
+    pub(crate) fn spawn<F, T>(&self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let options = self.clone();
+        thread::spawn(move || {
+            options.apply_to_current_thread();
+            f()
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::ThreadPriority;
+
+    pub(super) fn set_priority(priority: ThreadPriority) -> bool {
+        let nice = match priority {
+            ThreadPriority::Low => 10,
+            ThreadPriority::Normal => 0,
+            ThreadPriority::High => -10,
+        };
+        // SAFETY: gettid has no preconditions. On Linux, PRIO_PROCESS with a
+        // thread ID changes the nice value of that thread only.
+        unsafe { libc::setpriority(libc::PRIO_PROCESS, libc::gettid() as libc::id_t, nice) == 0 }
+    }
+
+    pub(super) fn set_affinity(cores: &[usize]) -> bool {
+        let max_cores = libc::CPU_SETSIZE as usize;
+        if cores.is_empty() || cores.iter().any(|&core| core >= max_cores) {
+            return false;
+        }
+
+        // SAFETY: cpu_set_t is a plain bit mask for which all zeroes is the
+        // empty set. CPU_SET is only called with indices below CPU_SETSIZE,
+        // and sched_setaffinity reads exactly size_of::<cpu_set_t>() bytes
+        // from the set. Thread ID 0 refers to the calling thread.
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            for &core in cores {
+                libc::CPU_SET(core, &mut set);
+            }
+            libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) == 0
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use super::ThreadPriority;
+
+    pub(super) fn set_priority(_priority: ThreadPriority) -> bool {
+        false
+    }
+
+    pub(super) fn set_affinity(_cores: &[usize]) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the lowest core the calling thread may run on, if any.
+    ///
+    /// Tests pin to this core rather than core 0, which may be outside a
+    /// restricted cpuset.
+    #[cfg(target_os = "linux")]
+    fn allowed_core() -> Option<usize> {
+        // SAFETY: the set is zero-initialized and sized for sched_getaffinity,
+        // and CPU_ISSET is only called with indices below CPU_SETSIZE.
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+                return None;
+            }
+            (0..libc::CPU_SETSIZE as usize).find(|&core| libc::CPU_ISSET(core, &set))
+        }
+    }
+
+    /// Affinity hints are ignored on other platforms, so any core will do.
+    #[cfg(not(target_os = "linux"))]
+    fn allowed_core() -> Option<usize> {
+        Some(0)
+    }
+
+    #[test]
+    fn test_spawn_default_options() {
+        let handle = ThreadOptions::default().spawn(|| 42);
+        assert_eq!(handle.join().ok(), Some(42));
+    }
+
+    #[test]
+    fn test_spawn_with_options_runs() {
+        let Some(core) = allowed_core() else {
+            return;
+        };
+        let options = ThreadOptions {
+            priority: Some(ThreadPriority::Low),
+            affinity: Some(vec![core]),
+        };
+        let handle = options.spawn(|| 42);
+        assert_eq!(handle.join().ok(), Some(42));
+    }
+
+    #[test]
+    fn test_invalid_affinity_is_not_applied() {
+        let options = ThreadOptions {
+            priority: None,
+            affinity: Some(vec![usize::MAX]),
+        };
+        // An out of range core is rejected without changing the calling thread
+        assert!(!options.apply_to_current_thread());
+        assert_eq!(options.spawn(|| 42).join().ok(), Some(42));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_affinity_applied_on_linux() {
+        let Some(core) = allowed_core() else {
+            return;
+        };
+        let options = ThreadOptions {
+            priority: None,
+            affinity: Some(vec![core]),
+        };
+        let handle = options.spawn(move || {
+            // SAFETY: the set is zero-initialized and sized for sched_getaffinity.
+            unsafe {
+                let mut set: libc::cpu_set_t = std::mem::zeroed();
+                let result =
+                    libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set);
+                (result, libc::CPU_COUNT(&set), libc::CPU_ISSET(core, &set))
+            }
+        });
+        assert_eq!(handle.join().ok(), Some((0, 1, true)));
+    }
+}
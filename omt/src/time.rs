@@ -0,0 +1,269 @@
+//! Timestamp helpers for the OMT timebase.
+//!
+//! OMT timestamps are expressed in ticks of 100 nanoseconds, i.e.
+//! [`OMT_TIME_BASE`] ticks per second. A timestamp of `-1` asks the sender
+//! to generate timestamps automatically.
+
+use crate::types::FrameRate;
+use std::time::{Duration, Instant};
+
+/// Number of OMT timestamp ticks per second.
+pub const OMT_TIME_BASE: i64 = 10_000_000;
+
+/// Nanoseconds per OMT timestamp tick.
+const NANOS_PER_TICK: u128 = 1_000_000_000 / OMT_TIME_BASE as u128;
+
+/// Converts a [`Duration`] to OMT timestamp ticks.
+///
+/// Sub-tick precision is truncated. Durations that do not fit in an `i64`
+/// saturate to `i64::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(omt::ticks_from_duration(Duration::from_secs(1)), omt::OMT_TIME_BASE);
+/// assert_eq!(omt::ticks_from_duration(Duration::from_millis(40)), 400_000);
+/// ```
+pub fn ticks_from_duration(duration: Duration) -> i64 {
+    i64::try_from(duration.as_nanos() / NANOS_PER_TICK).unwrap_or(i64::MAX)
+}
+
+/// Converts OMT timestamp ticks to a [`Duration`].
+///
+/// Returns `None` for negative values, including the `-1` auto-timestamp
+/// sentinel.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(omt::duration_from_ticks(5_000_000), Some(Duration::from_millis(500)));
+/// assert_eq!(omt::duration_from_ticks(-1), None);
+/// ```
+pub fn duration_from_ticks(ticks: i64) -> Option<Duration> {
+    if ticks < 0 {
+        return None;
+    }
+    let secs = (ticks / OMT_TIME_BASE) as u64;
+    let nanos = ((ticks % OMT_TIME_BASE) as u128 * NANOS_PER_TICK) as u32;
+    Some(Duration::new(secs, nanos))
+}
+
+/// Default audio sample rate used by [`SenderClock`].
+const DEFAULT_SAMPLE_RATE: i32 = 48_000;
+
+/// Generates explicit, drift-free timestamps for a sender.
+///
+/// Timestamps are measured from the `start` instant (timestamp `0`) and are
+/// derived from integer frame and sample counters rather than by repeatedly
+/// adding a rounded frame duration. Fractional rates such as `30000/1001` or
+/// even `30/1` (333333.33 ticks per frame) therefore never accumulate error:
+/// frame `n` is always at `n * OMT_TIME_BASE / fps`, truncated to whole ticks.
+///
+/// Video and audio are tracked independently, so one clock can timestamp
+/// both streams of a source.
+///
+/// # Examples
+///
+/// ```
+/// use omt::{FrameRate, SenderClock};
+/// use std::time::Instant;
+///
+/// let frame_rate = FrameRate::new(30, 1).unwrap();
+/// let mut clock = SenderClock::new(Instant::now(), frame_rate);
+///
+/// assert_eq!(clock.next_video(), 0);
+/// assert_eq!(clock.next_video(), 333_333);
+/// assert_eq!(clock.next_video(), 666_666);
+/// assert_eq!(clock.next_video(), 1_000_000);
+///
+/// assert_eq!(clock.next_audio(480), 0);
+/// assert_eq!(clock.next_audio(480), 100_000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SenderClock {
+    start: Instant,
+    frame_rate: FrameRate,
+    sample_rate: i32,
+    video_frames: u64,
+    audio_samples: u64,
+}
+
+impl SenderClock {
+    /// Creates a clock starting at `start` for video at `frame_rate`.
+    ///
+    /// Audio timestamps assume a sample rate of 48 kHz; use
+    /// [`with_sample_rate()`](Self::with_sample_rate) to change it.
+    pub fn new(start: Instant, frame_rate: FrameRate) -> Self {
+        Self {
+            start,
+            frame_rate,
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            video_frames: 0,
+            audio_samples: 0,
+        }
+    }
+
+    /// Sets the audio sample rate in Hz used by [`next_audio()`](Self::next_audio).
+    ///
+    /// Non-positive values are ignored.
+    pub fn with_sample_rate(mut self, sample_rate: i32) -> Self {
+        if sample_rate > 0 {
+            self.sample_rate = sample_rate;
+        }
+        self
+    }
+
+    /// Returns the instant corresponding to timestamp `0`.
+    pub fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// Returns the video frame rate.
+    pub fn frame_rate(&self) -> FrameRate {
+        self.frame_rate
+    }
+
+    /// Returns the audio sample rate in Hz.
+    pub fn sample_rate(&self) -> i32 {
+        self.sample_rate
+    }
+
+    /// Returns the timestamp for the next video frame and advances by one frame.
+    pub fn next_video(&mut self) -> i64 {
+        let ticks = scale_to_ticks(
+            self.video_frames,
+            self.frame_rate.denominator() as u64,
+            self.frame_rate.numerator() as u64,
+        );
+        self.video_frames += 1;
+        ticks
+    }
+
+    /// Returns the timestamp for the next audio frame and advances by `samples`
+    /// samples per channel.
+    ///
+    /// Negative sample counts do not advance the clock.
+    pub fn next_audio(&mut self, samples: i32) -> i64 {
+        let ticks = scale_to_ticks(self.audio_samples, 1, self.sample_rate as u64);
+        self.audio_samples += samples.max(0) as u64;
+        ticks
+    }
+
+    /// Returns the number of ticks elapsed since [`start()`](Self::start).
+    pub fn elapsed_ticks(&self) -> i64 {
+        ticks_from_duration(self.start.elapsed())
+    }
+
+    /// Returns the instant at which `timestamp` is due, for pacing a send loop.
+    ///
+    /// Negative timestamps map to [`start()`](Self::start).
+    pub fn instant_at(&self, timestamp: i64) -> Instant {
+        self.start + duration_from_ticks(timestamp).unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Computes `count * numerator / denominator` seconds in ticks without overflow.
+fn scale_to_ticks(count: u64, numerator: u64, denominator: u64) -> i64 {
+    let ticks = count as u128 * numerator as u128 * OMT_TIME_BASE as u128 / denominator as u128;
+    i64::try_from(ticks).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticks_from_duration() {
+        assert_eq!(ticks_from_duration(Duration::ZERO), 0);
+        assert_eq!(ticks_from_duration(Duration::from_secs(2)), 20_000_000);
+        assert_eq!(ticks_from_duration(Duration::from_nanos(150)), 1);
+        assert_eq!(ticks_from_duration(Duration::MAX), i64::MAX);
+    }
+
+    #[test]
+    fn test_duration_from_ticks() {
+        assert_eq!(duration_from_ticks(0), Some(Duration::ZERO));
+        assert_eq!(
+            duration_from_ticks(12_345_678),
+            Some(Duration::new(1, 234_567_800))
+        );
+        assert_eq!(duration_from_ticks(-1), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let duration = Duration::from_micros(33_366_700);
+        assert_eq!(
+            duration_from_ticks(ticks_from_duration(duration)),
+            Some(duration)
+        );
+    }
+
+    #[test]
+    fn test_sender_clock_video_does_not_drift() {
+        let frame_rate = FrameRate::new(30, 1).unwrap();
+        let mut clock = SenderClock::new(Instant::now(), frame_rate);
+
+        // Each frame lands on a multiple of 333333 ticks, plus the whole ticks
+        // of the accumulated 1/3 tick remainder
+        for n in 0..30 {
+            let timestamp = clock.next_video();
+            assert_eq!(timestamp, n * 333_333 + n / 3);
+        }
+        // One second later, exactly one second has elapsed
+        assert_eq!(clock.next_video(), OMT_TIME_BASE);
+
+        // After an hour of frames the clock is still exact
+        let mut clock = SenderClock::new(Instant::now(), frame_rate);
+        for _ in 0..30 * 3600 {
+            clock.next_video();
+        }
+        assert_eq!(clock.next_video(), 3600 * OMT_TIME_BASE);
+    }
+
+    #[test]
+    fn test_sender_clock_fractional_rate() {
+        let frame_rate = FrameRate::new(30000, 1001).unwrap();
+        let mut clock = SenderClock::new(Instant::now(), frame_rate);
+
+        assert_eq!(clock.next_video(), 0);
+        assert_eq!(clock.next_video(), 333_666);
+        for _ in 2..30000 {
+            clock.next_video();
+        }
+        // 30000 frames at 29.97 fps take exactly 1001 seconds
+        assert_eq!(clock.next_video(), 1001 * OMT_TIME_BASE);
+    }
+
+    #[test]
+    fn test_sender_clock_audio() {
+        let frame_rate = FrameRate::new(25, 1).unwrap();
+        let mut clock = SenderClock::new(Instant::now(), frame_rate).with_sample_rate(44_100);
+        assert_eq!(clock.sample_rate(), 44_100);
+
+        assert_eq!(clock.next_audio(441), 0);
+        assert_eq!(clock.next_audio(441), 100_000);
+        assert_eq!(clock.next_audio(-5), 200_000);
+        assert_eq!(clock.next_audio(0), 200_000);
+
+        // Video is tracked independently of audio
+        assert_eq!(clock.next_video(), 0);
+        assert_eq!(clock.next_video(), 400_000);
+    }
+
+    #[test]
+    fn test_sender_clock_instant_at() {
+        let start = Instant::now();
+        let clock = SenderClock::new(start, FrameRate::new(30, 1).unwrap());
+        assert_eq!(clock.instant_at(0), start);
+        assert_eq!(clock.instant_at(-1), start);
+        assert_eq!(
+            clock.instant_at(OMT_TIME_BASE),
+            start + Duration::from_secs(1)
+        );
+    }
+}
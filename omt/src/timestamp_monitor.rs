@@ -0,0 +1,148 @@
+//! Detection of timestamp discontinuities across frames.
+
+use crate::frame::MediaFrame;
+
+/// A discontinuity reported by [`TimestampMonitor`].
+///
+/// The delta is the difference to the previous timestamp in OMT ticks of
+/// 100 ns, see [`OMT_TIME_BASE`](crate::OMT_TIME_BASE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampEvent {
+    /// The timestamp went backwards, e.g. because the sender's clock was reset.
+    ///
+    /// The delta is negative.
+    Backwards(i64),
+    /// The timestamp jumped forward by more than the configured gap.
+    LargeGap(i64),
+}
+
+/// Checks that frame timestamps keep increasing without large jumps.
+///
+/// Feed it the timestamp of every frame of one stream, e.g. all video frames,
+/// with [`push()`](Self::push) or [`push_frame()`](Self::push_frame). Each
+/// timestamp is compared with the previous one: a decrease of more than
+/// `max_backwards` ticks is reported as [`TimestampEvent::Backwards`], an
+/// increase of more than `max_gap` ticks as [`TimestampEvent::LargeGap`]. The
+/// `-1` auto-timestamp sentinel is ignored.
+///
+/// After an event the new timestamp becomes the reference, so a clock reset is
+/// reported once rather than for every following frame.
+///
+/// # Examples
+///
+/// ```
+/// use omt::{TimestampEvent, TimestampMonitor, OMT_TIME_BASE};
+///
+/// // Report any backwards step and gaps of more than one second
+/// let mut monitor = TimestampMonitor::new(0, OMT_TIME_BASE);
+/// assert_eq!(monitor.push(1_000_000), None);
+/// assert_eq!(monitor.push(1_400_000), None);
+/// assert_eq!(monitor.push(400_000), Some(TimestampEvent::Backwards(-1_000_000)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimestampMonitor {
+    max_backwards: i64,
+    max_gap: i64,
+    previous: Option<i64>,
+}
+
+impl TimestampMonitor {
+    /// Creates a monitor reporting decreases of more than `max_backwards` ticks
+    /// and increases of more than `max_gap` ticks.
+    ///
+    /// A `max_backwards` of 0 reports every decrease. Negative thresholds are
+    /// treated as 0.
+    pub fn new(max_backwards: i64, max_gap: i64) -> Self {
+        Self {
+            max_backwards: max_backwards.max(0),
+            max_gap: max_gap.max(0),
+            previous: None,
+        }
+    }
+
+    /// Adds the next timestamp and returns the discontinuity it causes, if any.
+    pub fn push(&mut self, timestamp: i64) -> Option<TimestampEvent> {
+        if timestamp == -1 {
+            return None;
+        }
+
+        let previous = self.previous.replace(timestamp)?;
+        let delta = timestamp.saturating_sub(previous);
+        if delta < -self.max_backwards {
+            Some(TimestampEvent::Backwards(delta))
+        } else if delta > self.max_gap {
+            Some(TimestampEvent::LargeGap(delta))
+        } else {
+            None
+        }
+    }
+
+    /// Adds the timestamp of a frame, see [`push()`](Self::push).
+    pub fn push_frame(&mut self, frame: &MediaFrame<'_>) -> Option<TimestampEvent> {
+        self.push(frame.timestamp())
+    }
+
+    /// Returns the last timestamp that was not `-1`.
+    pub fn previous(&self) -> Option<i64> {
+        self.previous
+    }
+
+    /// Forgets the previous timestamp.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backwards() {
+        let mut monitor = TimestampMonitor::new(0, 1_000_000);
+        assert_eq!(monitor.push(3_000_000), None);
+        assert_eq!(
+            monitor.push(2_000_000),
+            Some(TimestampEvent::Backwards(-1_000_000))
+        );
+        assert_eq!(
+            monitor.push(1_500_000),
+            Some(TimestampEvent::Backwards(-500_000))
+        );
+        // The decreased timestamp is the new reference
+        assert_eq!(monitor.push(1_800_000), None);
+    }
+
+    #[test]
+    fn test_backwards_tolerance() {
+        let mut monitor = TimestampMonitor::new(100, 1_000_000);
+        assert_eq!(monitor.push(1_000), None);
+        assert_eq!(monitor.push(900), None);
+        assert_eq!(monitor.push(799), Some(TimestampEvent::Backwards(-101)));
+    }
+
+    #[test]
+    fn test_large_gap() {
+        let mut monitor = TimestampMonitor::new(0, 1_000_000);
+        assert_eq!(monitor.push(0), None);
+        assert_eq!(monitor.push(333_333), None);
+        assert_eq!(monitor.push(1_333_333), None);
+        assert_eq!(
+            monitor.push(5_000_000),
+            Some(TimestampEvent::LargeGap(3_666_667))
+        );
+    }
+
+    #[test]
+    fn test_sentinel_ignored_and_reset() {
+        let mut monitor = TimestampMonitor::new(0, 1_000_000);
+        assert_eq!(monitor.push(-1), None);
+        assert_eq!(monitor.previous(), None);
+        assert_eq!(monitor.push(5_000_000), None);
+        assert_eq!(monitor.push(-1), None);
+        assert_eq!(monitor.previous(), Some(5_000_000));
+
+        monitor.reset();
+        assert_eq!(monitor.push(0), None);
+    }
+}
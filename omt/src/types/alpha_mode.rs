@@ -0,0 +1,16 @@
+//! Alpha channel interpretation of video frames.
+
+/// How the alpha channel of a video frame is to be interpreted.
+///
+/// Derived from [`VideoFlags::ALPHA`](crate::VideoFlags::ALPHA) and
+/// [`VideoFlags::PRE_MULTIPLIED`](crate::VideoFlags::PRE_MULTIPLIED) by
+/// [`MediaFrame::alpha_mode()`](crate::MediaFrame::alpha_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlphaMode {
+    /// The frame has no alpha channel; any alpha values should be ignored.
+    None,
+    /// The frame has a straight (unassociated) alpha channel.
+    Straight,
+    /// The frame has an alpha channel and its color values are premultiplied by alpha.
+    Premultiplied,
+}
@@ -0,0 +1,17 @@
+//! Chroma upsampling mode definitions.
+
+/// How subsampled chroma is reconstructed when converting YUV frames to RGB.
+///
+/// Only affects 4:2:2 (UYVY, YUY2, UYVA) and 4:2:0 (NV12, YV12) sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ChromaUpsample {
+    /// Each chroma sample is repeated for all pixels it covers (fastest).
+    ///
+    /// Sharp color edges may look blocky.
+    #[default]
+    Nearest,
+    /// Chroma is linearly interpolated between neighbouring samples.
+    ///
+    /// Produces smoother color edges at some extra cost.
+    Linear,
+}
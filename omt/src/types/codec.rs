@@ -1,8 +1,30 @@
 //! Codec type definitions and utilities.
 
+/// FourCC of NV12 with swapped chroma order. Not defined by libomt.
+const FOURCC_NV21: u32 = u32::from_le_bytes(*b"NV21");
+
+/// Codecs defined by the libomt API the bindings are generated from.
+const LIBOMT_CODECS: [u32; 10] = [
+    omt_sys::OMTCodec_VMX1,
+    omt_sys::OMTCodec_FPA1,
+    omt_sys::OMTCodec_UYVY,
+    omt_sys::OMTCodec_YUY2,
+    omt_sys::OMTCodec_BGRA,
+    omt_sys::OMTCodec_NV12,
+    omt_sys::OMTCodec_YV12,
+    omt_sys::OMTCodec_UYVA,
+    omt_sys::OMTCodec_P216,
+    omt_sys::OMTCodec_PA16,
+];
+
 /// Media codec types supported by OMT.
+///
+/// Marked `#[non_exhaustive]`, so matches outside this crate need a wildcard
+/// arm. Further codecs, such as ones handled only by the local conversions
+/// like [`Codec::Nv21`], can then be added without breaking those matches.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
+#[non_exhaustive]
 pub enum Codec {
     /// VMX1 - Fast video codec.
     Vmx1 = omt_sys::OMTCodec_VMX1,
@@ -16,6 +38,11 @@ pub enum Codec {
     Bgra = omt_sys::OMTCodec_BGRA,
     /// NV12 - Planar 4:2:0 YUV format. Y plane followed by interleaved half height U/V plane.
     Nv12 = omt_sys::OMTCodec_NV12,
+    /// NV21 - Same as NV12 with V before U in the interleaved chroma plane.
+    ///
+    /// Emitted by Android encoders. libomt does not handle it, so it can only
+    /// be converted locally, see [`is_runtime_supported()`](Self::is_runtime_supported).
+    Nv21 = FOURCC_NV21,
     /// YV12 - Planar 4:2:0 YUV format. Y plane followed by half height U and V planes.
     Yv12 = omt_sys::OMTCodec_YV12,
     /// UYVA - 16pp YUV format immediately followed by an alpha plane.
@@ -36,6 +63,7 @@ impl Codec {
             omt_sys::OMTCodec_YUY2 => Some(Self::Yuy2),
             omt_sys::OMTCodec_BGRA => Some(Self::Bgra),
             omt_sys::OMTCodec_NV12 => Some(Self::Nv12),
+            FOURCC_NV21 => Some(Self::Nv21),
             omt_sys::OMTCodec_YV12 => Some(Self::Yv12),
             omt_sys::OMTCodec_UYVA => Some(Self::Uyva),
             omt_sys::OMTCodec_P216 => Some(Self::P216),
@@ -81,13 +109,55 @@ impl Codec {
         match self {
             Codec::Uyvy | Codec::Yuy2 | Codec::Uyva => Some(16),
             Codec::Bgra => Some(32),
-            Codec::Nv12 | Codec::Yv12 => Some(12), // 4:2:0 subsampling
-            Codec::P216 | Codec::Pa16 => Some(32), // 16-bit per component
-            Codec::Vmx1 => None,                   // Compressed, variable
-            Codec::Fpa1 => None,                   // Audio codec
+            Codec::Nv12 | Codec::Nv21 | Codec::Yv12 => Some(12), // 4:2:0 subsampling
+            Codec::P216 | Codec::Pa16 => Some(32),               // 16-bit per component
+            Codec::Vmx1 => None,                                 // Compressed, variable
+            Codec::Fpa1 => None,                                 // Audio codec
         }
     }
 
+    /// Returns whether the linked libomt can send and receive this codec.
+    ///
+    /// libomt offers no capability or version query, so this looks the codec
+    /// up in the codec table of the libomt API the bindings are generated
+    /// from. Codecs only handled by the local conversions, such as
+    /// [`Codec::Nv21`], report `false` and cannot be sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::Codec;
+    ///
+    /// assert!(Codec::Uyvy.is_runtime_supported());
+    /// assert!(!Codec::Nv21.is_runtime_supported());
+    /// ```
+    pub fn is_runtime_supported(&self) -> bool {
+        LIBOMT_CODECS.contains(&self.to_ffi())
+    }
+
+    /// Returns the minimum number of data bytes of an uncompressed video frame.
+    ///
+    /// `stride` is the number of bytes per row of the first plane. Planar
+    /// formats are laid out as described on each variant, with chroma planes
+    /// derived from the same stride. Rows may be padded, so larger strides
+    /// increase the result.
+    ///
+    /// Returns `None` for VMX1 and FPA1, which have no size fixed by the
+    /// geometry, for zero dimensions, and for a stride too small to hold a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::Codec;
+    ///
+    /// assert_eq!(Codec::Uyvy.expected_data_len(1920, 1080, 3840), Some(3840 * 1080));
+    /// assert_eq!(Codec::Nv12.expected_data_len(4, 4, 4), Some(4 * 4 + 4 * 2));
+    /// assert_eq!(Codec::Vmx1.expected_data_len(1920, 1080, 1920), None);
+    /// ```
+    pub fn expected_data_len(&self, width: usize, height: usize, stride: usize) -> Option<usize> {
+        crate::video_conversion::expected_data_len(*self, width, height, stride)
+    }
+
     /// Returns the FourCC code as a string.
     pub fn fourcc(&self) -> &'static str {
         match self {
@@ -97,6 +167,7 @@ impl Codec {
             Codec::Yuy2 => "YUY2",
             Codec::Bgra => "BGRA",
             Codec::Nv12 => "NV12",
+            Codec::Nv21 => "NV21",
             Codec::Yv12 => "YV12",
             Codec::Uyva => "UYVA",
             Codec::P216 => "P216",
@@ -134,6 +205,19 @@ mod tests {
         assert!(!Codec::Uyvy.is_compressed());
     }
 
+    #[test]
+    fn test_runtime_support() {
+        assert!(Codec::Uyvy.is_runtime_supported());
+        assert!(Codec::Pa16.is_runtime_supported());
+        // Not defined by libomt
+        assert!(!Codec::Nv21.is_runtime_supported());
+
+        for value in LIBOMT_CODECS {
+            let codec = Codec::from_ffi(value).expect("libomt codec without a variant");
+            assert!(codec.is_runtime_supported());
+        }
+    }
+
     #[test]
     fn test_bits_per_pixel() {
         assert_eq!(Codec::Uyvy.bits_per_pixel(), Some(16));
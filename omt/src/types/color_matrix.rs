@@ -0,0 +1,16 @@
+//! YUV color matrix definitions for video conversion.
+
+/// Matrix used to convert between YUV and RGB.
+///
+/// Returned by [`ColorSpace::to_matrix()`](crate::ColorSpace::to_matrix),
+/// which resolves [`ColorSpace::Undefined`](crate::ColorSpace::Undefined) the
+/// same way the crate's own conversions do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorMatrix {
+    /// ITU-R BT.601 coefficients, used for standard definition video.
+    Bt601,
+    /// ITU-R BT.709 coefficients, used for high definition video.
+    Bt709,
+    /// ITU-R BT.2020 coefficients, used for UHD and HDR video.
+    Bt2020,
+}
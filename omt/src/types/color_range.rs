@@ -0,0 +1,12 @@
+//! YUV value range definitions for video conversion.
+
+/// Range of the YUV sample values of a video frame.
+///
+/// Returned by [`VideoFlags::to_range()`](crate::VideoFlags::to_range).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorRange {
+    /// Limited (studio) range, e.g. 16-235 for 8-bit luma.
+    Limited,
+    /// Full range, using all values of the sample type.
+    Full,
+}
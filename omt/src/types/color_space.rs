@@ -1,21 +1,78 @@
 //! Color space definitions for video frames.
 
+use super::ColorMatrix;
+
 /// Color space for video frames.
 ///
 /// Used to determine the color space for YUV<>RGB conversions internally.
 /// If undefined, the codec will assume BT601 for heights < 720, BT709 for everything else.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(u32)]
 pub enum ColorSpace {
     /// Undefined color space (automatic selection).
-    Undefined = omt_sys::OMTColorSpace_Undefined,
+    Undefined,
     /// BT.601 color space.
-    Bt601 = omt_sys::OMTColorSpace_BT601,
+    Bt601,
     /// BT.709 color space.
-    Bt709 = omt_sys::OMTColorSpace_BT709,
+    Bt709,
+    /// BT.2020 color space.
+    ///
+    /// libomt has no BT.2020 value, so frames carrying it are sent with an
+    /// undefined color space and received frames never report it. Locally the
+    /// color space is kept: an [`OwnedMediaFrame`](crate::OwnedMediaFrame)
+    /// built with it reports it from
+    /// [`MediaFrame::color_space()`](crate::MediaFrame::color_space), and its
+    /// conversions such as [`MediaFrame::to_rgba8()`](crate::MediaFrame::to_rgba8)
+    /// use the BT.2020 matrix.
+    Bt2020,
 }
 
 impl ColorSpace {
+    /// Returns the YUV matrix for a frame of the given width in this color space.
+    ///
+    /// [`Undefined`](Self::Undefined) resolves to BT.709 for widths of 1280 and
+    /// above (HD) and BT.601 otherwise, the same heuristic used by the crate's
+    /// conversions such as [`MediaFrame::to_rgba8()`](crate::MediaFrame::to_rgba8).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{ColorMatrix, ColorSpace};
+    ///
+    /// assert_eq!(ColorSpace::Undefined.to_matrix(1920), ColorMatrix::Bt709);
+    /// assert_eq!(ColorSpace::Bt601.to_matrix(1920), ColorMatrix::Bt601);
+    /// ```
+    pub fn to_matrix(self, width: i32) -> ColorMatrix {
+        match self {
+            Self::Bt601 => ColorMatrix::Bt601,
+            Self::Bt709 => ColorMatrix::Bt709,
+            Self::Bt2020 => ColorMatrix::Bt2020,
+            Self::Undefined => Self::guess_from_dimensions(width, 0).to_matrix(width),
+        }
+    }
+
+    /// Guesses the color space of a frame with undefined color space.
+    ///
+    /// Returns BT.709 for widths of 1280 and above (HD) and BT.601 otherwise.
+    /// This is the heuristic the crate's conversions apply to
+    /// [`Undefined`](Self::Undefined); the height does not currently affect
+    /// the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::ColorSpace;
+    ///
+    /// assert_eq!(ColorSpace::guess_from_dimensions(1920, 1080), ColorSpace::Bt709);
+    /// assert_eq!(ColorSpace::guess_from_dimensions(720, 576), ColorSpace::Bt601);
+    /// ```
+    pub fn guess_from_dimensions(width: i32, _height: i32) -> Self {
+        if width >= 1280 {
+            Self::Bt709
+        } else {
+            Self::Bt601
+        }
+    }
+
     /// Creates a `ColorSpace` from raw FFI value.
     pub(crate) fn from_ffi(value: u32) -> Option<Self> {
         match value {
@@ -27,7 +84,62 @@ impl ColorSpace {
     }
 
     /// Converts to FFI value.
+    ///
+    /// [`Bt2020`](Self::Bt2020) becomes undefined, as libomt lacks it.
     pub(crate) fn to_ffi(self) -> u32 {
-        self as u32
+        match self {
+            Self::Undefined | Self::Bt2020 => omt_sys::OMTColorSpace_Undefined,
+            Self::Bt601 => omt_sys::OMTColorSpace_BT601,
+            Self::Bt709 => omt_sys::OMTColorSpace_BT709,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_matrix_undefined_by_width() {
+        assert_eq!(ColorSpace::Undefined.to_matrix(1920), ColorMatrix::Bt709);
+        assert_eq!(ColorSpace::Undefined.to_matrix(1280), ColorMatrix::Bt709);
+        assert_eq!(ColorSpace::Undefined.to_matrix(1279), ColorMatrix::Bt601);
+        assert_eq!(ColorSpace::Undefined.to_matrix(720), ColorMatrix::Bt601);
+    }
+
+    #[test]
+    fn test_to_matrix_explicit() {
+        assert_eq!(ColorSpace::Bt601.to_matrix(3840), ColorMatrix::Bt601);
+        assert_eq!(ColorSpace::Bt709.to_matrix(720), ColorMatrix::Bt709);
+        assert_eq!(ColorSpace::Bt2020.to_matrix(720), ColorMatrix::Bt2020);
+    }
+
+    #[test]
+    fn test_guess_from_dimensions_boundary() {
+        assert_eq!(
+            ColorSpace::guess_from_dimensions(1279, 720),
+            ColorSpace::Bt601
+        );
+        assert_eq!(
+            ColorSpace::guess_from_dimensions(1280, 720),
+            ColorSpace::Bt709
+        );
+        assert_eq!(
+            ColorSpace::guess_from_dimensions(1280, 480),
+            ColorSpace::Bt709
+        );
+        assert_eq!(ColorSpace::guess_from_dimensions(0, 0), ColorSpace::Bt601);
+    }
+
+    #[test]
+    fn test_bt2020_ffi_falls_back_to_undefined() {
+        assert_eq!(
+            ColorSpace::Bt2020.to_ffi(),
+            omt_sys::OMTColorSpace_Undefined
+        );
+        assert_eq!(
+            ColorSpace::from_ffi(ColorSpace::Bt709.to_ffi()),
+            Some(ColorSpace::Bt709)
+        );
     }
 }
@@ -0,0 +1,34 @@
+//! Field dominance of interlaced video frames.
+
+/// Which field of an interlaced frame is temporally first.
+///
+/// The OMT frame format only flags frames as interlaced, so the field order is
+/// carried in the per-frame metadata as `<OMTField order="tff"/>` or
+/// `<OMTField order="bff"/>`. See [`MediaFrame::field_order()`](crate::MediaFrame::field_order)
+/// and [`OwnedMediaFrame::set_field_order()`](crate::OwnedMediaFrame::set_field_order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldOrder {
+    /// Top field first (TFF).
+    TopFirst,
+    /// Bottom field first (BFF).
+    BottomFirst,
+}
+
+impl FieldOrder {
+    /// Returns the value used in the `order` attribute of the metadata element.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TopFirst => "tff",
+            Self::BottomFirst => "bff",
+        }
+    }
+
+    /// Parses the value of the `order` attribute of the metadata element.
+    pub(crate) fn from_attribute(value: &str) -> Option<Self> {
+        match value {
+            "tff" => Some(Self::TopFirst),
+            "bff" => Some(Self::BottomFirst),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 //! Flags for video frames and receiver configuration.
 
+use super::{Codec, ColorRange};
+
 /// Flags for video frames.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct VideoFlags(u32);
@@ -33,6 +35,82 @@ impl VideoFlags {
         (self.0 & other.0) == other.0
     }
 
+    /// Returns true if [`ALPHA`](Self::ALPHA) is set.
+    pub const fn has_alpha(self) -> bool {
+        self.contains(Self::ALPHA)
+    }
+
+    /// Returns true if [`INTERLACED`](Self::INTERLACED) is set.
+    pub const fn is_interlaced(self) -> bool {
+        self.contains(Self::INTERLACED)
+    }
+
+    /// Returns true if [`PRE_MULTIPLIED`](Self::PRE_MULTIPLIED) is set.
+    pub const fn is_premultiplied(self) -> bool {
+        self.contains(Self::PRE_MULTIPLIED)
+    }
+
+    /// Returns true if [`PREVIEW`](Self::PREVIEW) is set.
+    pub const fn is_preview(self) -> bool {
+        self.contains(Self::PREVIEW)
+    }
+
+    /// Returns true if [`HIGH_BIT_DEPTH`](Self::HIGH_BIT_DEPTH) is set.
+    pub const fn is_high_bit_depth(self) -> bool {
+        self.contains(Self::HIGH_BIT_DEPTH)
+    }
+
+    /// Returns the flags a frame in `codec` needs to be interpreted correctly.
+    ///
+    /// UYVA and PA16 always carry alpha and get [`ALPHA`](Self::ALPHA); P216
+    /// and PA16 get [`HIGH_BIT_DEPTH`](Self::HIGH_BIT_DEPTH). BGRA is opaque
+    /// unless `ALPHA` is added explicitly, as the codec alone cannot tell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, VideoFlags};
+    ///
+    /// assert_eq!(VideoFlags::for_codec(Codec::Uyvy), VideoFlags::NONE);
+    /// assert_eq!(
+    ///     VideoFlags::for_codec(Codec::Pa16),
+    ///     VideoFlags::ALPHA | VideoFlags::HIGH_BIT_DEPTH
+    /// );
+    /// let with_alpha = VideoFlags::for_codec(Codec::Bgra) | VideoFlags::ALPHA;
+    /// assert!(with_alpha.has_alpha());
+    /// ```
+    pub fn for_codec(codec: Codec) -> Self {
+        let mut flags = Self::NONE;
+        if matches!(codec, Codec::Uyva | Codec::Pa16) {
+            flags |= Self::ALPHA;
+        }
+        if codec.is_high_bit_depth() {
+            flags |= Self::HIGH_BIT_DEPTH;
+        }
+        flags
+    }
+
+    /// Returns the YUV range of a frame with these flags.
+    ///
+    /// High bit depth frames use [`ColorRange::Full`], all others
+    /// [`ColorRange::Limited`], the same as the crate's conversions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{ColorRange, VideoFlags};
+    ///
+    /// assert_eq!(VideoFlags::NONE.to_range(), ColorRange::Limited);
+    /// assert_eq!(VideoFlags::HIGH_BIT_DEPTH.to_range(), ColorRange::Full);
+    /// ```
+    pub fn to_range(self) -> ColorRange {
+        if self.contains(Self::HIGH_BIT_DEPTH) {
+            ColorRange::Full
+        } else {
+            ColorRange::Limited
+        }
+    }
+
     /// Creates flags from FFI value.
     pub(crate) fn from_ffi(value: u32) -> Self {
         Self(value)
@@ -127,3 +205,60 @@ impl std::ops::BitAnd for ReceiveFlags {
         Self(self.0 & rhs.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_video_flags_to_range() {
+        assert_eq!(VideoFlags::NONE.to_range(), ColorRange::Limited);
+        assert_eq!(
+            (VideoFlags::ALPHA | VideoFlags::PREVIEW).to_range(),
+            ColorRange::Limited
+        );
+        assert_eq!(
+            (VideoFlags::HIGH_BIT_DEPTH | VideoFlags::ALPHA).to_range(),
+            ColorRange::Full
+        );
+    }
+
+    #[test]
+    fn test_video_flags_predicates() {
+        let flags = VideoFlags::INTERLACED | VideoFlags::PREVIEW;
+        assert!(flags.is_interlaced());
+        assert!(flags.is_preview());
+        assert!(!flags.has_alpha());
+        assert!(!flags.is_premultiplied());
+        assert!(!flags.is_high_bit_depth());
+
+        let flags = VideoFlags::ALPHA | VideoFlags::PRE_MULTIPLIED | VideoFlags::HIGH_BIT_DEPTH;
+        assert!(flags.has_alpha());
+        assert!(flags.is_premultiplied());
+        assert!(flags.is_high_bit_depth());
+        assert!(!flags.is_interlaced());
+        assert!(!flags.is_preview());
+    }
+
+    #[test]
+    fn test_video_flags_for_codec() {
+        for codec in [
+            Codec::Uyvy,
+            Codec::Yuy2,
+            Codec::Bgra,
+            Codec::Nv12,
+            Codec::Yv12,
+        ] {
+            assert_eq!(VideoFlags::for_codec(codec), VideoFlags::NONE);
+        }
+        assert_eq!(VideoFlags::for_codec(Codec::Uyva), VideoFlags::ALPHA);
+        assert_eq!(
+            VideoFlags::for_codec(Codec::P216),
+            VideoFlags::HIGH_BIT_DEPTH
+        );
+        assert_eq!(
+            VideoFlags::for_codec(Codec::Pa16),
+            VideoFlags::ALPHA | VideoFlags::HIGH_BIT_DEPTH
+        );
+    }
+}
@@ -1,7 +1,15 @@
 //! Preferred video format definitions for receiving.
 
+use crate::error::Error;
+use crate::types::Codec;
+use std::fmt;
+use std::str::FromStr;
+
 /// Preferred video format for receiving.
 ///
+/// Formats can be parsed from and displayed as kebab-case names such as
+/// `"uyvy-or-bgra"`, e.g. for command line arguments.
+///
 /// - `Uyvy` is always the fastest, if no alpha channel is required.
 /// - `UyvyOrBgra` will provide BGRA only when alpha channel is present.
 /// - `Bgra` will always convert back to BGRA.
@@ -26,6 +34,63 @@ pub enum PreferredVideoFormat {
 }
 
 impl PreferredVideoFormat {
+    /// Prefers UYVY, switching to UYVA when the source has an alpha channel.
+    ///
+    /// Maps to [`UyvyOrUyva`](Self::UyvyOrUyva).
+    pub const fn prefer_alpha() -> Self {
+        Self::UyvyOrUyva
+    }
+
+    /// Prefers the sender's bit depth, receiving P216/PA16 for high bit depth
+    /// sources and UYVY/UYVA otherwise.
+    ///
+    /// Maps to [`UyvyOrUyvaOrP216OrPa16`](Self::UyvyOrUyvaOrP216OrPa16).
+    pub const fn prefer_high_bit_depth() -> Self {
+        Self::UyvyOrUyvaOrP216OrPa16
+    }
+
+    /// Always receives RGB, including any alpha channel, as BGRA.
+    ///
+    /// Maps to [`Bgra`](Self::Bgra).
+    pub const fn prefer_rgb() -> Self {
+        Self::Bgra
+    }
+
+    /// Returns the codecs received frames can have with this preference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{Codec, PreferredVideoFormat};
+    ///
+    /// assert_eq!(
+    ///     PreferredVideoFormat::prefer_alpha().describe(),
+    ///     &[Codec::Uyvy, Codec::Uyva]
+    /// );
+    /// ```
+    pub const fn describe(self) -> &'static [Codec] {
+        match self {
+            Self::Uyvy => &[Codec::Uyvy],
+            Self::UyvyOrBgra => &[Codec::Uyvy, Codec::Bgra],
+            Self::Bgra => &[Codec::Bgra],
+            Self::UyvyOrUyva => &[Codec::Uyvy, Codec::Uyva],
+            Self::UyvyOrUyvaOrP216OrPa16 => &[Codec::Uyvy, Codec::Uyva, Codec::P216, Codec::Pa16],
+            Self::P216 => &[Codec::P216],
+        }
+    }
+
+    /// Returns the canonical kebab-case name, e.g. `"uyvy-or-bgra"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Uyvy => "uyvy",
+            Self::UyvyOrBgra => "uyvy-or-bgra",
+            Self::Bgra => "bgra",
+            Self::UyvyOrUyva => "uyvy-or-uyva",
+            Self::UyvyOrUyvaOrP216OrPa16 => "uyvy-or-uyva-or-p216-or-pa16",
+            Self::P216 => "p216",
+        }
+    }
+
     /// Creates from FFI value.
     pub(crate) fn from_ffi(value: u32) -> Option<Self> {
         match value {
@@ -46,3 +111,144 @@ impl PreferredVideoFormat {
         self as u32
     }
 }
+
+/// All formats, in declaration order.
+const ALL_FORMATS: [PreferredVideoFormat; 6] = [
+    PreferredVideoFormat::Uyvy,
+    PreferredVideoFormat::UyvyOrBgra,
+    PreferredVideoFormat::Bgra,
+    PreferredVideoFormat::UyvyOrUyva,
+    PreferredVideoFormat::UyvyOrUyvaOrP216OrPa16,
+    PreferredVideoFormat::P216,
+];
+
+impl fmt::Display for PreferredVideoFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PreferredVideoFormat {
+    type Err = Error;
+
+    /// Parses a kebab-case name such as `"uyvy-or-bgra"` or a variant name
+    /// such as `"UyvyOrBgra"`, ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::PreferredVideoFormat;
+    ///
+    /// let format: PreferredVideoFormat = "uyvy-or-bgra".parse()?;
+    /// assert_eq!(format, PreferredVideoFormat::UyvyOrBgra);
+    /// assert_eq!("UyvyOrBgra".parse::<PreferredVideoFormat>()?, format);
+    /// assert_eq!(format.to_string(), "uyvy-or-bgra");
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        ALL_FORMATS
+            .into_iter()
+            .find(|format| {
+                s.eq_ignore_ascii_case(format.as_str())
+                    || s.eq_ignore_ascii_case(&format!("{:?}", format))
+            })
+            .ok_or_else(|| Error::InvalidParameter {
+                parameter: "format".to_string(),
+                reason: format!(
+                    "unknown video format '{}', expected one of: {}",
+                    s,
+                    ALL_FORMATS.map(|format| format.as_str()).join(", ")
+                ),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preference_constructors() {
+        assert_eq!(
+            PreferredVideoFormat::prefer_alpha(),
+            PreferredVideoFormat::UyvyOrUyva
+        );
+        assert_eq!(
+            PreferredVideoFormat::prefer_high_bit_depth(),
+            PreferredVideoFormat::UyvyOrUyvaOrP216OrPa16
+        );
+        assert_eq!(
+            PreferredVideoFormat::prefer_rgb(),
+            PreferredVideoFormat::Bgra
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            PreferredVideoFormat::prefer_alpha().describe(),
+            &[Codec::Uyvy, Codec::Uyva]
+        );
+        assert_eq!(
+            PreferredVideoFormat::prefer_high_bit_depth().describe(),
+            &[Codec::Uyvy, Codec::Uyva, Codec::P216, Codec::Pa16]
+        );
+        assert_eq!(PreferredVideoFormat::Uyvy.describe(), &[Codec::Uyvy]);
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        let cases = [
+            (PreferredVideoFormat::Uyvy, "uyvy", "Uyvy"),
+            (
+                PreferredVideoFormat::UyvyOrBgra,
+                "uyvy-or-bgra",
+                "UyvyOrBgra",
+            ),
+            (PreferredVideoFormat::Bgra, "bgra", "Bgra"),
+            (
+                PreferredVideoFormat::UyvyOrUyva,
+                "uyvy-or-uyva",
+                "UyvyOrUyva",
+            ),
+            (
+                PreferredVideoFormat::UyvyOrUyvaOrP216OrPa16,
+                "uyvy-or-uyva-or-p216-or-pa16",
+                "UyvyOrUyvaOrP216OrPa16",
+            ),
+            (PreferredVideoFormat::P216, "p216", "P216"),
+        ];
+
+        for (format, kebab, name) in cases {
+            assert_eq!(format.to_string(), kebab);
+            assert_eq!(kebab.parse::<PreferredVideoFormat>().unwrap(), format);
+            assert_eq!(name.parse::<PreferredVideoFormat>().unwrap(), format);
+            assert_eq!(
+                kebab
+                    .to_uppercase()
+                    .parse::<PreferredVideoFormat>()
+                    .unwrap(),
+                format
+            );
+            assert_eq!(
+                name.to_lowercase().parse::<PreferredVideoFormat>().unwrap(),
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        match "rgb24".parse::<PreferredVideoFormat>() {
+            Err(Error::InvalidParameter { parameter, reason }) => {
+                assert_eq!(parameter, "format");
+                assert!(reason.contains("'rgb24'"));
+                for format in ALL_FORMATS {
+                    assert!(reason.contains(format.as_str()), "{}", reason);
+                }
+            }
+            other => panic!("Expected InvalidParameter, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Video frame rate definitions.
+
+use crate::error::{Error, Result};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Largest denominator produced by [`FrameRate::from_f32()`].
+const MAX_APPROXIMATION_DENOMINATOR: i64 = 1001;
+
+/// Integer rates whose NTSC variant (`n * 1000/1001`) is snapped to exactly.
+const NTSC_BASE_RATES: [i32; 5] = [24, 30, 48, 60, 120];
+
+/// Maximum distance in fps for snapping to an NTSC rate.
+const NTSC_SNAP_TOLERANCE: f64 = 0.005;
+
+/// A video frame rate expressed as a fraction of frames per second.
+///
+/// Fractional broadcast rates are represented exactly, e.g. 29.97 fps is
+/// `30000/1001`. Both numerator and denominator are always positive.
+///
+/// Frame rates compare, order and hash by value, so `60/2` equals `30/1`
+/// while [`numerator()`](Self::numerator) and
+/// [`denominator()`](Self::denominator) keep the given representation.
+///
+/// # Examples
+///
+/// ```
+/// use omt::FrameRate;
+///
+/// let ntsc = FrameRate::new(30000, 1001).unwrap();
+/// assert!((ntsc.value() - 29.97).abs() < 0.001);
+/// assert_eq!(ntsc, FrameRate::fps_29_97());
+///
+/// assert!(FrameRate::new(0, 0).is_none());
+///
+/// let mut rates = vec![FrameRate::fps_60(), FrameRate::fps_23_976(), FrameRate::fps_30()];
+/// rates.sort();
+/// assert_eq!(rates, [FrameRate::fps_23_976(), FrameRate::fps_30(), FrameRate::fps_60()]);
+/// assert_eq!(FrameRate::new(60, 2), FrameRate::new(30, 1));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FrameRate {
+    numerator: i32,
+    denominator: i32,
+}
+
+impl FrameRate {
+    /// Creates a frame rate from a numerator and denominator.
+    ///
+    /// Returns `None` if either value is not positive.
+    pub fn new(numerator: i32, denominator: i32) -> Option<Self> {
+        if numerator > 0 && denominator > 0 {
+            Some(Self {
+                numerator,
+                denominator,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// 23.976 fps (`24000/1001`).
+    pub const fn fps_23_976() -> Self {
+        Self::fixed(24000, 1001)
+    }
+
+    /// 24 fps.
+    pub const fn fps_24() -> Self {
+        Self::fixed(24, 1)
+    }
+
+    /// 25 fps.
+    pub const fn fps_25() -> Self {
+        Self::fixed(25, 1)
+    }
+
+    /// 29.97 fps (`30000/1001`).
+    pub const fn fps_29_97() -> Self {
+        Self::fixed(30000, 1001)
+    }
+
+    /// 30 fps.
+    pub const fn fps_30() -> Self {
+        Self::fixed(30, 1)
+    }
+
+    /// 50 fps.
+    pub const fn fps_50() -> Self {
+        Self::fixed(50, 1)
+    }
+
+    /// 59.94 fps (`60000/1001`).
+    pub const fn fps_59_94() -> Self {
+        Self::fixed(60000, 1001)
+    }
+
+    /// 60 fps.
+    pub const fn fps_60() -> Self {
+        Self::fixed(60, 1)
+    }
+
+    /// Constructs a rate from known-positive constants.
+    const fn fixed(numerator: i32, denominator: i32) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Approximates a floating point frame rate, e.g. as reported by a
+    /// capture device.
+    ///
+    /// Values within 0.005 fps of an NTSC rate such as 23.976, 29.97, 47.952,
+    /// 59.94 or 119.88 are snapped to the exact `n * 1000/1001` fraction. Other
+    /// values are approximated with continued fractions, using the closest
+    /// fraction with a denominator of at most 1001.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `fps` is not finite, not
+    /// positive, too small to approximate, or too large for an `i32`
+    /// numerator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::FrameRate;
+    ///
+    /// assert_eq!(FrameRate::from_f32(29.97)?, FrameRate::fps_29_97());
+    /// assert_eq!(FrameRate::from_f32(15.5)?, FrameRate::new(31, 2).unwrap());
+    /// assert!(FrameRate::from_f32(f32::NAN).is_err());
+    /// # Ok::<(), omt::Error>(())
+    /// ```
+    pub fn from_f32(fps: f32) -> Result<Self> {
+        let invalid = |reason: &str| Error::InvalidParameter {
+            parameter: "fps".to_string(),
+            reason: format!("{}, got {}", reason, fps),
+        };
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(invalid(
+                "frame rate must be a finite value greater than zero",
+            ));
+        }
+
+        let fps = fps as f64;
+        if let Some(base) = NTSC_BASE_RATES
+            .into_iter()
+            .find(|&base| (fps - base as f64 * 1000.0 / 1001.0).abs() < NTSC_SNAP_TOLERANCE)
+        {
+            return Ok(Self::fixed(base * 1000, 1001));
+        }
+
+        let (numerator, denominator) = approximate_fraction(fps);
+        Self::new(numerator, denominator).ok_or_else(|| {
+            invalid("frame rate cannot be represented as a fraction of 32-bit integers")
+        })
+    }
+
+    /// Returns the numerator (frames).
+    pub fn numerator(&self) -> i32 {
+        self.numerator
+    }
+
+    /// Returns the denominator (seconds).
+    pub fn denominator(&self) -> i32 {
+        self.denominator
+    }
+
+    /// Returns the frame rate in frames per second.
+    pub fn value(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl FrameRate {
+    /// Returns the fraction in lowest terms.
+    fn reduced(&self) -> (i32, i32) {
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        (self.numerator / a, self.denominator / a)
+    }
+}
+
+impl PartialEq for FrameRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FrameRate {}
+
+impl Hash for FrameRate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal rates have the same reduced fraction
+        self.reduced().hash(state);
+    }
+}
+
+impl PartialOrd for FrameRate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FrameRate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Cross-multiplied in i64, which cannot overflow for i32 operands
+        let lhs = self.numerator as i64 * other.denominator as i64;
+        let rhs = other.numerator as i64 * self.denominator as i64;
+        lhs.cmp(&rhs)
+    }
+}
+
+/// Finds the last continued fraction convergent of `value` whose denominator
+/// is at most [`MAX_APPROXIMATION_DENOMINATOR`] and whose numerator fits an `i32`.
+///
+/// Returns a zero denominator if even the integer part does not fit.
+fn approximate_fraction(value: f64) -> (i32, i32) {
+    // Convergents h/k, starting from the conventional h(-1)/k(-1) = 1/0 and h(-2)/k(-2) = 0/1
+    let (mut h_prev, mut h) = (0i64, 1i64);
+    let (mut k_prev, mut k) = (1i64, 0i64);
+    let mut remainder = value;
+
+    loop {
+        let term = remainder.floor();
+        if term > i32::MAX as f64 {
+            break;
+        }
+        let term = term as i64;
+        let h_next = term * h + h_prev;
+        let k_next = term * k + k_prev;
+        if h_next > i32::MAX as i64 || k_next > MAX_APPROXIMATION_DENOMINATOR {
+            break;
+        }
+        (h_prev, h) = (h, h_next);
+        (k_prev, k) = (k, k_next);
+
+        let fraction = remainder - remainder.floor();
+        if fraction < 1e-9 {
+            break;
+        }
+        remainder = 1.0 / fraction;
+    }
+
+    (h as i32, k as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordering_by_value() {
+        let fps = |n, d| FrameRate::new(n, d).unwrap();
+
+        assert!(fps(30, 1) < fps(60, 1));
+        assert!(FrameRate::fps_29_97() < FrameRate::fps_30());
+        assert!(fps(i32::MAX, 1) > fps(i32::MAX - 1, 1));
+        assert!(fps(1, i32::MAX) < fps(1, i32::MAX - 1));
+
+        assert_eq!(fps(30, 1).cmp(&fps(60, 2)), Ordering::Equal);
+        assert_eq!(fps(30, 1), fps(60, 2));
+        assert_ne!(fps(30, 1), fps(60, 1));
+        assert_eq!(fps(60, 2).numerator(), 60);
+    }
+
+    #[test]
+    fn test_hash_matches_eq() {
+        use std::collections::HashSet;
+
+        let rates: HashSet<FrameRate> = [
+            FrameRate::new(30, 1).unwrap(),
+            FrameRate::new(60, 2).unwrap(),
+            FrameRate::new(3000, 100).unwrap(),
+            FrameRate::fps_29_97(),
+            FrameRate::new(60000, 2002).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(rates.len(), 2);
+    }
+
+    #[test]
+    fn test_from_f32_snaps_ntsc_rates() {
+        assert_eq!(
+            FrameRate::from_f32(23.976).unwrap(),
+            FrameRate::fps_23_976()
+        );
+        assert_eq!(FrameRate::from_f32(23.98).unwrap(), FrameRate::fps_23_976());
+        assert_eq!(FrameRate::from_f32(29.97).unwrap(), FrameRate::fps_29_97());
+        assert_eq!(
+            FrameRate::from_f32(30000.0 / 1001.0).unwrap(),
+            FrameRate::fps_29_97()
+        );
+        assert_eq!(FrameRate::from_f32(59.94).unwrap(), FrameRate::fps_59_94());
+        assert_eq!(
+            FrameRate::from_f32(119.88).unwrap(),
+            FrameRate::new(120000, 1001).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_f32_approximates() {
+        assert_eq!(FrameRate::from_f32(25.0).unwrap(), FrameRate::fps_25());
+        assert_eq!(
+            FrameRate::from_f32(15.5).unwrap(),
+            FrameRate::new(31, 2).unwrap()
+        );
+        assert_eq!(
+            FrameRate::from_f32(12.5).unwrap(),
+            FrameRate::new(25, 2).unwrap()
+        );
+        assert_eq!(
+            FrameRate::from_f32(0.1).unwrap(),
+            FrameRate::new(1, 10).unwrap()
+        );
+
+        let odd = FrameRate::from_f32(14.985).unwrap();
+        assert!(odd.denominator() <= 1001);
+        assert!((odd.value() - 14.985).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_from_f32_rejects_invalid() {
+        for fps in [
+            0.0,
+            -25.0,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            1e-6,
+            1e12,
+        ] {
+            assert!(
+                matches!(
+                    FrameRate::from_f32(fps),
+                    Err(Error::InvalidParameter { .. })
+                ),
+                "{} should be rejected",
+                fps
+            );
+        }
+    }
+}
@@ -1,17 +1,31 @@
 //! Core types and enumerations for the OMT library.
 
+mod alpha_mode;
+mod chroma_upsample;
 mod codec;
+mod color_matrix;
+mod color_range;
 mod color_space;
+mod field_order;
 mod flags;
 mod format;
+mod frame_rate;
 mod frame_type;
+mod plane_layout;
 mod quality;
 mod sender_info;
 
+pub use alpha_mode::AlphaMode;
+pub use chroma_upsample::ChromaUpsample;
 pub use codec::Codec;
+pub use color_matrix::ColorMatrix;
+pub use color_range::ColorRange;
 pub use color_space::ColorSpace;
+pub use field_order::FieldOrder;
 pub use flags::{ReceiveFlags, VideoFlags};
 pub use format::PreferredVideoFormat;
+pub use frame_rate::FrameRate;
 pub use frame_type::FrameType;
+pub use plane_layout::{Plane, PlaneLayout};
 pub use quality::Quality;
-pub use sender_info::SenderInfo;
+pub use sender_info::{SenderInfo, SenderInfoChange};
@@ -0,0 +1,212 @@
+//! Byte layout of the planes of uncompressed video frames.
+
+use super::codec::Codec;
+
+/// A single plane of an uncompressed video frame.
+///
+/// `width` and `height` count the elements of the plane: pixels for packed
+/// and luma planes, chroma samples for planar chroma planes, and U/V pairs
+/// for interleaved chroma planes (NV12, NV21, P216, PA16).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Plane {
+    /// Byte offset of the first row from the start of the frame data.
+    pub offset: usize,
+    /// Number of bytes per row, including any padding.
+    pub stride: usize,
+    /// Number of elements per row.
+    pub width: usize,
+    /// Number of rows.
+    pub height: usize,
+}
+
+impl Plane {
+    /// Returns the number of bytes the plane occupies, `stride * height`.
+    pub fn size(&self) -> usize {
+        self.stride * self.height
+    }
+
+    /// Returns the byte offset just past the last row of the plane.
+    pub fn end(&self) -> usize {
+        self.offset + self.size()
+    }
+}
+
+/// Byte offsets, strides and dimensions of each plane of a video frame.
+///
+/// Packed formats (UYVY, YUY2, BGRA) have a single plane. The other formats
+/// list their planes in memory order:
+///
+/// | Codec       | Planes                                  |
+/// |-------------|-----------------------------------------|
+/// | UYVA        | UYVY, alpha                             |
+/// | NV12, NV21  | Y, interleaved UV (VU for NV21)         |
+/// | YV12        | Y, V, U                                 |
+/// | P216        | Y, interleaved UV                       |
+/// | PA16        | Y, interleaved UV, alpha                |
+///
+/// Chroma planes follow the frame stride in the same way as
+/// [`Codec::expected_data_len()`], so the last plane ends at exactly that
+/// length.
+///
+/// # Examples
+///
+/// ```
+/// use omt::{Codec, PlaneLayout};
+///
+/// let layout = PlaneLayout::new(Codec::Nv12, 1920, 1080, 2048).unwrap();
+/// let uv = layout.planes[1];
+/// assert_eq!((uv.offset, uv.stride, uv.width, uv.height), (2048 * 1080, 2048, 960, 540));
+/// assert_eq!(layout.total_size(), Codec::Nv12.expected_data_len(1920, 1080, 2048).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlaneLayout {
+    /// Codec the layout describes.
+    pub codec: Codec,
+    /// Planes in memory order.
+    pub planes: Vec<Plane>,
+}
+
+impl PlaneLayout {
+    /// Computes the layout of a frame with the given geometry.
+    ///
+    /// `stride` is the number of bytes per row of the first plane.
+    ///
+    /// Returns `None` for VMX1 and FPA1, for zero dimensions, and for a stride
+    /// too small to hold a row, like [`Codec::expected_data_len()`].
+    pub fn new(codec: Codec, width: usize, height: usize, stride: usize) -> Option<Self> {
+        // Rejects unsupported codecs and invalid geometry, and guarantees
+        // that none of the sizes below overflow
+        codec.expected_data_len(width, height, stride)?;
+
+        let first = Plane {
+            offset: 0,
+            stride,
+            width,
+            height,
+        };
+        let chroma_width = width.div_ceil(2);
+        let mut planes = vec![first];
+        let mut push = |stride: usize, width: usize, height: usize| {
+            let offset = planes.last().map_or(0, Plane::end);
+            planes.push(Plane {
+                offset,
+                stride,
+                width,
+                height,
+            });
+        };
+        match codec {
+            Codec::Uyvy | Codec::Yuy2 | Codec::Bgra => {}
+            Codec::Uyva => push(width, width, height),
+            Codec::Nv12 | Codec::Nv21 => push(stride, chroma_width, height.div_ceil(2)),
+            Codec::Yv12 => {
+                push(stride / 2, chroma_width, height.div_ceil(2));
+                push(stride / 2, chroma_width, height.div_ceil(2));
+            }
+            Codec::P216 => push(chroma_width * 4, chroma_width, height),
+            Codec::Pa16 => {
+                push(chroma_width * 4, chroma_width, height);
+                push(width * 2, width, height);
+            }
+            Codec::Vmx1 | Codec::Fpa1 => return None,
+        }
+        Some(Self { codec, planes })
+    }
+
+    /// Returns the number of planes.
+    pub fn plane_count(&self) -> usize {
+        self.planes.len()
+    }
+
+    /// Returns the number of bytes covered by all planes.
+    pub fn total_size(&self) -> usize {
+        self.planes.last().map_or(0, Plane::end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plane(offset: usize, stride: usize, width: usize, height: usize) -> Plane {
+        Plane {
+            offset,
+            stride,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_packed_layout_has_one_plane() {
+        let layout = PlaneLayout::new(Codec::Bgra, 4, 2, 20).unwrap();
+        assert_eq!(layout.planes, [plane(0, 20, 4, 2)]);
+        assert_eq!(layout.total_size(), 40);
+    }
+
+    #[test]
+    fn test_nv12_layout() {
+        let layout = PlaneLayout::new(Codec::Nv12, 6, 4, 8).unwrap();
+        assert_eq!(layout.codec, Codec::Nv12);
+        assert_eq!(layout.planes, [plane(0, 8, 6, 4), plane(32, 8, 3, 2)]);
+        assert_eq!(layout.total_size(), 48);
+    }
+
+    #[test]
+    fn test_yv12_layout() {
+        let layout = PlaneLayout::new(Codec::Yv12, 6, 4, 8).unwrap();
+        assert_eq!(layout.plane_count(), 3);
+        assert_eq!(
+            layout.planes,
+            [plane(0, 8, 6, 4), plane(32, 4, 3, 2), plane(40, 4, 3, 2)]
+        );
+
+        // Odd heights round the chroma rows up
+        let layout = PlaneLayout::new(Codec::Yv12, 4, 3, 4).unwrap();
+        assert_eq!(
+            layout.planes,
+            [plane(0, 4, 4, 3), plane(12, 2, 2, 2), plane(16, 2, 2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_pa16_layout() {
+        let layout = PlaneLayout::new(Codec::Pa16, 4, 2, 8).unwrap();
+        assert_eq!(
+            layout.planes,
+            [plane(0, 8, 4, 2), plane(16, 8, 2, 2), plane(32, 8, 4, 2)]
+        );
+    }
+
+    #[test]
+    fn test_total_size_matches_expected_data_len() {
+        let codecs = [
+            Codec::Uyvy,
+            Codec::Yuy2,
+            Codec::Uyva,
+            Codec::Bgra,
+            Codec::Nv12,
+            Codec::Nv21,
+            Codec::Yv12,
+            Codec::P216,
+            Codec::Pa16,
+        ];
+        for codec in codecs {
+            for (width, height) in [(16, 8), (15, 9), (1, 1)] {
+                let layout = PlaneLayout::new(codec, width, height, 64).unwrap();
+                assert_eq!(
+                    Some(layout.total_size()),
+                    codec.expected_data_len(width, height, 64),
+                    "{codec:?} {width}x{height}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_geometry() {
+        assert_eq!(PlaneLayout::new(Codec::Vmx1, 16, 16, 16), None);
+        assert_eq!(PlaneLayout::new(Codec::Nv12, 0, 16, 16), None);
+        assert_eq!(PlaneLayout::new(Codec::Uyvy, 16, 16, 16), None);
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::MAX_STRING_LENGTH;
 use crate::error::{Error, Result};
+use std::ffi::CString;
 use std::fmt;
 
 /// Information describing the sender.
@@ -25,6 +26,45 @@ impl SenderInfo {
         }
     }
 
+    /// Returns the fields that differ between `self` and `other`.
+    ///
+    /// Each change holds the value from `self` as `old` and the value from
+    /// `other` as `new`, in field order. Identical infos yield an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omt::{SenderInfo, SenderInfoChange};
+    ///
+    /// let before = SenderInfo::new("Switcher".into(), "Acme".into(), "1.0".into());
+    /// let after = SenderInfo::new("Switcher".into(), "Acme".into(), "1.1".into());
+    /// for change in before.diff(&after) {
+    ///     println!("Sender changed: {}", change);
+    /// }
+    /// ```
+    pub fn diff(&self, other: &SenderInfo) -> Vec<SenderInfoChange> {
+        let mut changes = Vec::new();
+        if self.product_name != other.product_name {
+            changes.push(SenderInfoChange::ProductName {
+                old: self.product_name.clone(),
+                new: other.product_name.clone(),
+            });
+        }
+        if self.manufacturer != other.manufacturer {
+            changes.push(SenderInfoChange::Manufacturer {
+                old: self.manufacturer.clone(),
+                new: other.manufacturer.clone(),
+            });
+        }
+        if self.version != other.version {
+            changes.push(SenderInfoChange::Version {
+                old: self.version.clone(),
+                new: other.version.clone(),
+            });
+        }
+        changes
+    }
+
     /// Creates from FFI struct.
     pub(crate) fn from_ffi(ffi: &omt_sys::OMTSenderInfo) -> Result<Self> {
         Ok(Self {
@@ -35,6 +75,10 @@ impl SenderInfo {
     }
 
     /// Converts to FFI struct.
+    ///
+    /// Fails with [`Error::BufferTooSmall`] if a field does not fit into
+    /// `MAX_STRING_LENGTH` bytes including the null terminator, or with
+    /// [`Error::NulError`] if it contains a null byte.
     pub(crate) fn to_ffi(&self) -> Result<omt_sys::OMTSenderInfo> {
         let mut ffi = omt_sys::OMTSenderInfo {
             ProductName: [0; MAX_STRING_LENGTH],
@@ -69,7 +113,9 @@ impl SenderInfo {
     }
 
     fn string_to_c_array(s: &str, arr: &mut [i8; MAX_STRING_LENGTH]) -> Result<()> {
-        let bytes = s.as_bytes();
+        // Rejects interior null bytes, which would silently truncate the string
+        let c_string = CString::new(s)?;
+        let bytes = c_string.as_bytes();
 
         // We need space for the string plus a null terminator
         if bytes.len() >= MAX_STRING_LENGTH {
@@ -97,6 +143,44 @@ impl SenderInfo {
     }
 }
 
+/// A single field that differs between two [`SenderInfo`] values.
+///
+/// Returned by [`SenderInfo::diff()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SenderInfoChange {
+    /// The product name changed.
+    ProductName {
+        /// Previous product name.
+        old: String,
+        /// Current product name.
+        new: String,
+    },
+    /// The manufacturer changed.
+    Manufacturer {
+        /// Previous manufacturer.
+        old: String,
+        /// Current manufacturer.
+        new: String,
+    },
+    /// The version changed.
+    Version {
+        /// Previous version.
+        old: String,
+        /// Current version.
+        new: String,
+    },
+}
+
+impl fmt::Display for SenderInfoChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProductName { old, new } => write!(f, "product name '{}' -> '{}'", old, new),
+            Self::Manufacturer { old, new } => write!(f, "manufacturer '{}' -> '{}'", old, new),
+            Self::Version { old, new } => write!(f, "version '{}' -> '{}'", old, new),
+        }
+    }
+}
+
 impl Default for SenderInfo {
     fn default() -> Self {
         Self {
@@ -116,3 +200,62 @@ impl fmt::Display for SenderInfo {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(version: &str) -> SenderInfo {
+        SenderInfo::new(
+            "Switcher".to_string(),
+            "Acme".to_string(),
+            version.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        assert!(info("1.0").diff(&info("1.0")).is_empty());
+    }
+
+    #[test]
+    fn test_diff_version_only() {
+        assert_eq!(
+            info("1.0").diff(&info("1.1")),
+            vec![SenderInfoChange::Version {
+                old: "1.0".to_string(),
+                new: "1.1".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_all_fields() {
+        let changes = info("1.0").diff(&SenderInfo::default());
+        assert_eq!(changes.len(), 3);
+        assert!(matches!(changes[0], SenderInfoChange::ProductName { .. }));
+        assert!(matches!(changes[1], SenderInfoChange::Manufacturer { .. }));
+        assert_eq!(changes[2].to_string(), "version '1.0' -> ''");
+    }
+
+    #[test]
+    fn test_to_ffi_round_trip() {
+        let ffi = info("1.0").to_ffi().unwrap();
+        assert_eq!(SenderInfo::from_ffi(&ffi).unwrap(), info("1.0"));
+    }
+
+    #[test]
+    fn test_to_ffi_rejects_invalid_strings() {
+        assert!(matches!(
+            info("1.0\0beta").to_ffi(),
+            Err(Error::NulError(_))
+        ));
+
+        let longest = "v".repeat(MAX_STRING_LENGTH - 1);
+        assert!(info(&longest).to_ffi().is_ok());
+        assert!(matches!(
+            info(&format!("{}v", longest)).to_ffi(),
+            Err(Error::BufferTooSmall { .. })
+        ));
+    }
+}
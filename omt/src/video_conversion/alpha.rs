@@ -0,0 +1,61 @@
+//! Conversion between straight and premultiplied alpha.
+
+use rgb::RGBA8;
+
+/// Multiplies the color channels of each pixel by its alpha.
+pub fn premultiply_alpha(pixels: &mut [RGBA8]) {
+    for pixel in pixels {
+        let a = pixel.a as u16;
+        let scale = |c: u8| ((c as u16 * a + 127) / 255) as u8;
+        pixel.r = scale(pixel.r);
+        pixel.g = scale(pixel.g);
+        pixel.b = scale(pixel.b);
+    }
+}
+
+/// Divides the color channels of each pixel by its alpha.
+///
+/// Fully transparent pixels become black; values exceeding alpha, which are
+/// invalid for premultiplied data, saturate at 255.
+pub fn unpremultiply_alpha(pixels: &mut [RGBA8]) {
+    for pixel in pixels {
+        let a = pixel.a as u16;
+        let scale = |c: u8| match a {
+            0 => 0,
+            _ => ((c as u16 * 255 + a / 2) / a).min(255) as u8,
+        };
+        pixel.r = scale(pixel.r);
+        pixel.g = scale(pixel.g);
+        pixel.b = scale(pixel.b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_premultiply_round_trip() {
+        let mut pixels = vec![
+            RGBA8::new(255, 128, 0, 128),
+            RGBA8::new(200, 100, 50, 255),
+            RGBA8::new(255, 255, 255, 0),
+        ];
+        premultiply_alpha(&mut pixels);
+        assert_eq!(pixels[0], RGBA8::new(128, 64, 0, 128));
+        assert_eq!(pixels[1], RGBA8::new(200, 100, 50, 255));
+        assert_eq!(pixels[2], RGBA8::new(0, 0, 0, 0));
+
+        unpremultiply_alpha(&mut pixels);
+        assert_eq!(pixels[0], RGBA8::new(255, 128, 0, 128));
+        assert_eq!(pixels[1], RGBA8::new(200, 100, 50, 255));
+        assert_eq!(pixels[2], RGBA8::new(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_unpremultiply_saturates_invalid_values() {
+        let mut pixels = vec![RGBA8::new(200, 0, 0, 100)];
+        unpremultiply_alpha(&mut pixels);
+        assert_eq!(pixels[0].r, 255);
+    }
+}
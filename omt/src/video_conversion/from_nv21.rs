@@ -0,0 +1,281 @@
+//! NV21 video frame conversion functions.
+//!
+//! NV21 has the same layout as NV12, a Y plane followed by an interleaved half height
+//! chroma plane, but stores V before U in each chroma pair.
+
+use rgb::bytemuck;
+use rgb::*;
+use yuv::{YuvBiPlanarImage, YuvConversionMode, YuvRange, YuvStandardMatrix};
+
+/// Convert NV21 data to RGB8.
+///
+/// # Arguments
+///
+/// * `raw_data` - The Y plane followed by the interleaved V/U plane
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of both planes
+/// * `yuv_range` - The YUV range (limited or full)
+/// * `yuv_matrix` - The YUV standard matrix (BT.601 or BT.709)
+///
+/// # Returns
+///
+/// Returns `Some(Vec<RGB8>)` on success, or `None` if the data is too short
+/// or the conversion fails.
+pub fn nv21_to_rgb8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGB8>> {
+    let y_plane = raw_data.get(0..height * stride)?;
+    let vu_plane = &raw_data[height * stride..];
+
+    let bi_planar_image = YuvBiPlanarImage {
+        y_plane,
+        y_stride: stride as u32,
+        uv_plane: vu_plane,
+        uv_stride: stride as u32,
+        width: width as u32,
+        height: height as u32,
+    };
+
+    let mut rgb_data = vec![RGB8::new(0, 0, 0); width * height];
+    let rgb_stride = (width * 3) as u32;
+
+    yuv::yuv_nv21_to_rgb(
+        &bi_planar_image,
+        bytemuck::cast_slice_mut(&mut rgb_data),
+        rgb_stride,
+        yuv_range,
+        yuv_matrix,
+        YuvConversionMode::Balanced,
+    )
+    .ok()?;
+
+    Some(rgb_data)
+}
+
+/// Convert NV21 data to RGBA8 with opaque alpha.
+///
+/// Takes the same arguments as [`nv21_to_rgb8`].
+pub fn nv21_to_rgba8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    let y_plane = raw_data.get(0..height * stride)?;
+    let vu_plane = &raw_data[height * stride..];
+
+    let bi_planar_image = YuvBiPlanarImage {
+        y_plane,
+        y_stride: stride as u32,
+        uv_plane: vu_plane,
+        uv_stride: stride as u32,
+        width: width as u32,
+        height: height as u32,
+    };
+
+    let mut rgba_data = vec![RGBA8::new(0, 0, 0, 255); width * height];
+    let rgba_stride = (width * 4) as u32;
+
+    yuv::yuv_nv21_to_rgba(
+        &bi_planar_image,
+        bytemuck::cast_slice_mut(&mut rgba_data),
+        rgba_stride,
+        yuv_range,
+        yuv_matrix,
+        YuvConversionMode::Balanced,
+    )
+    .ok()?;
+
+    Some(rgba_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::nv12_to_rgb8;
+    use super::super::test_utils::yuv_utils;
+    use super::*;
+    use yuv::YuvRange::*;
+    use yuv::YuvStandardMatrix::*;
+
+    /// Create simple test NV21 data for gray image
+    fn create_gray_nv21_data(width: usize, height: usize, yuv_range: YuvRange) -> Vec<u8> {
+        let y_plane_size = width * height;
+        let vu_plane_size = width * height.div_ceil(2);
+        let mut nv21_data = vec![0u8; y_plane_size + vu_plane_size];
+
+        nv21_data[..y_plane_size].fill(yuv_utils::middle_gray_y(yuv_range));
+
+        // Set VU plane to neutral (128, 128) - no color
+        let (u_value, v_value) = yuv_utils::neutral_uv();
+        for pair in nv21_data[y_plane_size..].chunks_exact_mut(2) {
+            pair[0] = v_value;
+            pair[1] = u_value;
+        }
+
+        nv21_data
+    }
+
+    /// Create simple test NV21 data for color bars
+    fn create_color_bars_nv21_data(width: usize, height: usize, yuv_range: YuvRange) -> Vec<u8> {
+        let y_plane_size = width * height;
+        let mut nv21_data = vec![0u8; y_plane_size + width * height / 2];
+
+        // Fill Y plane with color bars
+        for y in 0..height {
+            for x in 0..width {
+                let bar_index = (x * 8) / width;
+                nv21_data[y * width + x] = yuv_utils::color_bar_y(bar_index, yuv_range);
+            }
+        }
+
+        // Fill VU plane with approximate values for color bars
+        for y in (0..height).step_by(2) {
+            for x in (0..width).step_by(2) {
+                let bar_index = (x * 8) / width;
+                let (u_value, v_value) = yuv_utils::color_bar_uv(bar_index);
+
+                let vu_index = y_plane_size + (y / 2) * width + x;
+                nv21_data[vu_index] = v_value;
+                nv21_data[vu_index + 1] = u_value;
+            }
+        }
+
+        nv21_data
+    }
+
+    /// Swaps each chroma pair, converting between NV21 and NV12.
+    fn swap_chroma(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+        let mut swapped = data.to_vec();
+        for pair in swapped[width * height..].chunks_exact_mut(2) {
+            pair.swap(0, 1);
+        }
+        swapped
+    }
+
+    #[test]
+    fn test_nv21_to_rgb8_gray() {
+        let width = 8;
+        let height = 8;
+        let stride = width;
+
+        for (yuv_range, yuv_matrix) in [
+            (Limited, Bt601),
+            (Full, Bt601),
+            (Limited, Bt709),
+            (Full, Bt709),
+        ] {
+            let nv21_data = create_gray_nv21_data(width, height, yuv_range);
+            let rgb_colors = nv21_to_rgb8(&nv21_data, width, height, stride, yuv_range, yuv_matrix)
+                .expect("nv21_to_rgb8 should return Some for gray image");
+
+            assert_eq!(rgb_colors.len(), width * height);
+            for (i, color) in rgb_colors.iter().enumerate() {
+                assert!(
+                    color.r == color.g && color.g == color.b,
+                    "Pixel at index {} should be gray for {:?} {:?}: R={}, G={}, B={}",
+                    i,
+                    yuv_range,
+                    yuv_matrix,
+                    color.r,
+                    color.g,
+                    color.b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_nv21_to_rgba8_gray() {
+        let width = 8;
+        let height = 8;
+        let stride = width;
+
+        for (yuv_range, yuv_matrix) in [
+            (Limited, Bt601),
+            (Full, Bt601),
+            (Limited, Bt709),
+            (Full, Bt709),
+        ] {
+            let nv21_data = create_gray_nv21_data(width, height, yuv_range);
+            let rgba_colors =
+                nv21_to_rgba8(&nv21_data, width, height, stride, yuv_range, yuv_matrix)
+                    .expect("nv21_to_rgba8 should return Some for gray image");
+
+            assert_eq!(rgba_colors.len(), width * height);
+            for (i, color) in rgba_colors.iter().enumerate() {
+                assert!(
+                    color.r == color.g && color.g == color.b,
+                    "Pixel at index {} should be gray: R={}, G={}, B={}",
+                    i,
+                    color.r,
+                    color.g,
+                    color.b
+                );
+                assert_eq!(color.a, 255, "Alpha should be 255 at index {}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nv21_conversion_with_color_bars() {
+        let width = 64;
+        let height = 8;
+        let stride = width;
+
+        let nv21_data = create_color_bars_nv21_data(width, height, Limited);
+
+        let rgb_colors = nv21_to_rgb8(&nv21_data, width, height, stride, Limited, Bt601)
+            .expect("nv21_to_rgb8 should return Some for color bars");
+        let rgba_colors = nv21_to_rgba8(&nv21_data, width, height, stride, Limited, Bt601)
+            .expect("nv21_to_rgba8 should return Some for color bars");
+        assert_eq!(rgb_colors.len(), width * height);
+        assert_eq!(rgba_colors.len(), width * height);
+
+        for (i, (rgb, rgba)) in rgb_colors.iter().zip(&rgba_colors).enumerate() {
+            assert_eq!(rgb.r, rgba.r, "Red mismatch at index {}", i);
+            assert_eq!(rgb.g, rgba.g, "Green mismatch at index {}", i);
+            assert_eq!(rgb.b, rgba.b, "Blue mismatch at index {}", i);
+            assert_eq!(rgba.a, 255, "Alpha should be 255 at index {}", i);
+        }
+
+        // The same bars stored as NV12 must decode identically
+        let nv12_data = swap_chroma(&nv21_data, width, height);
+        let nv12_colors = nv12_to_rgb8(&nv12_data, width, height, stride, Limited, Bt601)
+            .expect("nv12_to_rgb8 should return Some for color bars");
+        assert_eq!(rgb_colors, nv12_colors);
+
+        // Interpreting NV21 data as NV12 swaps the chroma and changes the colors
+        let misread = nv12_to_rgb8(&nv21_data, width, height, stride, Limited, Bt601)
+            .expect("nv12_to_rgb8 should return Some for color bars");
+        assert_ne!(rgb_colors, misread);
+    }
+
+    #[test]
+    fn test_nv21_conversion_edge_cases() {
+        for (width, height) in yuv_utils::planar_420_test_dimensions() {
+            let stride = width;
+            let nv21_data = create_gray_nv21_data(width, height, Limited);
+
+            let rgb_colors = nv21_to_rgb8(&nv21_data, width, height, stride, Limited, Bt601)
+                .unwrap_or_else(|| panic!("nv21_to_rgb8 failed for {}x{}", width, height));
+            let rgba_colors = nv21_to_rgba8(&nv21_data, width, height, stride, Limited, Bt601)
+                .unwrap_or_else(|| panic!("nv21_to_rgba8 failed for {}x{}", width, height));
+            assert_eq!(rgb_colors.len(), width * height);
+            assert_eq!(rgba_colors.len(), width * height);
+        }
+    }
+
+    #[test]
+    fn test_nv21_insufficient_data() {
+        assert!(nv21_to_rgb8(&[0u8; 4], 4, 4, 4, Limited, Bt601).is_none());
+        assert!(nv21_to_rgba8(&[0u8; 20], 4, 4, 4, Limited, Bt601).is_none());
+    }
+}
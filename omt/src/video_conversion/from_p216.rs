@@ -6,6 +6,8 @@
 //! Since the `yuv` crate doesn't have direct P216 decoding functions, we de-interleave the UV
 //! plane into separate U and V planes and use the `i216_to_rgb16`/`i216_to_rgba16` functions.
 
+use super::{uyvy_to_rgb8, uyvy_to_rgba8};
+use crate::types::Codec;
 use rgb::*;
 use yuv::{YuvPlanarImage, YuvRange, YuvStandardMatrix};
 
@@ -127,6 +129,219 @@ pub fn p216_to_rgb16(
     Some(rgb16_data)
 }
 
+/// Convert possibly truncated P216 data to RGB16, decoding as many rows as possible.
+///
+/// This is for error recovery only, e.g. to show a partially received frame
+/// rather than nothing. A row is decoded if both its Y row and its row of the
+/// UV plane are present. Since the UV plane follows the complete Y plane, no
+/// row can be decoded unless the whole Y plane is available. Rows that cannot
+/// be decoded are black.
+///
+/// # Returns
+///
+/// Returns `Some((rows_decoded, pixels))` with `width * height` pixels, or
+/// `None` if the dimensions are zero, the stride is too small for the width,
+/// or the conversion fails.
+pub fn p216_to_rgb16_partial(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<(usize, Vec<RGB16>)> {
+    let y_stride_u16 = stride / 2;
+    if width == 0 || height == 0 || y_stride_u16 < width {
+        return None;
+    }
+
+    let mut rgb_data = vec![RGB16::new(0, 0, 0); width.checked_mul(height)?];
+
+    let y_plane_size = y_stride_u16.checked_mul(height)?;
+    let uv_row_size = width.div_ceil(2) * 2;
+    let available = raw_data.len() / 2;
+    let rows = (available.saturating_sub(y_plane_size) / uv_row_size).min(height);
+    if rows == 0 {
+        return Some((0, rgb_data));
+    }
+
+    // Copy the complete rows into a contiguous buffer of `rows` height. Reading
+    // the samples individually also avoids relying on the input's alignment.
+    let samples = |range: std::ops::Range<usize>| {
+        raw_data[range.start * 2..range.end * 2]
+            .chunks_exact(2)
+            .map(|b| u16::from_ne_bytes([b[0], b[1]]))
+    };
+    let partial: Vec<u16> = samples(0..rows * y_stride_u16)
+        .chain(samples(y_plane_size..y_plane_size + rows * uv_row_size))
+        .collect();
+
+    let decoded = p216_to_rgb16(
+        bytemuck::cast_slice(&partial),
+        width,
+        rows,
+        stride,
+        yuv_range,
+        yuv_matrix,
+    )?;
+    rgb_data[..decoded.len()].copy_from_slice(&decoded);
+
+    Some((rows, rgb_data))
+}
+
+/// Downscale P216 data to packed 8-bit UYVY.
+///
+/// Each 16-bit sample keeps its most significant byte, as in `p216_to_nv12`.
+/// For odd widths the last Y sample of a row is repeated to fill the final pair.
+///
+/// # Returns
+///
+/// Returns `Some((uyvy, stride))` on success, or `None` if the dimensions are
+/// zero, the stride is too small for the width, or the data is too short.
+fn p216_to_uyvy8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<(Vec<u8>, usize)> {
+    let y_stride_u16 = stride / 2;
+    if width == 0 || height == 0 || y_stride_u16 < width {
+        return None;
+    }
+
+    let pairs = width.div_ceil(2);
+    let y_plane_size = y_stride_u16.checked_mul(height)?;
+    let uv_row_size = pairs * 2;
+    let total_u16_elements = y_plane_size.checked_add(uv_row_size.checked_mul(height)?)?;
+    if raw_data.len() < total_u16_elements.checked_mul(2)? {
+        return None;
+    }
+    let sample = |index: usize| {
+        (u16::from_ne_bytes([raw_data[index * 2], raw_data[index * 2 + 1]]) >> 8) as u8
+    };
+
+    let out_stride = pairs * 4;
+    let mut uyvy = vec![0u8; out_stride * height];
+    for (row, dst) in uyvy.chunks_exact_mut(out_stride).enumerate() {
+        let y_row = row * y_stride_u16;
+        let uv_row = y_plane_size + row * uv_row_size;
+        for (pair, dst) in dst.chunks_exact_mut(4).enumerate() {
+            let x = pair * 2;
+            dst[0] = sample(uv_row + pair * 2);
+            dst[1] = sample(y_row + x);
+            dst[2] = sample(uv_row + pair * 2 + 1);
+            dst[3] = sample(y_row + (x + 1).min(width - 1));
+        }
+    }
+
+    Some((uyvy, out_stride))
+}
+
+/// Convert P216 data to RGB8 format.
+///
+/// The 16-bit samples are reduced to 8 bits and converted like UYVY. Use
+/// [`p216_to_rgb16`] to keep the full precision.
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw P216 data as bytes (Y plane followed by interleaved UV plane)
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the Y plane
+/// * `yuv_range` - The YUV range (limited or full)
+/// * `yuv_matrix` - The YUV standard matrix (BT.601 or BT.709)
+///
+/// # Returns
+///
+/// Returns `Some(Vec<RGB8>)` on success, or `None` if the conversion fails.
+pub fn p216_to_rgb8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGB8>> {
+    let (uyvy, uyvy_stride) = p216_to_uyvy8(raw_data, width, height, stride)?;
+    uyvy_to_rgb8(&uyvy, width, height, uyvy_stride, yuv_range, yuv_matrix)
+}
+
+/// Convert P216 data to RGBA8 format.
+///
+/// The 16-bit samples are reduced to 8 bits and converted like UYVY. Alpha is
+/// set to 255 for all pixels.
+///
+/// # Returns
+///
+/// Returns `Some(Vec<RGBA8>)` on success, or `None` if the conversion fails.
+pub fn p216_to_rgba8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    let (uyvy, uyvy_stride) = p216_to_uyvy8(raw_data, width, height, stride)?;
+    uyvy_to_rgba8(&uyvy, width, height, uyvy_stride, yuv_range, yuv_matrix)
+}
+
+/// Convert PA16 data to RGB8 format.
+///
+/// Like [`p216_to_rgb8`], but requires the alpha plane to be present. The alpha
+/// plane is discarded.
+///
+/// # Returns
+///
+/// Returns `Some(Vec<RGB8>)` on success, or `None` if the conversion fails.
+pub fn pa16_to_rgb8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGB8>> {
+    pa16_alpha_offset(raw_data, width, height, stride)?;
+    p216_to_rgb8(raw_data, width, height, stride, yuv_range, yuv_matrix)
+}
+
+/// Convert PA16 data to RGBA8 format.
+///
+/// Like [`p216_to_rgba8`], with alpha taken from the most significant byte of
+/// the 16-bit alpha plane.
+///
+/// # Returns
+///
+/// Returns `Some(Vec<RGBA8>)` on success, or `None` if the conversion fails.
+pub fn pa16_to_rgba8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    let alpha_start = pa16_alpha_offset(raw_data, width, height, stride)?;
+    let mut rgba_data = p216_to_rgba8(raw_data, width, height, stride, yuv_range, yuv_matrix)?;
+
+    let alpha_plane = &raw_data[alpha_start..alpha_start + width * height * 2];
+    for (pixel, alpha) in rgba_data.iter_mut().zip(alpha_plane.chunks_exact(2)) {
+        pixel.a = (u16::from_ne_bytes([alpha[0], alpha[1]]) >> 8) as u8;
+    }
+
+    Some(rgba_data)
+}
+
+/// Returns the byte offset of the PA16 alpha plane if the data holds all planes.
+fn pa16_alpha_offset(raw_data: &[u8], width: usize, height: usize, stride: usize) -> Option<usize> {
+    let y_plane_size = (stride / 2).checked_mul(height)?;
+    let uv_plane_size = width.div_ceil(2).checked_mul(2)?.checked_mul(height)?;
+    let alpha_start = y_plane_size.checked_add(uv_plane_size)?.checked_mul(2)?;
+    let alpha_end = alpha_start.checked_add(width.checked_mul(height)?.checked_mul(2)?)?;
+    (raw_data.len() >= alpha_end).then_some(alpha_start)
+}
+
 /// Convert P216 data to RGBA16 format.
 ///
 /// P216 is a planar 4:2:2 YUV format:
@@ -335,6 +550,41 @@ pub fn pa16_to_rgba16(
     Some(rgba_data)
 }
 
+/// Extract the 16-bit alpha plane from PA16 data.
+///
+/// The alpha plane follows the Y plane (`stride * height` bytes) and the
+/// interleaved UV plane, and holds `width * height` little-endian values.
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw PA16 data as bytes (Y plane, interleaved UV plane, alpha plane)
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the Y plane
+///
+/// # Returns
+///
+/// Returns `Some(Vec<u16>)` with `width * height` alpha values, or `None` if the data is too small.
+pub fn pa16_to_alpha16(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<Vec<u16>> {
+    let total_len = super::expected_data_len(Codec::Pa16, width, height, stride)?;
+    if raw_data.len() < total_len {
+        return None;
+    }
+
+    let alpha_start = total_len - width * height * 2;
+    Some(
+        raw_data[alpha_start..total_len]
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::yuv_utils;
@@ -429,6 +679,97 @@ mod tests {
         bytemuck::cast_slice(&data).to_vec()
     }
 
+    #[test]
+    fn test_p216_to_rgb8_matches_rgb16() {
+        let width = 6;
+        let height = 4;
+        let stride = width * 2;
+        let data = create_gray_p216_data(width, height, Limited);
+
+        let rgb16 = p216_to_rgb16(&data, width, height, stride, Limited, Bt709).unwrap();
+        let rgb8 = p216_to_rgb8(&data, width, height, stride, Limited, Bt709).unwrap();
+        assert_eq!(rgb8.len(), width * height);
+        for (p8, p16) in rgb8.iter().zip(&rgb16) {
+            assert!(p8.r.abs_diff((p16.r >> 8) as u8) <= 1);
+            assert_eq!((p8.r, p8.g), (p8.g, p8.b));
+        }
+    }
+
+    #[test]
+    fn test_pa16_to_rgba8_requires_alpha_plane() {
+        let width = 4;
+        let height = 2;
+        let stride = width * 2;
+        let pa16 = create_gray_pa16_data(width, height, Limited, 0xFF00);
+        let rgba = pa16_to_rgba8(&pa16, width, height, stride, Limited, Bt601).unwrap();
+        assert!(rgba.iter().all(|p| p.a == 0xFF));
+
+        let p216 = create_gray_p216_data(width, height, Limited);
+        assert!(pa16_to_rgba8(&p216, width, height, stride, Limited, Bt601).is_none());
+        assert!(pa16_to_rgb8(&p216, width, height, stride, Limited, Bt601).is_none());
+        assert!(
+            p216_to_rgb8(
+                &p216[..p216.len() - 2],
+                width,
+                height,
+                stride,
+                Limited,
+                Bt601
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_p216_to_rgb16_partial() {
+        let width = 8;
+        let height = 8;
+        let stride = width * 2;
+        let full = create_gray_p216_data(width, height, Limited);
+        let expected = p216_to_rgb16(&full, width, height, stride, Limited, Bt601)
+            .expect("Full conversion should succeed");
+
+        // Complete Y plane, but UV data for only 4 of 8 rows
+        let truncated = &full[..width * height * 2 + 4 * width * 2];
+        let (rows, pixels) =
+            p216_to_rgb16_partial(truncated, width, height, stride, Limited, Bt601)
+                .expect("Partial conversion should succeed");
+
+        assert_eq!(rows, 4);
+        assert_eq!(pixels.len(), width * height);
+        assert_eq!(&pixels[..4 * width], &expected[..4 * width]);
+        assert!(
+            pixels[4 * width..]
+                .iter()
+                .all(|p| *p == RGB16::new(0, 0, 0))
+        );
+        assert_ne!(pixels[0], RGB16::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_p216_to_rgb16_partial_complete_and_missing() {
+        let width = 4;
+        let height = 2;
+        let stride = width * 2;
+        let full = create_gray_p216_data(width, height, Full);
+
+        let (rows, pixels) = p216_to_rgb16_partial(&full, width, height, stride, Full, Bt709)
+            .expect("Partial conversion should succeed");
+        assert_eq!(rows, height);
+        assert_eq!(
+            Some(pixels),
+            p216_to_rgb16(&full, width, height, stride, Full, Bt709)
+        );
+
+        // Without the complete Y plane nothing can be decoded
+        let (rows, pixels) = p216_to_rgb16_partial(&full[..10], width, height, stride, Full, Bt709)
+            .expect("Partial conversion should succeed");
+        assert_eq!(rows, 0);
+        assert!(pixels.iter().all(|p| *p == RGB16::new(0, 0, 0)));
+
+        assert!(p216_to_rgb16_partial(&full, width, height, 2, Full, Bt709).is_none());
+    }
+
     #[test]
     fn test_p216_to_rgb16_bt601_limited() {
         let width = 8;
@@ -582,6 +923,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pa16_to_alpha16() {
+        let width = 6;
+        let height = 4;
+        let stride = width * 2;
+        let pa16_data = create_gray_pa16_data(width, height, Limited, 0x1234);
+
+        let alpha = pa16_to_alpha16(&pa16_data, width, height, stride).unwrap();
+        assert_eq!(alpha, vec![0x1234; width * height]);
+
+        let p216_data = create_gray_p216_data(width, height, Limited);
+        assert!(pa16_to_alpha16(&p216_data, width, height, stride).is_none());
+    }
+
     #[test]
     fn test_p216_insufficient_data() {
         let width = 8;
@@ -0,0 +1,469 @@
+//! YUV to RGBA8 conversion functions with linearly interpolated chroma.
+//!
+//! The packed and semi-planar formats are split into separate Y, U and V planes,
+//! which are then converted using the `yuv` crate's bilinear planar functions.
+
+use rgb::bytemuck;
+use rgb::*;
+use yuv::{YuvPlanarImage, YuvRange, YuvStandardMatrix};
+
+/// Byte offsets of the Y0, U, Y1 and V samples within a packed 4:2:2 pixel pair.
+#[derive(Debug, Clone, Copy)]
+pub struct Packed422Layout {
+    pub(super) y0: usize,
+    pub(super) u: usize,
+    pub(super) y1: usize,
+    pub(super) v: usize,
+}
+
+/// UYVY (and UYVA) pixel pair layout: U0, Y0, V0, Y1.
+pub const UYVY_LAYOUT: Packed422Layout = Packed422Layout {
+    y0: 1,
+    u: 0,
+    y1: 3,
+    v: 2,
+};
+
+/// YUY2 pixel pair layout: Y0, U0, Y1, V0.
+pub const YUY2_LAYOUT: Packed422Layout = Packed422Layout {
+    y0: 0,
+    u: 1,
+    y1: 2,
+    v: 3,
+};
+
+/// Convert packed 4:2:2 data to RGBA8 with linear chroma interpolation.
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw packed data
+/// * `width` - The width of the image in pixels (must be even)
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the packed data
+/// * `layout` - Sample order within each pixel pair
+/// * `yuv_range` - The YUV range (limited or full)
+/// * `yuv_matrix` - The YUV standard matrix (BT.601 or BT.709)
+///
+/// # Returns
+///
+/// Returns `Some(Vec<RGBA8>)` on success, or `None` if the conversion fails or
+/// the stride or data are too short for the given dimensions.
+pub fn packed_422_to_rgba8_linear(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    layout: Packed422Layout,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    if width == 0 || !width.is_multiple_of(2) || height == 0 {
+        return None;
+    }
+    let row_len = width.checked_mul(2)?;
+    let min_len = stride.checked_mul(height - 1)?.checked_add(row_len)?;
+    if stride < row_len || raw_data.len() < min_len {
+        return None;
+    }
+
+    let chroma_width = width / 2;
+    let mut y_plane = Vec::with_capacity(width * height);
+    let mut u_plane = Vec::with_capacity(chroma_width * height);
+    let mut v_plane = Vec::with_capacity(chroma_width * height);
+
+    for row in raw_data.chunks(stride).take(height) {
+        for pair in row[..row_len].chunks_exact(4) {
+            y_plane.push(pair[layout.y0]);
+            y_plane.push(pair[layout.y1]);
+            u_plane.push(pair[layout.u]);
+            v_plane.push(pair[layout.v]);
+        }
+    }
+
+    let planar_image = YuvPlanarImage {
+        y_plane: &y_plane,
+        y_stride: width as u32,
+        u_plane: &u_plane,
+        u_stride: chroma_width as u32,
+        v_plane: &v_plane,
+        v_stride: chroma_width as u32,
+        width: width as u32,
+        height: height as u32,
+    };
+
+    let mut rgba_data = vec![RGBA8::new(0, 0, 0, 255); width * height];
+    let rgba_stride = (width * 4) as u32;
+
+    yuv::yuv422_to_rgba_bilinear(
+        &planar_image,
+        bytemuck::cast_slice_mut(&mut rgba_data),
+        rgba_stride,
+        yuv_range,
+        yuv_matrix,
+    )
+    .ok()?;
+
+    Some(rgba_data)
+}
+
+/// Convert UYVA data to RGBA8 with linear chroma interpolation.
+///
+/// The UYVY portion is converted with [`packed_422_to_rgba8_linear`] and the
+/// alpha plane that follows it is applied afterwards.
+pub fn uyva_to_rgba8_linear(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    let uyvy_size = height.checked_mul(stride)?;
+    let alpha_size = width.checked_mul(height)?;
+    if raw_data.len() < uyvy_size.checked_add(alpha_size)? {
+        return None;
+    }
+
+    let mut rgba_data = packed_422_to_rgba8_linear(
+        &raw_data[..uyvy_size],
+        width,
+        height,
+        stride,
+        UYVY_LAYOUT,
+        yuv_range,
+        yuv_matrix,
+    )?;
+
+    for (pixel, &alpha) in rgba_data
+        .iter_mut()
+        .zip(&raw_data[uyvy_size..uyvy_size + alpha_size])
+    {
+        pixel.a = alpha;
+    }
+
+    Some(rgba_data)
+}
+
+/// Convert NV12 data to RGBA8 with linear chroma interpolation.
+///
+/// The interleaved UV plane is split into separate U and V planes first.
+pub fn nv12_to_rgba8_linear(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    semi_planar_420_to_rgba8_linear(
+        raw_data, width, height, stride, false, yuv_range, yuv_matrix,
+    )
+}
+
+/// Convert NV21 data to RGBA8 with linear chroma interpolation.
+///
+/// Like [`nv12_to_rgba8_linear`], with V before U in each chroma pair.
+pub fn nv21_to_rgba8_linear(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    semi_planar_420_to_rgba8_linear(raw_data, width, height, stride, true, yuv_range, yuv_matrix)
+}
+
+/// Splits the interleaved chroma plane of NV12 (`v_first == false`) or NV21
+/// data and converts it with linear chroma interpolation.
+fn semi_planar_420_to_rgba8_linear(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    v_first: bool,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    let (u, v) = if v_first { (1, 0) } else { (0, 1) };
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let y_size = height.checked_mul(stride)?;
+    // Chroma rows of odd widths end with a full U/V pair, which the stride must hold
+    if stride < chroma_width * 2
+        || raw_data.len() < y_size.checked_add(stride.checked_mul(chroma_height)?)?
+    {
+        return None;
+    }
+
+    let mut u_plane = Vec::with_capacity(chroma_width * chroma_height);
+    let mut v_plane = Vec::with_capacity(chroma_width * chroma_height);
+    for row in raw_data[y_size..].chunks(stride).take(chroma_height) {
+        for pair in row[..chroma_width * 2].chunks_exact(2) {
+            u_plane.push(pair[u]);
+            v_plane.push(pair[v]);
+        }
+    }
+
+    let planar_image = YuvPlanarImage {
+        y_plane: &raw_data[..y_size],
+        y_stride: stride as u32,
+        u_plane: &u_plane,
+        u_stride: chroma_width as u32,
+        v_plane: &v_plane,
+        v_stride: chroma_width as u32,
+        width: width as u32,
+        height: height as u32,
+    };
+
+    planar_420_to_rgba8_linear(&planar_image, width, height, yuv_range, yuv_matrix)
+}
+
+/// Convert YV12 data to RGBA8 with linear chroma interpolation.
+///
+/// YV12 stores the V plane before the U plane.
+pub fn yv12_to_rgba8_linear(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    // Chroma planes cover odd dimensions with a trailing half-filled sample
+    let chroma_stride = stride / 2;
+    let y_size = height.checked_mul(stride)?;
+    let uv_size = height.div_ceil(2).checked_mul(chroma_stride)?;
+    if stride < width
+        || chroma_stride < width.div_ceil(2)
+        || raw_data.len() < y_size.checked_add(uv_size.checked_mul(2)?)?
+    {
+        return None;
+    }
+
+    let planar_image = YuvPlanarImage {
+        y_plane: &raw_data[0..y_size],
+        y_stride: stride as u32,
+        u_plane: &raw_data[y_size + uv_size..y_size + 2 * uv_size],
+        u_stride: chroma_stride as u32,
+        v_plane: &raw_data[y_size..y_size + uv_size],
+        v_stride: chroma_stride as u32,
+        width: width as u32,
+        height: height as u32,
+    };
+
+    planar_420_to_rgba8_linear(&planar_image, width, height, yuv_range, yuv_matrix)
+}
+
+fn planar_420_to_rgba8_linear(
+    planar_image: &YuvPlanarImage<'_, u8>,
+    width: usize,
+    height: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    let mut rgba_data = vec![RGBA8::new(0, 0, 0, 255); width * height];
+    let rgba_stride = (width * 4) as u32;
+
+    yuv::yuv420_to_rgba_bilinear(
+        planar_image,
+        bytemuck::cast_slice_mut(&mut rgba_data),
+        rgba_stride,
+        yuv_range,
+        yuv_matrix,
+    )
+    .ok()?;
+
+    Some(rgba_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::from_uyvy::uyvy_to_rgba8;
+    use super::super::test_utils::yuv_utils;
+    use super::*;
+    use yuv::YuvRange::*;
+    use yuv::YuvStandardMatrix::*;
+
+    /// Create UYVY data with a hard chroma edge: red-ish chroma on the left
+    /// half and blue-ish chroma on the right half, constant luma.
+    fn create_chroma_edge_uyvy_data(width: usize, height: usize) -> Vec<u8> {
+        let y = yuv_utils::middle_gray_y(Limited);
+        let mut data = Vec::with_capacity(width * height * 2);
+        for _ in 0..height {
+            for pair in 0..width / 2 {
+                let (u, v) = if pair < width / 4 {
+                    (90, 240)
+                } else {
+                    (240, 90)
+                };
+                data.extend_from_slice(&[u, y, v, y]);
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_linear_chroma_produces_intermediate_colors() {
+        let width = 16;
+        let height = 2;
+        let stride = width * 2;
+        let data = create_chroma_edge_uyvy_data(width, height);
+
+        let nearest = uyvy_to_rgba8(&data, width, height, stride, Limited, Bt709).unwrap();
+        let linear =
+            packed_422_to_rgba8_linear(&data, width, height, stride, UYVY_LAYOUT, Limited, Bt709)
+                .unwrap();
+
+        let nearest_reds: Vec<u8> = nearest.iter().map(|p| p.r).collect();
+        let left_red = nearest_reds[0];
+        let right_red = nearest_reds[width - 1];
+        assert!(left_red > right_red);
+
+        // Nearest only produces the two extreme colors
+        assert!(
+            nearest_reds
+                .iter()
+                .all(|&r| r == left_red || r == right_red)
+        );
+
+        // Linear produces at least one value strictly in between at the edge
+        assert!(
+            linear.iter().any(|p| p.r > right_red && p.r < left_red),
+            "Linear chroma should produce intermediate colors: {:?}",
+            linear.iter().map(|p| p.r).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_linear_chroma_gray_stays_gray() {
+        let width = 8;
+        let height = 4;
+        let y = yuv_utils::middle_gray_y(Full);
+        let (u, v) = yuv_utils::neutral_uv();
+
+        let yuy2 = [y, u, y, v].repeat(width * height / 2);
+        let rgba =
+            packed_422_to_rgba8_linear(&yuy2, width, height, width * 2, YUY2_LAYOUT, Full, Bt601)
+                .unwrap();
+        assert!(rgba.iter().all(|p| p.r == p.g && p.g == p.b && p.a == 255));
+
+        let mut nv12 = vec![y; width * height];
+        nv12.extend([u, v].repeat(width * height / 4));
+        let rgba = nv12_to_rgba8_linear(&nv12, width, height, width, Full, Bt601).unwrap();
+        assert!(rgba.iter().all(|p| p.r == p.g && p.g == p.b));
+
+        let mut yv12 = vec![y; width * height];
+        yv12.extend(vec![v; width * height / 4]);
+        yv12.extend(vec![u; width * height / 4]);
+        let rgba = yv12_to_rgba8_linear(&yv12, width, height, width, Full, Bt601).unwrap();
+        assert!(rgba.iter().all(|p| p.r == p.g && p.g == p.b));
+    }
+
+    #[test]
+    fn test_uyva_linear_applies_alpha() {
+        let width = 4;
+        let height = 2;
+        let y = yuv_utils::middle_gray_y(Limited);
+        let (u, v) = yuv_utils::neutral_uv();
+
+        let mut data = [u, y, v, y].repeat(width * height / 2);
+        data.extend(vec![77u8; width * height]);
+
+        let rgba = uyva_to_rgba8_linear(&data, width, height, width * 2, Limited, Bt601).unwrap();
+        assert!(rgba.iter().all(|p| p.a == 77));
+    }
+
+    #[test]
+    fn test_nv12_linear_odd_width() {
+        let (width, height) = (5, 2);
+        let y = yuv_utils::middle_gray_y(Full);
+        let (u, v) = yuv_utils::neutral_uv();
+
+        // Rows padded to hold the last U/V pair
+        let stride = 6;
+        let mut nv12 = vec![y; stride * height];
+        nv12.extend([u, v].repeat(stride / 2));
+        let rgba = nv12_to_rgba8_linear(&nv12, width, height, stride, Full, Bt601).unwrap();
+        assert_eq!(rgba.len(), width * height);
+        assert!(rgba.iter().all(|p| p.r == p.g && p.g == p.b));
+
+        // A stride of width cuts the last pair short
+        let mut nv12 = vec![y; width * height];
+        nv12.extend(vec![u; width]);
+        assert!(nv12_to_rgba8_linear(&nv12, width, height, width, Full, Bt601).is_none());
+    }
+
+    #[test]
+    fn test_linear_chroma_data_too_small() {
+        assert!(
+            packed_422_to_rgba8_linear(&[0u8; 7], 2, 2, 4, UYVY_LAYOUT, Limited, Bt601).is_none()
+        );
+        assert!(nv12_to_rgba8_linear(&[0u8; 5], 2, 2, 2, Limited, Bt601).is_none());
+        assert!(yv12_to_rgba8_linear(&[0u8; 5], 2, 2, 2, Limited, Bt601).is_none());
+    }
+
+    #[test]
+    fn test_linear_chroma_short_or_zero_stride() {
+        // Rows of 4 pixels need 8 bytes; the data is long enough for stride 4
+        let data = [0x80u8; 12];
+        for stride in [4, 0] {
+            assert!(
+                packed_422_to_rgba8_linear(&data, 4, 2, stride, UYVY_LAYOUT, Limited, Bt601)
+                    .is_none()
+            );
+            assert!(
+                packed_422_to_rgba8_linear(&data, 4, 2, stride, YUY2_LAYOUT, Limited, Bt601)
+                    .is_none()
+            );
+            assert!(uyva_to_rgba8_linear(&data, 4, 2, stride, Limited, Bt601).is_none());
+        }
+        // Planar rows of 4 pixels need 4 bytes
+        for stride in [2, 0] {
+            assert!(nv12_to_rgba8_linear(&data, 4, 2, stride, Limited, Bt601).is_none());
+            assert!(nv21_to_rgba8_linear(&data, 4, 2, stride, Limited, Bt601).is_none());
+            assert!(yv12_to_rgba8_linear(&data, 4, 2, stride, Limited, Bt601).is_none());
+        }
+
+        // A stride that overflows the data size is rejected, not wrapped
+        assert!(
+            packed_422_to_rgba8_linear(&data, 4, 2, usize::MAX, UYVY_LAYOUT, Limited, Bt601)
+                .is_none()
+        );
+        assert!(yv12_to_rgba8_linear(&data, 4, 2, usize::MAX, Limited, Bt601).is_none());
+    }
+
+    #[test]
+    fn test_yv12_linear_odd_dimensions() {
+        let (width, height) = (5, 3);
+        let y = yuv_utils::middle_gray_y(Full);
+        let (u, v) = yuv_utils::neutral_uv();
+
+        // Chroma planes of 3x2 samples with a stride of 6 / 2
+        let stride = 6;
+        let mut yv12 = vec![y; stride * height];
+        yv12.extend(vec![v; 3 * 2]);
+        yv12.extend(vec![u; 3 * 2]);
+        assert_eq!(
+            crate::video_conversion::expected_data_len(crate::Codec::Yv12, width, height, stride),
+            Some(yv12.len())
+        );
+        let rgba = yv12_to_rgba8_linear(&yv12, width, height, stride, Full, Bt601).unwrap();
+        assert_eq!(rgba.len(), width * height);
+        assert!(rgba.iter().all(|p| p.r == p.g && p.g == p.b));
+
+        // The last chroma row is required
+        assert!(
+            yv12_to_rgba8_linear(&yv12[..yv12.len() - 1], width, height, stride, Full, Bt601)
+                .is_none()
+        );
+        // A stride of width leaves chroma rows one sample short
+        assert!(yv12_to_rgba8_linear(&yv12, width, height, width, Full, Bt601).is_none());
+    }
+}
@@ -0,0 +1,246 @@
+//! Luma (Y plane) extraction functions.
+//!
+//! These functions copy the Y component of YUV frames directly, without any
+//! matrix multiplication or range conversion. The returned values are therefore
+//! in the same range as the source (e.g. 16-235 for limited range 8-bit video).
+
+use yuv::YuvStandardMatrix;
+
+/// Checks that `raw_data` holds `height` rows of `row_bytes` bytes spaced `stride` apart.
+fn has_rows(raw_data: &[u8], height: usize, stride: usize, row_bytes: usize) -> bool {
+    if height == 0 || stride < row_bytes {
+        return false;
+    }
+    raw_data.len() >= stride * (height - 1) + row_bytes
+}
+
+/// Extract the 8-bit Y component from packed 4:2:2 data (UYVY, YUY2, UYVA).
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw packed data
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the packed data
+/// * `y_offset` - Byte offset of the first Y sample in each pixel pair
+///   (1 for UYVY/UYVA, 0 for YUY2)
+///
+/// # Returns
+///
+/// Returns `Some(Vec<u8>)` with `width * height` luma values, or `None` if the data is too small.
+pub fn packed_422_to_luma8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    y_offset: usize,
+) -> Option<Vec<u8>> {
+    if !has_rows(raw_data, height, stride, width * 2) {
+        return None;
+    }
+
+    let mut luma = Vec::with_capacity(width * height);
+    for row in raw_data.chunks(stride).take(height) {
+        luma.extend(row[..width * 2].iter().skip(y_offset).step_by(2));
+    }
+
+    Some(luma)
+}
+
+/// Extract the 8-bit Y plane from planar data (NV12, YV12).
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw planar data (Y plane first)
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the Y plane
+///
+/// # Returns
+///
+/// Returns `Some(Vec<u8>)` with `width * height` luma values, or `None` if the data is too small.
+pub fn planar_to_luma8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<Vec<u8>> {
+    if !has_rows(raw_data, height, stride, width) {
+        return None;
+    }
+
+    let mut luma = Vec::with_capacity(width * height);
+    for row in raw_data.chunks(stride).take(height) {
+        luma.extend_from_slice(&row[..width]);
+    }
+
+    Some(luma)
+}
+
+/// Extract the 16-bit Y plane from P216/PA16 data.
+///
+/// The Y plane is stored as little-endian 16-bit samples.
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw P216/PA16 data (Y plane first)
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the Y plane
+///
+/// # Returns
+///
+/// Returns `Some(Vec<u16>)` with `width * height` luma values, or `None` if the data is too small.
+pub fn p216_to_luma16(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<Vec<u16>> {
+    if !has_rows(raw_data, height, stride, width * 2) {
+        return None;
+    }
+
+    let mut luma = Vec::with_capacity(width * height);
+    for row in raw_data.chunks(stride).take(height) {
+        luma.extend(
+            row[..width * 2]
+                .chunks_exact(2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]])),
+        );
+    }
+
+    Some(luma)
+}
+
+/// Compute full range 8-bit luma from BGRA data.
+///
+/// Uses the luma weights of the given matrix (Rec.601 or Rec.709).
+///
+/// # Arguments
+///
+/// * `raw_data` - The raw BGRA data
+/// * `width` - The width of the image in pixels
+/// * `height` - The height of the image in pixels
+/// * `stride` - The stride (bytes per row) of the BGRA data
+/// * `yuv_matrix` - The matrix whose luma weights are used
+///
+/// # Returns
+///
+/// Returns `Some(Vec<u8>)` with `width * height` luma values, or `None` if the data is too small.
+pub fn bgra_to_luma8(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<u8>> {
+    if !has_rows(raw_data, height, stride, width * 4) {
+        return None;
+    }
+
+    let (kr, kb) = match yuv_matrix {
+        YuvStandardMatrix::Bt601 => (0.299, 0.114),
+        YuvStandardMatrix::Bt2020 => (0.2627, 0.0593),
+        _ => (0.2126, 0.0722),
+    };
+    let kg = 1.0 - kr - kb;
+
+    let mut luma = Vec::with_capacity(width * height);
+    for row in raw_data.chunks(stride).take(height) {
+        luma.extend(row[..width * 4].chunks_exact(4).map(|px| {
+            let y = kr * px[2] as f32 + kg * px[1] as f32 + kb * px[0] as f32;
+            y.round().clamp(0.0, 255.0) as u8
+        }));
+    }
+
+    Some(luma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::yuv_utils;
+    use super::*;
+    use yuv::YuvRange::*;
+    use yuv::YuvStandardMatrix::*;
+
+    #[test]
+    fn test_packed_422_to_luma8_uyvy_and_yuy2() {
+        let width = 4;
+        let height = 2;
+        let stride = width * 2;
+        let y = yuv_utils::middle_gray_y(Limited);
+        let (u, v) = yuv_utils::neutral_uv();
+
+        let uyvy: Vec<u8> = [u, y, v, y].repeat(width * height / 2);
+        let yuy2: Vec<u8> = [y, u, y, v].repeat(width * height / 2);
+
+        assert_eq!(
+            packed_422_to_luma8(&uyvy, width, height, stride, 1),
+            Some(vec![y; width * height])
+        );
+        assert_eq!(
+            packed_422_to_luma8(&yuy2, width, height, stride, 0),
+            Some(vec![y; width * height])
+        );
+    }
+
+    #[test]
+    fn test_packed_422_to_luma8_respects_stride() {
+        let width = 2;
+        let height = 2;
+        let stride = 8; // 4 bytes of padding per row
+        let data = vec![128, 10, 128, 11, 0xFF, 0xFF, 0xFF, 0xFF, 128, 20, 128, 21];
+
+        assert_eq!(
+            packed_422_to_luma8(&data, width, height, stride, 1),
+            Some(vec![10, 11, 20, 21])
+        );
+    }
+
+    #[test]
+    fn test_planar_to_luma8() {
+        let width = 4;
+        let height = 2;
+        let mut data = vec![0u8; width * height * 3 / 2];
+        data[..width * height].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert_eq!(
+            planar_to_luma8(&data, width, height, width),
+            Some(vec![1, 2, 3, 4, 5, 6, 7, 8])
+        );
+    }
+
+    #[test]
+    fn test_p216_to_luma16_mid_gray() {
+        let width = 4;
+        let height = 2;
+        let y_value = (yuv_utils::middle_gray_y(Full) as u16) << 8;
+        let uv_neutral = 128u16 << 8;
+
+        let mut samples = vec![y_value; width * height];
+        samples.extend(std::iter::repeat_n(uv_neutral, width * height));
+        let data: Vec<u8> = samples.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+        assert_eq!(
+            p216_to_luma16(&data, width, height, width * 2),
+            Some(vec![0x8000; width * height])
+        );
+    }
+
+    #[test]
+    fn test_bgra_to_luma8_gray_and_white() {
+        let data = [128, 128, 128, 255, 255, 255, 255, 255];
+
+        assert_eq!(bgra_to_luma8(&data, 2, 1, 8, Bt601), Some(vec![128, 255]));
+        assert_eq!(bgra_to_luma8(&data, 2, 1, 8, Bt709), Some(vec![128, 255]));
+    }
+
+    #[test]
+    fn test_luma_data_too_small() {
+        assert_eq!(packed_422_to_luma8(&[0u8; 7], 2, 2, 4, 1), None);
+        assert_eq!(planar_to_luma8(&[0u8; 3], 2, 2, 2), None);
+        assert_eq!(p216_to_luma16(&[0u8; 7], 2, 2, 4), None);
+        assert_eq!(bgra_to_luma8(&[0u8; 15], 2, 2, 8, Bt601), None);
+    }
+}
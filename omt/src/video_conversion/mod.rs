@@ -15,55 +15,153 @@
 //! - [`MediaFrame::to_rgb16()`](crate::MediaFrame::to_rgb16)
 //! - [`MediaFrame::to_rgba16()`](crate::MediaFrame::to_rgba16)
 use crate::MediaFrame;
-use crate::types::{ColorSpace, VideoFlags};
+use crate::types::{Codec, ColorMatrix, ColorRange, ColorSpace};
 use yuv::{YuvRange, YuvStandardMatrix};
 
+pub(crate) use alpha::*;
 pub(crate) use from_bgra::*;
 pub(crate) use from_nv12::*;
+pub(crate) use from_nv21::*;
 pub(crate) use from_p216::*;
 pub(crate) use from_uyva::*;
 pub(crate) use from_uyvy::*;
 pub(crate) use from_yuy2::*;
 pub(crate) use from_yv12::*;
+pub(crate) use linear_chroma::*;
+pub(crate) use luma::*;
+#[cfg(feature = "rayon")]
+pub(crate) use parallel::*;
+pub(crate) use resize::*;
+pub(crate) use to_bgra::*;
+pub(crate) use to_nv12::*;
+pub(crate) use to_uyva::*;
+pub(crate) use to_uyvy::*;
 
+mod alpha;
 mod from_bgra;
 mod from_nv12;
+mod from_nv21;
 mod from_p216;
 mod from_uyva;
 mod from_uyvy;
 mod from_yuy2;
 mod from_yv12;
+mod linear_chroma;
+mod luma;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod resize;
+mod to_bgra;
+mod to_nv12;
+mod to_uyva;
+mod to_uyvy;
 
 #[cfg(test)]
 mod test_utils;
 
 /// Determines the appropriate YUV standard matrix for a video frame.
 ///
-/// Returns the YUV standard matrix based on the frame's color space:
+/// Delegates to [`ColorSpace::to_matrix()`], treating a missing color space as
+/// undefined:
 /// - `Bt709` for BT.709 color space or frames with width >= 1280 (HD and above)
 /// - `Bt601` for BT.601 color space or frames with width < 1280 (SD)
 pub(crate) fn get_yuv_matrix(frame: &MediaFrame<'_>) -> YuvStandardMatrix {
-    match frame.color_space() {
-        Some(ColorSpace::Bt709) => YuvStandardMatrix::Bt709,
-        Some(ColorSpace::Bt601) => YuvStandardMatrix::Bt601,
-        Some(ColorSpace::Undefined) | None => {
-            if frame.width() >= 1280 {
-                YuvStandardMatrix::Bt709
-            } else {
-                YuvStandardMatrix::Bt601
-            }
-        }
-    }
+    let color_space = frame.color_space().unwrap_or(ColorSpace::Undefined);
+    yuv_matrix(color_space.to_matrix(frame.width()))
 }
 
 /// Determines the appropriate YUV range for a video frame.
 ///
-/// Returns `Full` range if the frame has high bit depth flag set,
-/// otherwise returns `Limited` range.
+/// Delegates to [`VideoFlags::to_range()`]: `Full` range if the frame has the
+/// high bit depth flag set, otherwise `Limited` range.
 pub(crate) fn get_yuv_range(frame: &MediaFrame<'_>) -> YuvRange {
-    if frame.flags().contains(VideoFlags::HIGH_BIT_DEPTH) {
-        YuvRange::Full
-    } else {
-        YuvRange::Limited
+    yuv_range(frame.flags().to_range())
+}
+
+/// Converts a [`ColorMatrix`] to the `yuv` crate equivalent.
+pub(crate) fn yuv_matrix(matrix: ColorMatrix) -> YuvStandardMatrix {
+    match matrix {
+        ColorMatrix::Bt601 => YuvStandardMatrix::Bt601,
+        ColorMatrix::Bt709 => YuvStandardMatrix::Bt709,
+        ColorMatrix::Bt2020 => YuvStandardMatrix::Bt2020,
+    }
+}
+
+/// Converts a [`ColorRange`] to the `yuv` crate equivalent.
+pub(crate) fn yuv_range(range: ColorRange) -> YuvRange {
+    match range {
+        ColorRange::Limited => YuvRange::Limited,
+        ColorRange::Full => YuvRange::Full,
+    }
+}
+
+/// Returns the minimum number of data bytes for an uncompressed video frame.
+///
+/// The layouts match what the conversion functions in this module read:
+/// - UYVY, YUY2, BGRA: `stride * height`
+/// - UYVA: UYVY portion followed by a `width * height` alpha plane
+/// - NV12, NV21: Y plane followed by an interleaved UV (VU) plane of half height
+/// - YV12: Y plane followed by V and U planes of half width and half height
+/// - P216: 16-bit Y plane followed by an interleaved 16-bit UV plane
+/// - PA16: P216 followed by a 16-bit `width * height` alpha plane
+///
+/// Returns `None` for compressed codecs, zero dimensions, or a stride that is
+/// too small to hold a row of `width` pixels.
+pub(crate) fn expected_data_len(
+    codec: Codec,
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<usize> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let min_stride = match codec {
+        Codec::Uyvy | Codec::Yuy2 | Codec::Uyva => width.div_ceil(2).checked_mul(4)?,
+        Codec::Bgra => width.checked_mul(4)?,
+        Codec::Nv12 | Codec::Nv21 | Codec::Yv12 => width,
+        Codec::P216 | Codec::Pa16 => width.checked_mul(2)?,
+        Codec::Vmx1 | Codec::Fpa1 => return None,
+    };
+    if stride < min_stride {
+        return None;
+    }
+
+    let plane = stride.checked_mul(height)?;
+    let chroma_height = height.div_ceil(2);
+    match codec {
+        Codec::Uyvy | Codec::Yuy2 | Codec::Bgra => Some(plane),
+        Codec::Uyva => plane.checked_add(width.checked_mul(height)?),
+        Codec::Nv12 | Codec::Nv21 => plane.checked_add(stride.checked_mul(chroma_height)?),
+        Codec::Yv12 => plane.checked_add((stride / 2).checked_mul(chroma_height)?.checked_mul(2)?),
+        Codec::P216 => plane.checked_add(width.div_ceil(2).checked_mul(4)?.checked_mul(height)?),
+        Codec::Pa16 => plane
+            .checked_add(width.div_ceil(2).checked_mul(4)?.checked_mul(height)?)?
+            .checked_add(width.checked_mul(height)?.checked_mul(2)?),
+        Codec::Vmx1 | Codec::Fpa1 => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_data_len() {
+        assert_eq!(expected_data_len(Codec::Uyvy, 4, 2, 8), Some(16));
+        assert_eq!(expected_data_len(Codec::Bgra, 4, 2, 16), Some(32));
+        assert_eq!(expected_data_len(Codec::Uyva, 4, 2, 8), Some(24));
+        assert_eq!(expected_data_len(Codec::Nv12, 4, 2, 4), Some(12));
+        assert_eq!(expected_data_len(Codec::Yv12, 4, 2, 4), Some(12));
+        assert_eq!(expected_data_len(Codec::P216, 4, 2, 8), Some(32));
+        assert_eq!(expected_data_len(Codec::Pa16, 4, 2, 8), Some(48));
+    }
+
+    #[test]
+    fn test_expected_data_len_invalid() {
+        assert_eq!(expected_data_len(Codec::Uyvy, 4, 2, 6), None);
+        assert_eq!(expected_data_len(Codec::Bgra, 0, 2, 16), None);
+        assert_eq!(expected_data_len(Codec::Vmx1, 4, 2, 8), None);
     }
 }
@@ -0,0 +1,133 @@
+//! Row-band parallel RGBA8 conversion, enabled by the `rayon` feature.
+//!
+//! Only codecs whose rows convert independently are supported, so each band
+//! produces exactly the bytes the serial converter writes for those rows.
+
+use crate::types::Codec;
+use rayon::prelude::*;
+use rgb::bytemuck;
+use rgb::*;
+use yuv::{YuvPackedImage, YuvRange, YuvStandardMatrix};
+
+/// Rows converted per task; small enough to balance, large enough for SIMD.
+const BAND_ROWS: usize = 64;
+
+/// Converts a frame to RGBA8 in parallel row bands.
+///
+/// Returns `None` for codecs without independent rows (4:2:0 and 16-bit
+/// formats) and whenever a band fails, e.g. because the data is too short.
+/// Callers fall back to the serial converter in that case.
+pub fn to_rgba8_parallel(
+    codec: Codec,
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<Vec<RGBA8>> {
+    if !matches!(codec, Codec::Uyvy | Codec::Yuy2 | Codec::Uyva | Codec::Bgra) || width == 0 {
+        return None;
+    }
+
+    let source = Source {
+        codec,
+        raw_data,
+        width,
+        height,
+        stride,
+        yuv_range,
+        yuv_matrix,
+    };
+
+    // Same initialization as the serial converters
+    let mut rgba_data = vec![RGBA8::new(0, 0, 0, 255); width.checked_mul(height)?];
+    rgba_data
+        .par_chunks_mut(BAND_ROWS * width)
+        .enumerate()
+        .try_for_each(|(band, output)| source.convert_band(band * BAND_ROWS, output))?;
+
+    Some(rgba_data)
+}
+
+/// The frame being converted.
+struct Source<'a> {
+    codec: Codec,
+    raw_data: &'a [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+}
+
+impl Source<'_> {
+    /// Converts the rows starting at `start` into `output`, which holds
+    /// whole rows.
+    fn convert_band(&self, start: usize, output: &mut [RGBA8]) -> Option<()> {
+        let Self {
+            codec,
+            raw_data,
+            width,
+            height,
+            stride,
+            yuv_range,
+            yuv_matrix,
+        } = *self;
+        let rows = output.len() / width;
+        let rgba_stride = (width * 4) as u32;
+
+        match codec {
+            Codec::Bgra => {
+                // The serial converter assumes tightly packed rows
+                let bgra = raw_data.get(start * width * 4..)?;
+                yuv::bgra_to_rgba(
+                    bgra,
+                    rgba_stride,
+                    bytemuck::cast_slice_mut(output),
+                    rgba_stride,
+                    width as u32,
+                    rows as u32,
+                )
+                .ok()
+            }
+            Codec::Uyvy | Codec::Yuy2 | Codec::Uyva => {
+                let packed_end = if codec == Codec::Uyva {
+                    height * stride
+                } else {
+                    raw_data.len()
+                };
+                let packed_image = YuvPackedImage {
+                    yuy: raw_data.get(start * stride..packed_end)?,
+                    yuy_stride: stride as u32,
+                    width: width as u32,
+                    height: rows as u32,
+                };
+                let convert = if codec == Codec::Yuy2 {
+                    yuv::yuyv422_to_rgba
+                } else {
+                    yuv::uyvy422_to_rgba
+                };
+                convert(
+                    &packed_image,
+                    bytemuck::cast_slice_mut(output),
+                    rgba_stride,
+                    yuv_range,
+                    yuv_matrix,
+                )
+                .ok()?;
+
+                if codec == Codec::Uyva {
+                    // The alpha plane follows the UYVY plane, one byte per pixel
+                    let alpha_start = height * stride + start * width;
+                    let alpha = raw_data.get(alpha_start..alpha_start + rows * width)?;
+                    for (pixel, &a) in output.iter_mut().zip(alpha) {
+                        pixel.a = a;
+                    }
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+}
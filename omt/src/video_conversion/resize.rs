@@ -0,0 +1,81 @@
+//! Nearest neighbor downscaling of converted pixel buffers.
+
+/// Returns the largest size with the aspect ratio of `width` x `height` that fits
+/// within `max_width` x `max_height`.
+///
+/// Sizes already within the bounds are returned unchanged; both dimensions of
+/// the result are at least 1. Returns `None` if any argument is zero.
+pub fn fit_within(
+    width: usize,
+    height: usize,
+    max_width: usize,
+    max_height: usize,
+) -> Option<(usize, usize)> {
+    if width == 0 || height == 0 || max_width == 0 || max_height == 0 {
+        return None;
+    }
+    if width <= max_width && height <= max_height {
+        return Some((width, height));
+    }
+
+    // Compare max_width / width against max_height / height without division
+    let (w, h) = (width as u128, height as u128);
+    let (max_w, max_h) = (max_width as u128, max_height as u128);
+    let (out_w, out_h) = if max_w * h <= max_h * w {
+        (max_w, (h * max_w / w).max(1))
+    } else {
+        ((w * max_h / h).max(1), max_h)
+    };
+    Some((out_w as usize, out_h as usize))
+}
+
+/// Resizes a tightly packed `width` x `height` image to `dst_width` x `dst_height`
+/// by picking the nearest source pixel.
+///
+/// Returns `None` if `pixels` is smaller than `width * height`.
+pub fn resize_nearest<T: Copy>(
+    pixels: &[T],
+    width: usize,
+    height: usize,
+    dst_width: usize,
+    dst_height: usize,
+) -> Option<Vec<T>> {
+    if pixels.len() < width.checked_mul(height)? || width == 0 || height == 0 {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(dst_width * dst_height);
+    for y in 0..dst_height {
+        // Sample at the center of each destination pixel
+        let src_y = (2 * y + 1) * height / (2 * dst_height);
+        let row = &pixels[src_y * width..(src_y + 1) * width];
+        result.extend((0..dst_width).map(|x| row[(2 * x + 1) * width / (2 * dst_width)]));
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_within() {
+        assert_eq!(fit_within(640, 480, 1920, 1080), Some((640, 480)));
+        assert_eq!(fit_within(7680, 4320, 1920, 1080), Some((1920, 1080)));
+        assert_eq!(fit_within(1920, 1080, 640, 640), Some((640, 360)));
+        assert_eq!(fit_within(1080, 1920, 640, 640), Some((360, 640)));
+        assert_eq!(fit_within(10000, 1, 100, 100), Some((100, 1)));
+        assert_eq!(fit_within(640, 480, 0, 100), None);
+    }
+
+    #[test]
+    fn test_resize_nearest_halves() {
+        let pixels: Vec<u8> = (0..16).collect();
+        assert_eq!(
+            resize_nearest(&pixels, 4, 4, 2, 2),
+            Some(vec![5, 7, 13, 15])
+        );
+        assert_eq!(resize_nearest(&pixels, 4, 4, 4, 4), Some(pixels.clone()));
+        assert_eq!(resize_nearest(&pixels[..15], 4, 4, 2, 2), None);
+    }
+}
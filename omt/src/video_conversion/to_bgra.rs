@@ -0,0 +1,52 @@
+//! Conversion of RGBA8 images to BGRA.
+
+/// Convert tightly packed RGBA8 data to BGRA by swapping the red and blue channels.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the dimensions are
+/// zero, `rgba` is shorter than `width * height * 4` bytes, or the conversion fails.
+pub fn rgba8_to_bgra(rgba: &[u8], width: usize, height: usize) -> Option<(Vec<u8>, usize)> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let stride = width.checked_mul(4)?;
+    let rgba = rgba.get(..stride.checked_mul(height)?)?;
+
+    let mut data = vec![0u8; rgba.len()];
+    yuv::rgba_to_bgra(
+        rgba,
+        stride as u32,
+        &mut data,
+        stride as u32,
+        width as u32,
+        height as u32,
+    )
+    .ok()?;
+    Some((data, stride))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bgra_to_rgba8;
+    use super::*;
+
+    #[test]
+    fn test_rgba8_to_bgra_round_trip() {
+        let rgba: Vec<u8> = (0..3 * 2 * 4).map(|i| (i * 10) as u8).collect();
+
+        let (data, stride) = rgba8_to_bgra(&rgba, 3, 2).expect("Conversion should succeed");
+        assert_eq!(stride, 12);
+        assert_eq!(&data[..4], &[20, 10, 0, 30]);
+
+        let decoded = bgra_to_rgba8(&data, 3, 2, stride).expect("Decoding should succeed");
+        let decoded: Vec<u8> = decoded.iter().flat_map(|p| [p.r, p.g, p.b, p.a]).collect();
+        assert_eq!(decoded, rgba);
+    }
+
+    #[test]
+    fn test_rgba8_to_bgra_invalid_input() {
+        assert!(rgba8_to_bgra(&[0; 16], 0, 4).is_none());
+        assert!(rgba8_to_bgra(&[0; 15], 2, 2).is_none());
+    }
+}
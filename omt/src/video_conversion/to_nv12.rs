@@ -0,0 +1,351 @@
+//! Conversion of uncompressed video frames to tightly packed NV12.
+//!
+//! The output uses the same stride for the Y plane and the interleaved UV plane:
+//! the width rounded up to an even number of bytes. YUV sources are repacked and
+//! their chroma subsampled to 4:2:0 by averaging vertically adjacent rows; BGRA
+//! is converted using the `yuv` crate.
+
+use super::linear_chroma::Packed422Layout;
+use yuv::{BufferStoreMut, YuvBiPlanarImageMut, YuvConversionMode, YuvRange, YuvStandardMatrix};
+
+/// NV12 buffer with planes of `stride * height` (Y) and `stride * height.div_ceil(2)` (UV).
+struct Nv12Buffer {
+    data: Vec<u8>,
+    stride: usize,
+    y_size: usize,
+}
+
+impl Nv12Buffer {
+    fn new(width: usize, height: usize) -> Self {
+        let stride = width.next_multiple_of(2);
+        let y_size = stride * height;
+        Self {
+            data: vec![0u8; y_size + stride * height.div_ceil(2)],
+            stride,
+            y_size,
+        }
+    }
+
+    fn y_row(&mut self, row: usize) -> &mut [u8] {
+        let start = row * self.stride;
+        &mut self.data[start..start + self.stride]
+    }
+
+    fn uv_row(&mut self, row: usize) -> &mut [u8] {
+        let start = self.y_size + row * self.stride;
+        &mut self.data[start..start + self.stride]
+    }
+}
+
+/// Returns the two source rows averaged for chroma row `row` of a 4:2:0 image.
+fn chroma_source_rows(row: usize, height: usize) -> (usize, usize) {
+    (row * 2, (row * 2 + 1).min(height - 1))
+}
+
+/// Average of two 8-bit samples, rounding half up.
+fn average(a: u8, b: u8) -> u8 {
+    (a as u16 + b as u16).div_ceil(2) as u8
+}
+
+/// Convert BGRA data to NV12.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the conversion fails.
+pub fn bgra_to_nv12(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<(Vec<u8>, usize)> {
+    if width == 0 || height == 0 || stride < width * 4 {
+        return None;
+    }
+    if raw_data.len() < stride * (height - 1) + width * 4 {
+        return None;
+    }
+
+    let mut buffer = Nv12Buffer::new(width, height);
+    let nv12_stride = buffer.stride;
+    let (y_plane, uv_plane) = buffer.data.split_at_mut(buffer.y_size);
+
+    let mut image = YuvBiPlanarImageMut {
+        y_plane: BufferStoreMut::Borrowed(y_plane),
+        y_stride: nv12_stride as u32,
+        uv_plane: BufferStoreMut::Borrowed(uv_plane),
+        uv_stride: nv12_stride as u32,
+        width: width as u32,
+        height: height as u32,
+    };
+
+    yuv::bgra_to_yuv_nv12(
+        &mut image,
+        raw_data,
+        stride as u32,
+        yuv_range,
+        yuv_matrix,
+        YuvConversionMode::Balanced,
+    )
+    .ok()?;
+
+    Some((buffer.data, nv12_stride))
+}
+
+/// Repack packed 4:2:2 data (UYVY, YUY2, UYVA) to NV12.
+///
+/// For UYVA only the UYVY portion is used; the alpha plane is discarded.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the data is too small.
+pub fn packed_422_to_nv12(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    layout: Packed422Layout,
+) -> Option<(Vec<u8>, usize)> {
+    let pairs = width.div_ceil(2);
+    if width == 0 || height == 0 || stride < pairs * 4 {
+        return None;
+    }
+    if raw_data.len() < stride * (height - 1) + pairs * 4 {
+        return None;
+    }
+
+    let row = |y: usize| &raw_data[y * stride..y * stride + pairs * 4];
+    let mut buffer = Nv12Buffer::new(width, height);
+
+    for y in 0..height {
+        let src = row(y);
+        let dst = buffer.y_row(y);
+        for (pair, px) in src.chunks_exact(4).enumerate() {
+            dst[pair * 2] = px[layout.y0];
+            dst[pair * 2 + 1] = px[layout.y1];
+        }
+    }
+
+    for uv_y in 0..height.div_ceil(2) {
+        let (top, bottom) = chroma_source_rows(uv_y, height);
+        let (top, bottom) = (row(top), row(bottom));
+        let dst = buffer.uv_row(uv_y);
+        for (pair, (a, b)) in top.chunks_exact(4).zip(bottom.chunks_exact(4)).enumerate() {
+            dst[pair * 2] = average(a[layout.u], b[layout.u]);
+            dst[pair * 2 + 1] = average(a[layout.v], b[layout.v]);
+        }
+    }
+
+    Some((buffer.data, buffer.stride))
+}
+
+/// Repack NV12 data with an arbitrary stride to tightly packed NV12.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the data is too small.
+pub fn nv12_to_nv12(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<(Vec<u8>, usize)> {
+    let row_bytes = width.next_multiple_of(2);
+    let uv_height = height.div_ceil(2);
+    if width == 0 || height == 0 || stride < row_bytes {
+        return None;
+    }
+    if raw_data.len() < stride * (height + uv_height - 1) + row_bytes {
+        return None;
+    }
+
+    let mut buffer = Nv12Buffer::new(width, height);
+    for y in 0..height {
+        buffer.y_row(y)[..width].copy_from_slice(&raw_data[y * stride..y * stride + width]);
+    }
+    let uv_start = stride * height;
+    for uv_y in 0..uv_height {
+        let start = uv_start + uv_y * stride;
+        buffer
+            .uv_row(uv_y)
+            .copy_from_slice(&raw_data[start..start + row_bytes]);
+    }
+
+    Some((buffer.data, buffer.stride))
+}
+
+/// Repack NV21 data with an arbitrary stride to tightly packed NV12.
+///
+/// The layout matches NV12 except for the chroma order, so the data is
+/// repacked like NV12 and each chroma pair is swapped.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the data is too small.
+pub fn nv21_to_nv12(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<(Vec<u8>, usize)> {
+    let (mut data, nv12_stride) = nv12_to_nv12(raw_data, width, height, stride)?;
+    for pair in data[nv12_stride * height..].chunks_exact_mut(2) {
+        pair.swap(0, 1);
+    }
+    Some((data, nv12_stride))
+}
+
+/// Repack YV12 data (Y, V, U planes) to NV12.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the data is too small.
+pub fn yv12_to_nv12(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<(Vec<u8>, usize)> {
+    let uv_width = width.div_ceil(2);
+    let uv_height = height.div_ceil(2);
+    let uv_stride = stride / 2;
+    if width == 0 || height == 0 || stride < width || uv_stride < uv_width {
+        return None;
+    }
+
+    let y_size = stride * height;
+    let uv_size = uv_stride * uv_height;
+    if raw_data.len() < y_size + 2 * uv_size {
+        return None;
+    }
+    let v_plane = &raw_data[y_size..y_size + uv_size];
+    let u_plane = &raw_data[y_size + uv_size..y_size + 2 * uv_size];
+
+    let mut buffer = Nv12Buffer::new(width, height);
+    for y in 0..height {
+        buffer.y_row(y)[..width].copy_from_slice(&raw_data[y * stride..y * stride + width]);
+    }
+    for uv_y in 0..uv_height {
+        let dst = buffer.uv_row(uv_y);
+        let start = uv_y * uv_stride;
+        for x in 0..uv_width {
+            dst[x * 2] = u_plane[start + x];
+            dst[x * 2 + 1] = v_plane[start + x];
+        }
+    }
+
+    Some((buffer.data, buffer.stride))
+}
+
+/// Convert P216 (or the P216 portion of PA16) data to 8-bit NV12.
+///
+/// The most significant byte of each 16-bit sample is kept.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the data is too small.
+pub fn p216_to_nv12(
+    raw_data: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Option<(Vec<u8>, usize)> {
+    let uv_row_bytes = width.div_ceil(2) * 4;
+    if width == 0 || height == 0 || stride < width * 2 {
+        return None;
+    }
+
+    let y_size = stride * height;
+    if raw_data.len() < y_size + uv_row_bytes * height {
+        return None;
+    }
+    // Upper byte of a little-endian 16-bit sample at byte offset `offset`
+    let msb = |offset: usize| raw_data[offset + 1];
+
+    let mut buffer = Nv12Buffer::new(width, height);
+    for y in 0..height {
+        let dst = buffer.y_row(y);
+        for (x, sample) in dst.iter_mut().take(width).enumerate() {
+            *sample = msb(y * stride + x * 2);
+        }
+    }
+
+    for uv_y in 0..height.div_ceil(2) {
+        let (top, bottom) = chroma_source_rows(uv_y, height);
+        let (top, bottom) = (y_size + top * uv_row_bytes, y_size + bottom * uv_row_bytes);
+        let dst = buffer.uv_row(uv_y);
+        for (i, sample) in dst.iter_mut().take(uv_row_bytes / 2).enumerate() {
+            *sample = average(msb(top + i * 2), msb(bottom + i * 2));
+        }
+    }
+
+    Some((buffer.data, buffer.stride))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::UYVY_LAYOUT;
+    use super::*;
+
+    #[test]
+    fn test_packed_422_to_nv12_averages_chroma_rows() {
+        // 2x2 UYVY: row 0 has U=100, V=200, row 1 has U=110, V=210
+        let data = [100, 16, 200, 17, 110, 18, 210, 19];
+        let (nv12, stride) = packed_422_to_nv12(&data, 2, 2, 4, UYVY_LAYOUT).unwrap();
+        assert_eq!(stride, 2);
+        assert_eq!(nv12, vec![16, 17, 18, 19, 105, 205]);
+    }
+
+    #[test]
+    fn test_yv12_to_nv12_interleaves_chroma() {
+        // 2x2 YV12: Y plane, then V, then U
+        let data = [1, 2, 3, 4, 200, 100];
+        let (nv12, stride) = yv12_to_nv12(&data, 2, 2, 2).unwrap();
+        assert_eq!(stride, 2);
+        assert_eq!(nv12, vec![1, 2, 3, 4, 100, 200]);
+    }
+
+    #[test]
+    fn test_nv12_to_nv12_removes_padding() {
+        // 2x2 NV12 with a stride of 4
+        let data = [1, 2, 0, 0, 3, 4, 0, 0, 100, 200, 0, 0];
+        let (nv12, stride) = nv12_to_nv12(&data, 2, 2, 4).unwrap();
+        assert_eq!(stride, 2);
+        assert_eq!(nv12, vec![1, 2, 3, 4, 100, 200]);
+    }
+
+    #[test]
+    fn test_nv21_to_nv12_swaps_chroma() {
+        // 2x2 NV21 with padded stride: Y rows then one V, U pair
+        let data = [1, 2, 0, 0, 3, 4, 0, 0, 0x60, 0xA0, 0, 0];
+        let (nv12, stride) = nv21_to_nv12(&data, 2, 2, 4).unwrap();
+        assert_eq!(stride, 2);
+        assert_eq!(nv12, vec![1, 2, 3, 4, 0xA0, 0x60]);
+    }
+
+    #[test]
+    fn test_p216_to_nv12_keeps_msb() {
+        // 2x1 P216: Y plane then one interleaved U, V pair
+        let data = [0x11, 0x40, 0x22, 0x50, 0x33, 0x80, 0x44, 0x90];
+        let (nv12, stride) = p216_to_nv12(&data, 2, 1, 4).unwrap();
+        assert_eq!(stride, 2);
+        assert_eq!(nv12, vec![0x40, 0x50, 0x80, 0x90]);
+    }
+
+    #[test]
+    fn test_odd_dimensions_are_padded() {
+        let data = [128u8; 3 * 3 * 4];
+        let (nv12, stride) =
+            bgra_to_nv12(&data, 3, 3, 12, YuvRange::Limited, YuvStandardMatrix::Bt601).unwrap();
+        assert_eq!(stride, 4);
+        assert_eq!(nv12.len(), 4 * 3 + 4 * 2);
+    }
+
+    #[test]
+    fn test_insufficient_data() {
+        assert!(packed_422_to_nv12(&[0u8; 4], 2, 2, 4, UYVY_LAYOUT).is_none());
+        assert!(yv12_to_nv12(&[0u8; 4], 2, 2, 2).is_none());
+        assert!(p216_to_nv12(&[0u8; 4], 2, 1, 4).is_none());
+    }
+}
@@ -0,0 +1,109 @@
+//! Conversion of RGBA8 images to UYVA.
+//!
+//! The color is encoded to packed UYVY 4:2:2 using the `yuv` crate, and the
+//! alpha is copied unchanged into the alpha plane that follows it. The UYVY
+//! stride is the width rounded up to an even number of pixels, times 2 bytes.
+
+use super::to_uyvy::pack_uyvy;
+use yuv::{
+    YuvChromaSubsampling, YuvConversionMode, YuvPlanarImageMut, YuvRange, YuvStandardMatrix,
+};
+
+/// Convert tightly packed RGBA8 data to UYVA.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the dimensions are
+/// zero, `rgba` is shorter than `width * height * 4` bytes, or the conversion fails.
+pub fn rgba8_to_uyva(
+    rgba: &[u8],
+    width: usize,
+    height: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<(Vec<u8>, usize)> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let pixels = width.checked_mul(height)?;
+    let rgba = rgba.get(..pixels.checked_mul(4)?)?;
+
+    let mut planar =
+        YuvPlanarImageMut::alloc(width as u32, height as u32, YuvChromaSubsampling::Yuv422);
+    yuv::rgba_to_yuv422(
+        &mut planar,
+        rgba,
+        (width * 4) as u32,
+        yuv_range,
+        yuv_matrix,
+        YuvConversionMode::Balanced,
+    )
+    .ok()?;
+
+    let stride = width.div_ceil(2) * 4;
+    let uyvy_size = stride * height;
+    let mut data = vec![0u8; uyvy_size + pixels];
+    let (uyvy, alpha) = data.split_at_mut(uyvy_size);
+    pack_uyvy(&planar, uyvy, stride)?;
+
+    for (a, pixel) in alpha.iter_mut().zip(rgba.chunks_exact(4)) {
+        *a = pixel[3];
+    }
+
+    Some((data, stride))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::uyva_to_rgba8;
+    use super::*;
+    use yuv::YuvRange::*;
+    use yuv::YuvStandardMatrix::*;
+
+    #[test]
+    fn test_rgba8_to_uyva_layout() {
+        let width = 4;
+        let height = 2;
+        let rgba: Vec<u8> = (0..width * height)
+            .flat_map(|i| [128, 128, 128, (i * 30) as u8])
+            .collect();
+
+        let (data, stride) =
+            rgba8_to_uyva(&rgba, width, height, Limited, Bt601).expect("Conversion should succeed");
+        assert_eq!(stride, 8);
+        assert_eq!(data.len(), stride * height + width * height);
+
+        // Gray has neutral chroma
+        for pair in data[..stride * height].chunks_exact(4) {
+            assert!(pair[0].abs_diff(128) <= 1 && pair[2].abs_diff(128) <= 1);
+        }
+        let alpha: Vec<u8> = (0..width * height).map(|i| (i * 30) as u8).collect();
+        assert_eq!(&data[stride * height..], &alpha[..]);
+    }
+
+    #[test]
+    fn test_rgba8_to_uyva_odd_width_round_trip() {
+        let width = 3;
+        let height = 2;
+        let rgba = [200u8, 40, 90, 255].repeat(width * height);
+
+        let (data, stride) =
+            rgba8_to_uyva(&rgba, width, height, Full, Bt709).expect("Conversion should succeed");
+        assert_eq!(stride, 8);
+
+        let decoded = uyva_to_rgba8(&data, width, height, stride, Full, Bt709)
+            .expect("Decoding should succeed");
+        for pixel in decoded {
+            assert!(pixel.r.abs_diff(200) <= 3);
+            assert!(pixel.g.abs_diff(40) <= 3);
+            assert!(pixel.b.abs_diff(90) <= 3);
+            assert_eq!(pixel.a, 255);
+        }
+    }
+
+    #[test]
+    fn test_rgba8_to_uyva_invalid_input() {
+        assert!(rgba8_to_uyva(&[0; 16], 0, 4, Limited, Bt601).is_none());
+        assert!(rgba8_to_uyva(&[0; 15], 2, 2, Limited, Bt601).is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! Conversion of RGB8 images to UYVY.
+//!
+//! The color is encoded to planar YUV 4:2:2 using the `yuv` crate and then
+//! interleaved into packed UYVY. The stride is the width rounded up to an even
+//! number of pixels, times 2 bytes.
+
+use yuv::{
+    BufferStoreMut, YuvChromaSubsampling, YuvConversionMode, YuvPackedImageMut, YuvPlanarImageMut,
+    YuvRange, YuvStandardMatrix,
+};
+
+/// Convert tightly packed RGB8 data to UYVY.
+///
+/// # Returns
+///
+/// Returns `Some((data, stride))` on success, or `None` if the dimensions are
+/// zero, `rgb` is shorter than `width * height * 3` bytes, or the conversion fails.
+pub fn rgb8_to_uyvy(
+    rgb: &[u8],
+    width: usize,
+    height: usize,
+    yuv_range: YuvRange,
+    yuv_matrix: YuvStandardMatrix,
+) -> Option<(Vec<u8>, usize)> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let rgb = rgb.get(..width.checked_mul(height)?.checked_mul(3)?)?;
+
+    let mut planar =
+        YuvPlanarImageMut::alloc(width as u32, height as u32, YuvChromaSubsampling::Yuv422);
+    yuv::rgb_to_yuv422(
+        &mut planar,
+        rgb,
+        (width * 3) as u32,
+        yuv_range,
+        yuv_matrix,
+        YuvConversionMode::Balanced,
+    )
+    .ok()?;
+
+    let stride = width.div_ceil(2) * 4;
+    let mut data = vec![0u8; stride * height];
+    pack_uyvy(&planar, &mut data, stride)?;
+    Some((data, stride))
+}
+
+/// Interleaves planar YUV 4:2:2 into packed UYVY rows of `stride` bytes.
+pub(crate) fn pack_uyvy(
+    planar: &YuvPlanarImageMut<'_, u8>,
+    uyvy: &mut [u8],
+    stride: usize,
+) -> Option<()> {
+    let mut packed = YuvPackedImageMut {
+        yuy: BufferStoreMut::Borrowed(uyvy),
+        yuy_stride: stride as u32,
+        width: planar.width,
+        height: planar.height,
+    };
+    yuv::yuv422_to_uyvy422(&mut packed, &planar.to_fixed()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::uyvy_to_rgb8;
+    use super::*;
+    use yuv::YuvRange::*;
+    use yuv::YuvStandardMatrix::*;
+
+    #[test]
+    fn test_rgb8_to_uyvy_round_trip() {
+        let width = 4;
+        let height = 2;
+        let colors = [[200u8, 40, 90], [20, 180, 60], [128, 128, 128], [0, 0, 0]];
+        // Pairs of equal pixels, so 4:2:2 subsampling is lossless
+        let rgb: Vec<u8> = (0..width * height)
+            .flat_map(|i| colors[(i / 2) % colors.len()])
+            .collect();
+
+        let (data, stride) =
+            rgb8_to_uyvy(&rgb, width, height, Limited, Bt709).expect("Conversion should succeed");
+        assert_eq!(stride, 8);
+        assert_eq!(data.len(), stride * height);
+
+        let decoded = uyvy_to_rgb8(&data, width, height, stride, Limited, Bt709)
+            .expect("Decoding should succeed");
+        for (pixel, expected) in decoded.iter().zip(rgb.chunks_exact(3)) {
+            assert!(
+                pixel.r.abs_diff(expected[0]) <= 3,
+                "{pixel:?} vs {expected:?}"
+            );
+            assert!(
+                pixel.g.abs_diff(expected[1]) <= 3,
+                "{pixel:?} vs {expected:?}"
+            );
+            assert!(
+                pixel.b.abs_diff(expected[2]) <= 3,
+                "{pixel:?} vs {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rgb8_to_uyvy_odd_width() {
+        let rgb = [90u8, 160, 220].repeat(3 * 2);
+        let (data, stride) =
+            rgb8_to_uyvy(&rgb, 3, 2, Full, Bt601).expect("Conversion should succeed");
+        assert_eq!(stride, 8);
+        assert_eq!(data.len(), 16);
+    }
+
+    #[test]
+    fn test_rgb8_to_uyvy_invalid_input() {
+        assert!(rgb8_to_uyvy(&[0; 12], 0, 4, Limited, Bt601).is_none());
+        assert!(rgb8_to_uyvy(&[0; 11], 2, 2, Limited, Bt601).is_none());
+    }
+}
@@ -0,0 +1,295 @@
+//! Integration tests for audio accessors on `MediaFrame`.
+
+use omt::{AudioFrameBuilder, Error, FrameType, MetadataFrameBuilder, OwnedMediaFrame};
+
+fn stereo_frame(samples_per_channel: usize) -> omt::OwnedMediaFrame {
+    let left = (0..samples_per_channel).map(|i| i as f32);
+    let right = (0..samples_per_channel).map(|i| -(i as f32));
+    let data = left
+        .chain(right)
+        .flat_map(|f| f.to_ne_bytes())
+        .collect::<Vec<u8>>();
+
+    AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(samples_per_channel as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build audio frame")
+}
+
+#[test]
+fn test_audio_frames_interleaved_view() {
+    let samples_per_channel = 64;
+    let frame = stereo_frame(samples_per_channel);
+    let media_frame = frame.as_media_frame();
+
+    let planes = media_frame.as_f32_planar().expect("Planar view failed");
+    let frames = media_frame.audio_frames().expect("Frame iterator failed");
+    assert_eq!(frames.len(), samples_per_channel);
+
+    let mut count = 0;
+    for (i, samples) in frames.enumerate() {
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0], planes[0][i]);
+        assert_eq!(samples[1], planes[1][i]);
+        count += 1;
+    }
+    assert_eq!(count, samples_per_channel);
+}
+
+#[test]
+fn test_audio_frames_not_audio() {
+    let frame = omt::MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert!(frame.as_media_frame().audio_frames().is_none());
+
+    // A 2x2 BGRA frame holds 16 bytes, the size of 2 channels of 2 samples
+    let frame = omt::VideoFrameBuilder::with_format(omt::Codec::Bgra, 2, 2)
+        .data(vec![0u8; 16])
+        .build()
+        .expect("Failed to build video frame");
+    let mut media_frame = frame.as_media_frame();
+    // SAFETY: Only the channel and sample counts of this frame's own copy of
+    // the FFI struct are written; the data pointer and length are unchanged.
+    unsafe {
+        let ffi = media_frame.as_raw_ffi_mut();
+        (*ffi).Channels = 2;
+        (*ffi).SamplesPerChannel = 2;
+    }
+    assert_eq!(
+        media_frame.as_f32_planar().map(|planes| planes.len()),
+        Some(2)
+    );
+    assert!(media_frame.audio_frames().is_none());
+}
+
+#[test]
+fn test_audio_clipped_per_channel() {
+    let left = [0.25f32, -1.5, 0.5, 0.0];
+    let right = [0.25f32, -1.0, 1.0, 0.0];
+    let data = left
+        .iter()
+        .chain(right.iter())
+        .flat_map(|f| f.to_ne_bytes())
+        .collect::<Vec<u8>>();
+    let frame = AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(4)
+        .data(data)
+        .build()
+        .expect("Failed to build audio frame");
+    let media_frame = frame.as_media_frame();
+
+    assert_eq!(media_frame.audio_clipped(1.0), Some(vec![true, false]));
+    assert_eq!(media_frame.audio_clipped(2.0), Some(vec![false, false]));
+    assert_eq!(media_frame.audio_clipped(0.75), Some(vec![true, true]));
+}
+
+#[test]
+fn test_audio_clipped_non_audio() {
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(frame.as_media_frame().audio_clipped(1.0), None);
+}
+
+#[test]
+fn test_audio_to_i16_interleaved() {
+    let left = [1.0f32, 0.0, 2.0, 0.5];
+    let right = [-1.0f32, -0.5, -3.0, f32::NAN];
+    let data = left
+        .iter()
+        .chain(right.iter())
+        .flat_map(|f| f.to_ne_bytes())
+        .collect::<Vec<u8>>();
+    let frame = AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(4)
+        .data(data)
+        .build()
+        .expect("Failed to build audio frame");
+
+    assert_eq!(
+        frame.as_media_frame().audio_to_i16_interleaved(),
+        Some(vec![32767, -32768, 0, -16384, 32767, -32768, 16383, 0])
+    );
+}
+
+#[test]
+fn test_audio_to_i16_interleaved_non_audio() {
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(frame.as_media_frame().audio_to_i16_interleaved(), None);
+}
+
+#[test]
+fn test_as_f32_planar_large_frame_last_sample() {
+    let channels = 4;
+    let samples_per_channel = 1 << 18;
+    let mut samples = vec![0f32; channels * samples_per_channel];
+    *samples.last_mut().unwrap() = 0.75;
+    let data = samples
+        .iter()
+        .flat_map(|f| f.to_ne_bytes())
+        .collect::<Vec<u8>>();
+
+    let frame = AudioFrameBuilder::new()
+        .channels(channels as i32)
+        .samples_per_channel(samples_per_channel as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build audio frame");
+    let media_frame = frame.as_media_frame();
+
+    let planes = media_frame.as_f32_planar().expect("Planar view failed");
+    assert_eq!(planes.len(), channels);
+    assert!(planes.iter().all(|p| p.len() == samples_per_channel));
+    assert_eq!(planes[channels - 1][samples_per_channel - 1], 0.75);
+}
+
+#[test]
+fn test_builder_rejects_huge_sample_count_without_panic() {
+    let result = AudioFrameBuilder::new()
+        .channels(32)
+        .samples_per_channel(i32::MAX)
+        .data(vec![0u8; 16])
+        .build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_silent_audio() {
+    let frame = OwnedMediaFrame::silent_audio(44_100, 2, 441, 1234).expect("Invalid parameters");
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.frame_type(), FrameType::AUDIO);
+    assert_eq!(media_frame.sample_rate(), 44_100);
+    assert_eq!(media_frame.timestamp(), 1234);
+
+    let planes = media_frame.as_f32_planar().expect("Planar view failed");
+    assert_eq!(planes.len(), 2);
+    assert!(planes.iter().all(|p| p.len() == 441));
+    assert!(planes.iter().flat_map(|p| p.iter()).all(|&s| s == 0.0));
+}
+
+#[test]
+fn test_silent_audio_validates_parameters() {
+    assert!(OwnedMediaFrame::silent_audio(0, 2, 480, -1).is_err());
+    assert!(OwnedMediaFrame::silent_audio(48_000, 0, 480, -1).is_err());
+    assert!(OwnedMediaFrame::silent_audio(48_000, 33, 480, -1).is_err());
+    assert!(OwnedMediaFrame::silent_audio(48_000, 2, 0, -1).is_err());
+    assert!(OwnedMediaFrame::silent_audio(48_000, 2, -1, -1).is_err());
+
+    // Rejected before allocating, rather than aborting on a huge allocation
+    assert!(matches!(
+        OwnedMediaFrame::silent_audio(48_000, 32, i32::MAX, -1),
+        Err(Error::InvalidParameter { .. })
+    ));
+    assert!(OwnedMediaFrame::silent_audio(48_000, 32, 192_001, -1).is_err());
+    let frame =
+        OwnedMediaFrame::silent_audio(192_000, 32, 192_000, -1).expect("Invalid parameters");
+    assert_eq!(frame.as_media_frame().data().len(), 32 * 192_000 * 4);
+}
+
+#[test]
+fn test_empty_metadata() {
+    let frame = OwnedMediaFrame::empty_metadata(42);
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.frame_type(), FrameType::METADATA);
+    assert_eq!(media_frame.timestamp(), 42);
+    assert_eq!(media_frame.as_utf8().expect("Invalid UTF-8"), "<OMTNull/>");
+}
+
+#[test]
+fn test_audio_output_len() {
+    let frame = stereo_frame(1024);
+    assert_eq!(frame.as_media_frame().audio_output_len(), Some(8192));
+
+    let metadata = OwnedMediaFrame::empty_metadata(-1);
+    assert_eq!(metadata.as_media_frame().audio_output_len(), None);
+}
+
+#[test]
+fn test_from_interleaved_deinterleaves() {
+    let interleaved = [0.0f32, 1.0, 2.0, 0.1, 1.1, 2.1];
+    let frame = AudioFrameBuilder::from_interleaved(44_100, 3, &interleaved)
+        .expect("Invalid samples")
+        .timestamp(99)
+        .build()
+        .expect("Failed to build audio frame");
+    let media_frame = frame.as_media_frame();
+
+    assert_eq!(media_frame.sample_rate(), 44_100);
+    assert_eq!(media_frame.channels(), 3);
+    assert_eq!(media_frame.samples_per_channel(), 2);
+    assert_eq!(media_frame.timestamp(), 99);
+    let planes = media_frame.as_f32_planar().expect("Planar view failed");
+    assert_eq!(
+        planes,
+        vec![&[0.0, 0.1][..], &[1.0, 1.1][..], &[2.0, 2.1][..]]
+    );
+}
+
+#[test]
+fn test_from_interleaved_rejects_partial_frame() {
+    let result = AudioFrameBuilder::from_interleaved(48_000, 2, &[0.0; 5]);
+    match result {
+        Err(omt::Error::InvalidParameter { parameter, .. }) => assert_eq!(parameter, "samples"),
+        _ => panic!("Expected InvalidParameter error"),
+    }
+    assert!(AudioFrameBuilder::from_interleaved(48_000, 0, &[0.0; 4]).is_err());
+    assert!(AudioFrameBuilder::from_interleaved(48_000, 33, &[0.0; 33]).is_err());
+}
+
+#[test]
+fn test_from_planar() {
+    let frame = AudioFrameBuilder::from_planar(vec![vec![0.5; 4], vec![-0.5; 4]])
+        .expect("Invalid planes")
+        .build()
+        .expect("Failed to build audio frame");
+    let media_frame = frame.as_media_frame();
+
+    assert_eq!(media_frame.sample_rate(), 48_000);
+    assert_eq!(media_frame.channels(), 2);
+    assert_eq!(media_frame.samples_per_channel(), 4);
+    let planes = media_frame.as_f32_planar().expect("Planar view failed");
+    assert_eq!(planes, vec![&[0.5; 4][..], &[-0.5; 4][..]]);
+}
+
+#[test]
+fn test_from_planar_rejects_unequal_channels() {
+    let result = AudioFrameBuilder::from_planar(vec![vec![0.0; 4], vec![0.0; 3]]);
+    match result {
+        Err(omt::Error::InvalidParameter { parameter, reason }) => {
+            assert_eq!(parameter, "planes");
+            assert!(reason.contains("channel 1"));
+        }
+        _ => panic!("Expected InvalidParameter error"),
+    }
+    assert!(AudioFrameBuilder::from_planar(Vec::new()).is_err());
+}
+
+#[test]
+fn test_audio_data_interleaved_round_trip() {
+    let interleaved = (0..12).map(|i| i as f32 * 0.25).collect::<Vec<_>>();
+    let frame = AudioFrameBuilder::from_interleaved(48_000, 3, &interleaved)
+        .expect("Invalid samples")
+        .build()
+        .expect("Failed to build audio frame");
+
+    assert_eq!(
+        frame.as_media_frame().audio_data_interleaved(),
+        Some(interleaved)
+    );
+}
+
+#[test]
+fn test_audio_data_interleaved_non_audio() {
+    let frame = OwnedMediaFrame::empty_metadata(-1);
+    assert_eq!(frame.as_media_frame().audio_data_interleaved(), None);
+}
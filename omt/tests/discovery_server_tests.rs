@@ -0,0 +1,41 @@
+//! Discovery server error reporting tests.
+//!
+//! These tests change the process-wide discovery server setting, so they live in
+//! their own test binary.
+
+use omt::{Discovery, DiscoverySession, Error, Settings};
+use std::net::TcpListener;
+
+/// Returns a local address that refuses connections.
+fn unreachable_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind listener");
+    let port = listener.local_addr().expect("No local address").port();
+    drop(listener);
+    format!("omt://127.0.0.1:{}", port)
+}
+
+#[test]
+fn test_discovery_unreachable_server_sets_last_error() {
+    let server = unreachable_server();
+    Settings::set_discovery_server(&server).expect("Failed to set discovery server");
+
+    let discovery = DiscoverySession::new();
+    assert!(discovery.last_error().is_none());
+
+    assert!(discovery.addresses().is_empty());
+    match discovery.last_error() {
+        Some(Error::DiscoveryFailed { server: failed, .. }) => assert_eq!(failed, server),
+        other => panic!("Expected DiscoveryFailed, got {:?}", other),
+    }
+
+    assert!(Discovery::get_addresses().is_empty());
+    assert!(matches!(
+        Discovery.last_error(),
+        Some(Error::DiscoveryFailed { .. })
+    ));
+
+    // Without a discovery server, DNS-SD reports no failure
+    Settings::set_discovery_server("").expect("Failed to clear discovery server");
+    Discovery::get_addresses();
+    assert!(Discovery.last_error().is_none());
+}
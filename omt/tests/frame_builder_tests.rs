@@ -1,8 +1,13 @@
 //! Integration tests for frame builders.
 
+// Several tests pass owned metadata strings, which `frame_metadata()` also accepts
+#![allow(clippy::unnecessary_to_owned)]
+
 use omt::{
-    AudioFrameBuilder, Codec, ColorSpace, MetadataFrameBuilder, VideoFlags, VideoFrameBuilder,
+    AlphaMode, AudioFrameBuilder, Codec, ColorSpace, Error, FieldOrder, FrameRate,
+    MetadataFrameBuilder, OwnedMediaFrame, VideoFlags, VideoFrameBuilder,
 };
+use std::time::Duration;
 
 #[test]
 fn test_video_frame_builder_basic() {
@@ -243,6 +248,80 @@ fn test_audio_frame_builder_with_timestamp() {
     assert_eq!(frame.timestamp(), 5_000_000);
 }
 
+#[test]
+fn test_audio_frame_builder_with_frame_metadata() {
+    let channels = 2i32;
+    let samples_per_channel = 1024i32;
+    let data = vec![0u8; (samples_per_channel * channels * 4) as usize];
+    let metadata = "<test>audio metadata</test>";
+
+    let frame = AudioFrameBuilder::new()
+        .channels(channels)
+        .samples_per_channel(samples_per_channel)
+        .frame_metadata(metadata.to_string())
+        .data(data)
+        .build()
+        .expect("Failed to build audio frame with metadata");
+
+    assert_eq!(frame.frame_metadata(), Some(metadata));
+    assert_eq!(frame.as_media_frame().frame_metadata(), metadata);
+}
+
+#[test]
+fn test_audio_frame_builder_frame_metadata_too_long() {
+    let channels = 2i32;
+    let samples_per_channel = 1024i32;
+    let data = vec![0u8; (samples_per_channel * channels * 4) as usize];
+
+    let result = AudioFrameBuilder::new()
+        .channels(channels)
+        .samples_per_channel(samples_per_channel)
+        .frame_metadata("x".repeat(65536))
+        .data(data)
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_frame_metadata_accepts_str_and_string() {
+    let metadata = "<Camera id=\"1\"/>";
+    let video = VideoFrameBuilder::with_format(Codec::Bgra, 2, 2)
+        .frame_metadata(metadata)
+        .data(vec![0u8; 16])
+        .build()
+        .expect("Failed to build video frame");
+    let audio = AudioFrameBuilder::new()
+        .channels(1)
+        .samples_per_channel(4)
+        .frame_metadata(String::from(metadata))
+        .data(vec![0u8; 16])
+        .build()
+        .expect("Failed to build audio frame");
+
+    for frame in [&video, &audio] {
+        assert_eq!(frame.frame_metadata(), Some(metadata));
+        assert_eq!(frame.as_media_frame().frame_metadata(), metadata);
+    }
+}
+
+#[test]
+fn test_frame_metadata_rejects_interior_null_at_build() {
+    let video = VideoFrameBuilder::with_format(Codec::Bgra, 2, 2)
+        .frame_metadata("<a/>\0<b/>")
+        .data(vec![0u8; 16])
+        .build();
+    assert!(matches!(video, Err(Error::NulError(_))));
+
+    let audio = AudioFrameBuilder::new()
+        .channels(1)
+        .samples_per_channel(4)
+        .frame_metadata("<a/>\0<b/>")
+        .data(vec![0u8; 16])
+        .build();
+    assert!(matches!(audio, Err(Error::NulError(_))));
+}
+
 #[test]
 fn test_audio_frame_builder_invalid_sample_rate() {
     let channels = 2i32;
@@ -322,6 +401,116 @@ fn test_metadata_frame_builder_with_timestamp() {
     assert_eq!(frame.timestamp(), 20_000_000);
 }
 
+#[test]
+fn test_timestamp_seconds() {
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .timestamp(omt::OMT_TIME_BASE)
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(frame.as_media_frame().timestamp_seconds(), Some(1.0));
+
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .timestamp(-1)
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(frame.as_media_frame().timestamp_seconds(), None);
+}
+
+#[test]
+fn test_plane_layout() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Nv12)
+        .dimensions(6, 4)
+        .stride(8)
+        .data(vec![0u8; 48])
+        .build()
+        .expect("Failed to build NV12 frame");
+    let layout = frame
+        .as_media_frame()
+        .plane_layout()
+        .expect("No NV12 layout");
+    let planes: Vec<_> = layout
+        .planes
+        .iter()
+        .map(|p| (p.offset, p.stride, p.width, p.height))
+        .collect();
+    assert_eq!(planes, [(0, 8, 6, 4), (32, 8, 3, 2)]);
+
+    let frame = VideoFrameBuilder::with_format(Codec::Yv12, 4, 4)
+        .data(vec![0u8; 24])
+        .build()
+        .expect("Failed to build YV12 frame");
+    let layout = frame
+        .as_media_frame()
+        .plane_layout()
+        .expect("No YV12 layout");
+    let planes: Vec<_> = layout
+        .planes
+        .iter()
+        .map(|p| (p.offset, p.stride, p.width, p.height))
+        .collect();
+    assert_eq!(planes, [(0, 4, 4, 4), (16, 2, 2, 2), (20, 2, 2, 2)]);
+    assert_eq!(layout.total_size(), frame.as_media_frame().data().len());
+
+    let metadata = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(metadata.as_media_frame().plane_layout(), None);
+}
+
+#[test]
+fn test_timestamp_duration() {
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .timestamp(15_000_000)
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(
+        frame.as_media_frame().timestamp_duration(),
+        Some(Duration::from_millis(1500))
+    );
+    assert_eq!(
+        omt::ticks_from_duration(Duration::from_millis(1500)),
+        frame.timestamp()
+    );
+
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .timestamp(-1)
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(frame.as_media_frame().timestamp_duration(), None);
+}
+
+#[test]
+fn test_frame_rate_typed() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(16, 16)
+        .frame_rate(30000, 1001)
+        .data(vec![0u8; 16 * 16 * 2])
+        .build()
+        .expect("Failed to build video frame");
+    let frame_rate = frame
+        .as_media_frame()
+        .frame_rate_typed()
+        .expect("Video frame should have a frame rate");
+    assert_eq!(frame_rate.numerator(), 30000);
+    assert_eq!(frame_rate.denominator(), 1001);
+    assert!((frame_rate.value() - 29.97).abs() < 0.001);
+
+    let frame = AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(480)
+        .data(vec![0u8; 2 * 480 * 4])
+        .build()
+        .expect("Failed to build audio frame");
+    assert_eq!(frame.as_media_frame().frame_rate_typed(), None);
+}
+
 #[test]
 fn test_metadata_frame_builder_empty() {
     let result = MetadataFrameBuilder::new().metadata("").build();
@@ -409,3 +598,442 @@ fn test_as_media_frame_conversion() {
     assert_eq!(media_frame.height(), height as i32);
     assert_eq!(media_frame.data().len(), width * height * 2);
 }
+
+#[test]
+fn test_as_raw_ffi_matches_accessors() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(4, 2)
+        .frame_rate(60, 1)
+        .timestamp(12345)
+        .data(vec![7u8; 4 * 2 * 4])
+        .build()
+        .expect("Failed to build frame");
+    let media_frame = frame.as_media_frame();
+
+    // SAFETY: The frame is alive for the duration of the reads below.
+    let raw = unsafe { &*media_frame.as_raw_ffi() };
+    assert_eq!(raw.Timestamp, media_frame.timestamp());
+    assert_eq!(raw.Width, media_frame.width());
+    assert_eq!(raw.Height, media_frame.height());
+    assert_eq!(raw.Stride, media_frame.stride());
+    assert_eq!(raw.FrameRateN, media_frame.frame_rate_numerator());
+    assert_eq!(raw.FrameRateD, media_frame.frame_rate_denominator());
+    assert_eq!(raw.DataLength as usize, media_frame.data().len());
+    assert_eq!(raw.Data as *const u8, media_frame.data().as_ptr());
+}
+
+#[test]
+fn test_as_raw_ffi_mut_updates_cloned_frame() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(2, 2)
+        .data(vec![0u8; 2 * 2 * 4])
+        .build()
+        .expect("Failed to build frame");
+    let mut cloned = frame.as_media_frame().clone();
+
+    // SAFETY: The cloned frame owns its data and only the timestamp and
+    // buffer contents are modified.
+    unsafe {
+        let raw = &mut *cloned.as_raw_ffi_mut();
+        raw.Timestamp = 42;
+        *(raw.Data as *mut u8) = 0xFF;
+    }
+    assert_eq!(cloned.timestamp(), 42);
+    assert_eq!(cloned.data()[0], 0xFF);
+}
+
+#[test]
+fn test_sequence_number_parsing() {
+    let frame = |metadata: &str| {
+        AudioFrameBuilder::new()
+            .channels(1)
+            .samples_per_channel(4)
+            .data(vec![0u8; 16])
+            .frame_metadata(metadata.to_string())
+            .build()
+            .expect("Failed to build audio frame")
+    };
+
+    let stamped = frame("<custom/><OMTSeq n=\"123\"/>");
+    assert_eq!(stamped.as_media_frame().sequence_number(), Some(123));
+
+    let invalid = frame("<OMTSeq n=\"abc\"/>");
+    assert_eq!(invalid.as_media_frame().sequence_number(), None);
+
+    let plain = frame("<custom/>");
+    assert_eq!(plain.as_media_frame().sequence_number(), None);
+}
+
+#[test]
+fn test_field_order() {
+    let mut frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(16, 8)
+        .data(vec![0u8; 16 * 8 * 2])
+        .frame_metadata("<Camera id=\"1\"/>".to_string())
+        .build()
+        .expect("Failed to build video frame");
+
+    // Progressive frames have no field order
+    assert_eq!(frame.as_media_frame().field_order(), None);
+
+    frame
+        .set_field_order(Some(FieldOrder::TopFirst))
+        .expect("Failed to set field order");
+    {
+        let media_frame = frame.as_media_frame();
+        assert!(media_frame.flags().contains(VideoFlags::INTERLACED));
+        assert_eq!(media_frame.field_order(), Some(FieldOrder::TopFirst));
+        assert_eq!(
+            media_frame.frame_metadata(),
+            "<Camera id=\"1\"/><OMTField order=\"tff\"/>"
+        );
+    }
+
+    // Replacing keeps a single element
+    frame
+        .set_field_order(Some(FieldOrder::BottomFirst))
+        .expect("Failed to set field order");
+    assert_eq!(
+        frame.as_media_frame().field_order(),
+        Some(FieldOrder::BottomFirst)
+    );
+    assert_eq!(
+        frame.frame_metadata(),
+        Some("<Camera id=\"1\"/><OMTField order=\"bff\"/>")
+    );
+
+    frame
+        .set_field_order(None)
+        .expect("Failed to clear field order");
+    assert!(
+        !frame
+            .as_media_frame()
+            .flags()
+            .contains(VideoFlags::INTERLACED)
+    );
+    assert_eq!(frame.as_media_frame().field_order(), None);
+    assert_eq!(frame.frame_metadata(), Some("<Camera id=\"1\"/>"));
+}
+
+#[test]
+fn test_field_order_requires_interlaced_flag() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(16, 8)
+        .data(vec![0u8; 16 * 8 * 2])
+        .frame_metadata("<OMTField order=\"tff\"/>".to_string())
+        .build()
+        .expect("Failed to build video frame");
+    assert_eq!(frame.as_media_frame().field_order(), None);
+}
+
+#[test]
+fn test_alpha_mode() {
+    let cases = [
+        (VideoFlags::NONE, AlphaMode::None),
+        (VideoFlags::PRE_MULTIPLIED, AlphaMode::None),
+        (VideoFlags::ALPHA, AlphaMode::Straight),
+        (
+            VideoFlags::ALPHA | VideoFlags::PRE_MULTIPLIED,
+            AlphaMode::Premultiplied,
+        ),
+    ];
+
+    for (flags, expected) in cases {
+        let frame = VideoFrameBuilder::new()
+            .codec(Codec::Bgra)
+            .dimensions(2, 2)
+            .flags(flags)
+            .data(vec![0u8; 2 * 2 * 4])
+            .build()
+            .expect("Failed to build video frame");
+        assert_eq!(frame.as_media_frame().alpha_mode(), expected, "{:?}", flags);
+    }
+}
+
+#[test]
+fn test_data_matches_geometry() {
+    let video = |codec: Codec, len: usize| {
+        VideoFrameBuilder::new()
+            .codec(codec)
+            .dimensions(16, 8)
+            .data(vec![0u8; len])
+            .allow_short_data(true)
+            .build()
+            .expect("Failed to build video frame")
+    };
+
+    assert_eq!(
+        video(Codec::Uyvy, 16 * 8 * 2)
+            .as_media_frame()
+            .data_matches_geometry(),
+        Some(true)
+    );
+    assert_eq!(
+        video(Codec::Nv12, 16 * 8 * 3 / 2)
+            .as_media_frame()
+            .data_matches_geometry(),
+        Some(true)
+    );
+    // Truncated by one byte
+    assert_eq!(
+        video(Codec::Uyvy, 16 * 8 * 2 - 1)
+            .as_media_frame()
+            .data_matches_geometry(),
+        Some(false)
+    );
+    assert_eq!(
+        video(Codec::Vmx1, 100)
+            .as_media_frame()
+            .data_matches_geometry(),
+        None
+    );
+
+    let audio = AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(4)
+        .data(vec![0u8; 2 * 4 * 4])
+        .build()
+        .expect("Failed to build audio frame");
+    assert_eq!(audio.as_media_frame().data_matches_geometry(), Some(true));
+
+    let metadata = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(metadata.as_media_frame().data_matches_geometry(), None);
+}
+
+#[test]
+fn test_expected_data_len() {
+    let bgra = VideoFrameBuilder::with_format(Codec::Bgra, 16, 8)
+        .data(vec![0u8; 16 * 8 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    assert_eq!(bgra.as_media_frame().expected_data_len(), Some(16 * 8 * 4));
+
+    // Padded rows count towards the expected length
+    let padded = VideoFrameBuilder::with_format(Codec::Bgra, 16, 8)
+        .stride(80)
+        .data(vec![0u8; 80 * 8])
+        .build()
+        .expect("Failed to build video frame");
+    assert_eq!(padded.as_media_frame().expected_data_len(), Some(80 * 8));
+
+    // UYVY plane followed by an 8-bit alpha plane
+    let uyva = VideoFrameBuilder::with_format(Codec::Uyva, 16, 8)
+        .data(vec![0u8; 16 * 8 * 3])
+        .build()
+        .expect("Failed to build video frame");
+    assert_eq!(
+        uyva.as_media_frame().expected_data_len(),
+        Some(16 * 8 * 2 + 16 * 8)
+    );
+
+    let audio = AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(4)
+        .data(vec![0u8; 2 * 4 * 4])
+        .build()
+        .expect("Failed to build audio frame");
+    assert_eq!(audio.as_media_frame().expected_data_len(), Some(32));
+
+    let metadata = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(metadata.as_media_frame().expected_data_len(), None);
+}
+
+#[test]
+fn test_byte_size() {
+    let frame = VideoFrameBuilder::with_format(Codec::Bgra, 16, 8)
+        .data(vec![0u8; 16 * 8 * 4])
+        .frame_metadata("<a/>")
+        .build()
+        .expect("Failed to build video frame");
+    // Metadata includes its null terminator
+    assert_eq!(frame.as_media_frame().byte_size(), 16 * 8 * 4 + 5);
+
+    let metadata = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(metadata.as_media_frame().byte_size(), 8);
+}
+
+#[test]
+fn test_from_received_outlives_source() {
+    let copy = {
+        let original = VideoFrameBuilder::with_format(Codec::Uyvy, 4, 2)
+            .timestamp(42)
+            .data((0..16).collect())
+            .frame_metadata("<source/>")
+            .build()
+            .expect("Failed to build video frame");
+        OwnedMediaFrame::from_received(&original.as_media_frame())
+    };
+
+    {
+        let frame = copy.as_media_frame();
+        assert_eq!(frame.codec(), Some(Codec::Uyvy));
+        assert_eq!((frame.width(), frame.height()), (4, 2));
+        assert_eq!(frame.timestamp(), 42);
+        assert_eq!(frame.data(), (0..16).collect::<Vec<u8>>().as_slice());
+        assert_eq!(frame.frame_metadata(), "<source/>");
+    }
+
+    // The copy can be moved to another thread
+    let handle = std::thread::spawn(move || copy.data().len());
+    assert_eq!(handle.join().expect("Thread panicked"), 16);
+}
+
+#[test]
+fn test_into_sendable_uyvy() {
+    let frame =
+        OwnedMediaFrame::into_sendable(Codec::Uyvy, 1920, 1080, vec![0x80; 1920 * 1080 * 2])
+            .expect("Failed to create frame");
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.codec(), Some(Codec::Uyvy));
+    assert_eq!((media_frame.width(), media_frame.height()), (1920, 1080));
+    assert_eq!(media_frame.stride(), 3840);
+    assert_eq!(media_frame.frame_rate(), 30.0);
+    assert_eq!(media_frame.color_space(), Some(ColorSpace::Bt709));
+    assert_eq!(media_frame.data_matches_geometry(), Some(true));
+}
+
+#[test]
+fn test_into_sendable_odd_width_packed_422() {
+    // Rows are padded to whole pixel pairs
+    for codec in [Codec::Uyvy, Codec::Yuy2] {
+        let frame = OwnedMediaFrame::into_sendable(codec, 7, 2, vec![0x80; 16 * 2])
+            .expect("Failed to create frame");
+        assert_eq!(frame.as_media_frame().stride(), 16, "{codec:?}");
+    }
+    assert!(OwnedMediaFrame::into_sendable(Codec::Uyvy, 7, 2, vec![0x80; 14 * 2]).is_err());
+}
+
+#[test]
+fn test_into_sendable_wrong_size() {
+    let result = OwnedMediaFrame::into_sendable(Codec::Uyvy, 640, 480, vec![0x80; 640 * 480]);
+    match result {
+        Err(Error::InvalidParameter { parameter, reason }) => {
+            assert_eq!(parameter, "data");
+            assert!(reason.contains(&(640 * 480 * 2).to_string()));
+        }
+        _ => panic!("Expected InvalidParameter error"),
+    }
+}
+
+#[test]
+fn test_into_sendable_compressed_codec() {
+    assert!(OwnedMediaFrame::into_sendable(Codec::Vmx1, 640, 480, vec![0; 1024]).is_err());
+}
+
+#[test]
+fn test_video_frame_builder_with_format_defaults() {
+    let frame = VideoFrameBuilder::with_format(Codec::Bgra, 640, 480)
+        .frame_rate_typed(FrameRate::fps_29_97())
+        .color_space(ColorSpace::Bt601)
+        .data(vec![0u8; 640 * 480 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    assert_eq!(media_frame.codec(), Some(Codec::Bgra));
+    assert_eq!((media_frame.width(), media_frame.height()), (640, 480));
+    assert_eq!(media_frame.stride(), 640 * 4);
+    assert_eq!(media_frame.frame_rate_typed(), FrameRate::new(30000, 1001));
+    assert_eq!(media_frame.aspect_ratio(), 640.0 / 480.0);
+    assert_eq!(media_frame.timestamp(), -1);
+}
+
+#[test]
+fn test_video_frame_builder_rejects_short_data() {
+    let result = VideoFrameBuilder::with_format(Codec::Uyvy, 16, 8)
+        .data(vec![0u8; 16 * 8 * 2 - 1])
+        .build();
+    match result {
+        Err(Error::BufferTooSmall { required, provided }) => {
+            assert_eq!(required, 16 * 8 * 2);
+            assert_eq!(provided, 16 * 8 * 2 - 1);
+        }
+        _ => panic!("Expected BufferTooSmall error"),
+    }
+
+    // Explicit stride larger than the natural one raises the requirement
+    assert!(
+        VideoFrameBuilder::with_format(Codec::Uyvy, 16, 8)
+            .stride(64)
+            .data(vec![0u8; 16 * 8 * 2])
+            .build()
+            .is_err()
+    );
+
+    // Compressed payloads have no geometry to validate
+    assert!(
+        VideoFrameBuilder::with_format(Codec::Vmx1, 16, 8)
+            .data(vec![0u8; 10])
+            .build()
+            .is_ok()
+    );
+
+    assert!(
+        VideoFrameBuilder::with_format(Codec::Uyvy, 16, 8)
+            .data(vec![0u8; 10])
+            .allow_short_data(true)
+            .build()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_video_flag_booleans() {
+    let cases = [
+        VideoFlags::NONE,
+        VideoFlags::INTERLACED,
+        VideoFlags::ALPHA,
+        VideoFlags::ALPHA | VideoFlags::PRE_MULTIPLIED,
+        VideoFlags::HIGH_BIT_DEPTH | VideoFlags::INTERLACED,
+        VideoFlags::INTERLACED
+            | VideoFlags::ALPHA
+            | VideoFlags::PRE_MULTIPLIED
+            | VideoFlags::HIGH_BIT_DEPTH,
+    ];
+
+    for flags in cases {
+        let frame = VideoFrameBuilder::with_format(Codec::Bgra, 2, 2)
+            .flags(flags)
+            .data(vec![0u8; 2 * 2 * 4])
+            .build()
+            .expect("Failed to build video frame");
+        let media_frame = frame.as_media_frame();
+        assert_eq!(
+            media_frame.is_interlaced(),
+            flags.contains(VideoFlags::INTERLACED),
+            "{:?}",
+            flags
+        );
+        assert_eq!(
+            media_frame.is_alpha(),
+            flags.contains(VideoFlags::ALPHA),
+            "{:?}",
+            flags
+        );
+        assert_eq!(
+            media_frame.is_premultiplied(),
+            flags.contains(VideoFlags::PRE_MULTIPLIED),
+            "{:?}",
+            flags
+        );
+        assert_eq!(
+            media_frame.is_high_bit_depth(),
+            flags.contains(VideoFlags::HIGH_BIT_DEPTH),
+            "{:?}",
+            flags
+        );
+    }
+}
@@ -0,0 +1,1247 @@
+//! Integration tests that send frames from a `Sender` to a `Receiver` on the
+//! local machine.
+//!
+//! These tests require libomt with working local networking and are ignored by
+//! default. Run them with `cargo test -- --ignored`.
+
+use omt::{
+    AudioFrameBuilder, Codec, CompressedPlayer, ConnectionState, ConvertError, Discovery,
+    DiscoverySession, DualReceiver, FrameRate, FrameType, MetadataFrameBuilder, OwnedMediaFrame,
+    PlaceholderPattern, PreferredVideoFormat, Proxy, Quality, ReceiveFlags, Receiver, Recorder,
+    Sender, SenderInfo, Tally, ThreadOptions, ThreadPriority, VideoFlags, VideoFrameBuilder,
+};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Creates a sender and a receiver connected to it.
+fn connect(name: &str, frame_types: FrameType) -> (Sender, Receiver) {
+    connect_with_flags(name, frame_types, ReceiveFlags::NONE)
+}
+
+/// Creates a sender and a receiver with the given flags connected to it.
+fn connect_with_flags(
+    name: &str,
+    frame_types: FrameType,
+    flags: ReceiveFlags,
+) -> (Sender, Receiver) {
+    let sender = Sender::new(name, Quality::Default).expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+    let receiver = Receiver::new(&address, frame_types, PreferredVideoFormat::Uyvy, flags)
+        .expect("Failed to create receiver");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sender.connection_count() == 0 {
+        assert!(Instant::now() < deadline, "Receiver did not connect");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    (sender, receiver)
+}
+
+fn audio_frame(timestamp: i64) -> OwnedMediaFrame {
+    AudioFrameBuilder::new()
+        .channels(2)
+        .samples_per_channel(480)
+        .timestamp(timestamp)
+        .data(vec![0u8; 2 * 480 * 4])
+        .build()
+        .expect("Failed to build audio frame")
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_recv_batch_returns_all_buffered_frames() {
+    let (sender, mut receiver) = connect("recv_batch", FrameType::AUDIO);
+
+    for i in 0..5 {
+        let frame = audio_frame(i * 100_000);
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+    }
+    // Give the frames time to arrive before draining
+    thread::sleep(Duration::from_millis(200));
+
+    let frames = receiver
+        .recv_batch(FrameType::AUDIO, 1000, 10)
+        .expect("Failed to receive batch");
+    assert_eq!(frames.len(), 5);
+    for (i, frame) in frames.iter().enumerate() {
+        assert_eq!(frame.frame_type(), FrameType::AUDIO);
+        assert_eq!(frame.timestamp(), i as i64 * 100_000);
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_recv_batch_respects_max() {
+    let (sender, mut receiver) = connect("recv_batch_max", FrameType::AUDIO);
+
+    for i in 0..5 {
+        let frame = audio_frame(i * 100_000);
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+    }
+    thread::sleep(Duration::from_millis(200));
+
+    let frames = receiver
+        .recv_batch(FrameType::AUDIO, 1000, 3)
+        .expect("Failed to receive batch");
+    assert_eq!(frames.len(), 3);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_sender_status_reports_last_video_frame() {
+    let (sender, _receiver) = connect("status", FrameType::VIDEO);
+
+    let status = sender.status();
+    assert_eq!(status.name, "status");
+    assert_eq!(status.configured_quality, Quality::Default);
+    assert_eq!(status.codec, None);
+    assert_eq!(status.dimensions, None);
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(1920, 1080)
+        .data(vec![0u8; 1920 * 1080 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    sender
+        .send(&frame.as_media_frame())
+        .expect("Failed to send frame");
+
+    let status = sender.status();
+    assert_eq!(status.codec, Some(Codec::Bgra));
+    assert_eq!(status.dimensions, Some((1920, 1080)));
+    assert!(status.connection_count > 0);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_sender_source_id_is_stable() {
+    let sender = Sender::new("source_id", Quality::Default).expect("Failed to create sender");
+    let id = sender.source_id();
+    assert!(!id.is_empty());
+    assert_eq!(sender.source_id(), id);
+    assert_eq!(sender.source_id(), id);
+
+    let other = Sender::new("source_id_other", Quality::Default).expect("Failed to create sender");
+    assert_ne!(other.source_id(), id);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_metadata_bytes_round_trip() {
+    let (sender, mut receiver) = connect("metadata_bytes", FrameType::METADATA);
+
+    let bytes = [0xDE, 0xAD, 0x00, 0xBE, 0xEF, 0x00];
+    let frame = OwnedMediaFrame::metadata_bytes(&bytes, 1000);
+    sender
+        .send(&frame.as_media_frame())
+        .expect("Failed to send frame");
+
+    let received = receiver
+        .receive(FrameType::METADATA, 1000)
+        .expect("Failed to receive frame")
+        .expect("No metadata frame received");
+    assert_eq!(received.frame_type(), FrameType::METADATA);
+    assert_eq!(received.data(), &bytes);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_receiver_sends_metadata_to_sender() {
+    let (mut sender, receiver) = connect("receiver_send", FrameType::METADATA);
+
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<request />")
+        .build()
+        .expect("Failed to build metadata frame");
+    let bytes = receiver
+        .send(&frame.as_media_frame())
+        .expect("Failed to send metadata");
+    assert!(bytes > 0);
+
+    let received = sender
+        .receive_metadata(1000)
+        .expect("Failed to receive metadata")
+        .expect("No metadata frame received");
+    assert_eq!(received.as_utf8().expect("Invalid UTF-8"), "<request />");
+
+    let video = VideoFrameBuilder::with_format(Codec::Bgra, 2, 2)
+        .data(vec![0u8; 16])
+        .build()
+        .expect("Failed to build video frame");
+    assert!(matches!(
+        receiver.send(&video.as_media_frame()),
+        Err(omt::Error::InvalidFrameType)
+    ));
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_proxy_forwards_identical_pixels() {
+    let source = Sender::new("proxy_source", Quality::Default).expect("Failed to create sender");
+    let source_address = source.get_address().expect("Failed to get sender address");
+    let mut proxy = Proxy::new(&source_address, "proxy_output", Quality::Default)
+        .expect("Failed to create proxy");
+
+    let output_address = proxy
+        .sender()
+        .get_address()
+        .expect("Failed to get proxy address");
+    let mut receiver = Receiver::new(
+        &output_address,
+        FrameType::VIDEO,
+        PreferredVideoFormat::UyvyOrBgra,
+        ReceiveFlags::NONE,
+    )
+    .expect("Failed to create receiver");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while source.connection_count() == 0 || proxy.sender().connection_count() == 0 {
+        assert!(Instant::now() < deadline, "Proxy did not connect");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let pixels: Vec<u8> = (0..4 * 4 * 4).map(|i| i as u8).collect();
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(4, 4)
+        .data(pixels.clone())
+        .build()
+        .expect("Failed to build video frame");
+    source
+        .send(&frame.as_media_frame())
+        .expect("Failed to send frame");
+
+    assert_eq!(
+        proxy.forward(1000).expect("Failed to forward frame"),
+        Some(FrameType::VIDEO)
+    );
+
+    let received = receiver
+        .receive(FrameType::VIDEO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No video frame received");
+    assert_eq!(received.codec(), Some(Codec::Bgra));
+    assert_eq!((received.width(), received.height()), (4, 4));
+    assert_eq!(received.data(), pixels.as_slice());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_owned_frame_sent_from_another_thread() {
+    let (sender, mut receiver) = connect("owned_frame_thread", FrameType::VIDEO);
+
+    let pixels: Vec<u8> = (0..8 * 8 * 4).map(|i| i as u8).collect();
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(8, 8)
+        .data(pixels.clone())
+        .build()
+        .expect("Failed to build video frame");
+
+    thread::spawn(move || {
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+        // Keep the sender alive until the frame has been delivered
+        thread::sleep(Duration::from_millis(200));
+    })
+    .join()
+    .expect("Sending thread panicked");
+
+    let received = receiver
+        .receive(FrameType::VIDEO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No video frame received");
+    assert_eq!((received.width(), received.height()), (8, 8));
+    assert_eq!(received.data(), pixels.as_slice());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_discovery_wait_for_source_matches_partial_name() {
+    let sender =
+        Sender::new("wait_for_source camera", Quality::Default).expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+    let discovery = DiscoverySession::new();
+
+    let start = Instant::now();
+    let found = discovery.wait_for_source("for_source cam", Duration::from_secs(5));
+    assert_eq!(found.as_deref(), Some(address.as_str()));
+    assert!(start.elapsed() < Duration::from_secs(2));
+
+    let found = discovery.wait_for_source(&address, Duration::from_secs(1));
+    assert_eq!(found.as_deref(), Some(address.as_str()));
+
+    assert!(
+        discovery
+            .wait_for_source("no such source", Duration::from_millis(100))
+            .is_none()
+    );
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_discovery_sources_tracks_senders() {
+    let discovery = DiscoverySession::new();
+    let sender =
+        Sender::new("sources_snapshot", Quality::Default).expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+
+    let poll_until = |present: bool| {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let sources = discovery.sources();
+            let mut unique = sources.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(
+                unique.len(),
+                sources.len(),
+                "Duplicate sources: {:?}",
+                sources
+            );
+
+            if sources.contains(&address) == present {
+                return;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "{} present != {}",
+                address,
+                present
+            );
+            thread::sleep(Duration::from_millis(50));
+        }
+    };
+
+    poll_until(true);
+    drop(sender);
+    poll_until(false);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_discovery_new_filtered_matches_sender_name() {
+    let discovery = Discovery::new_filtered("studio-a");
+    let matching =
+        Sender::new("STUDIO-A Filtered", Quality::Default).expect("Failed to create sender");
+    let other =
+        Sender::new("STUDIO-B Filtered", Quality::Default).expect("Failed to create sender");
+    let matching_address = matching
+        .get_address()
+        .expect("Failed to get sender address");
+    let other_address = other.get_address().expect("Failed to get sender address");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let sources = loop {
+        let sources = discovery.sources();
+        if sources.contains(&matching_address) {
+            break sources;
+        }
+        assert!(Instant::now() < deadline, "{} not found", matching_address);
+        thread::sleep(Duration::from_millis(50));
+    };
+    assert!(!sources.contains(&other_address));
+    assert!(discovery.addresses().contains(&other_address));
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_sequence_numbers_increase() {
+    let (sender, mut receiver) = connect("sequence_numbers", FrameType::AUDIO);
+    sender.enable_sequence_numbers(true);
+
+    for i in 0..5 {
+        let frame = audio_frame(i * 100_000);
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+    }
+
+    let mut sequence_numbers = Vec::new();
+    for _ in 0..5 {
+        let frame = receiver
+            .receive(FrameType::AUDIO, 1000)
+            .expect("Failed to receive frame")
+            .expect("No audio frame received");
+        sequence_numbers.push(frame.sequence_number().expect("Missing sequence number"));
+    }
+    assert_eq!(sequence_numbers, vec![0, 1, 2, 3, 4]);
+
+    sender.enable_sequence_numbers(false);
+    let frame = audio_frame(500_000);
+    sender
+        .send(&frame.as_media_frame())
+        .expect("Failed to send frame");
+    let frame = receiver
+        .receive(FrameType::AUDIO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No audio frame received");
+    assert_eq!(frame.sequence_number(), None);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_sender_rejects_disallowed_codec() {
+    let (sender, mut receiver) = connect("allowed_codecs", FrameType::VIDEO);
+    sender.set_allowed_codecs(Some(&[Codec::Uyvy]));
+    assert_eq!(sender.allowed_codecs(), Some(vec![Codec::Uyvy]));
+
+    let bgra = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(4, 4)
+        .data(vec![0u8; 4 * 4 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    assert!(matches!(
+        sender.send(&bgra.as_media_frame()),
+        Err(omt::Error::CodecNotAllowed(Codec::Bgra))
+    ));
+
+    let uyvy = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(4, 4)
+        .data(vec![0u8; 4 * 4 * 2])
+        .build()
+        .expect("Failed to build video frame");
+    sender
+        .send(&uyvy.as_media_frame())
+        .expect("Failed to send allowed codec");
+    let frame = receiver
+        .receive(FrameType::VIDEO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No video frame received");
+    assert_eq!(frame.codec(), Some(Codec::Uyvy));
+
+    sender.set_allowed_codecs(None);
+    sender
+        .send(&bgra.as_media_frame())
+        .expect("Failed to send after clearing restriction");
+
+    // libomt cannot send NV21 regardless of the allowed set
+    let nv21 = VideoFrameBuilder::with_format(Codec::Nv21, 4, 4)
+        .data(vec![0u8; 4 * 4 * 3 / 2])
+        .allow_local_codecs(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(matches!(
+        sender.send(&nv21.as_media_frame()),
+        Err(omt::Error::CodecNotAllowed(Codec::Nv21))
+    ));
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_sender_rejects_short_frame() {
+    let (sender, _receiver) = connect("short_frame", FrameType::VIDEO);
+
+    let short = VideoFrameBuilder::with_format(Codec::Uyvy, 16, 8)
+        .data(vec![0u8; 10])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(matches!(
+        sender.send(&short.as_media_frame()),
+        Err(omt::Error::BufferTooSmall {
+            required: 256,
+            provided: 10
+        })
+    ));
+
+    let narrow = VideoFrameBuilder::with_format(Codec::Uyvy, 7, 2)
+        .stride(14)
+        .data(vec![0u8; 14 * 2])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(matches!(
+        sender.send(&narrow.as_media_frame()),
+        Err(omt::Error::InvalidParameter { .. })
+    ));
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_typed_receive_yields_only_requested_type() {
+    let (sender, mut receiver) = connect("typed_receive", FrameType::ALL);
+
+    let video = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(4, 4)
+        .data(vec![0u8; 4 * 4 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    let metadata = MetadataFrameBuilder::new()
+        .metadata("<typed />")
+        .build()
+        .expect("Failed to build metadata frame");
+    // Queue the other types first so they would be returned by an untyped receive
+    sender
+        .send(&audio_frame(0).as_media_frame())
+        .expect("Failed to send audio");
+    sender
+        .send(&metadata.as_media_frame())
+        .expect("Failed to send metadata");
+    sender
+        .send(&video.as_media_frame())
+        .expect("Failed to send video");
+
+    let frame_type = receiver
+        .receive_video(1000)
+        .expect("Failed to receive video")
+        .map(|frame| frame.frame_type());
+    assert_eq!(frame_type, Some(FrameType::VIDEO));
+    let frame_type = receiver
+        .receive_metadata(1000)
+        .expect("Failed to receive metadata")
+        .map(|frame| frame.frame_type());
+    assert_eq!(frame_type, Some(FrameType::METADATA));
+    let frame_type = receiver
+        .receive_audio(1000)
+        .expect("Failed to receive audio")
+        .map(|frame| frame.frame_type());
+    assert_eq!(frame_type, Some(FrameType::AUDIO));
+    assert!(
+        receiver
+            .receive_audio(0)
+            .expect("Failed to receive audio")
+            .is_none()
+    );
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_into_channels_routes_by_type() {
+    let (sender, receiver) = connect("into_channels", FrameType::ALL);
+    let (handle, channels) = receiver.into_channels(100);
+    assert!(handle.is_running());
+
+    let video = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(4, 4)
+        .data(vec![0u8; 4 * 4 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    sender
+        .send(&video.as_media_frame())
+        .expect("Failed to send video");
+    sender
+        .send(&audio_frame(0).as_media_frame())
+        .expect("Failed to send audio");
+
+    let timeout = Duration::from_secs(2);
+    let frame = channels
+        .video
+        .recv_timeout(timeout)
+        .expect("No video frame received");
+    assert_eq!(frame.frame_type(), FrameType::VIDEO);
+    let frame = channels
+        .audio
+        .recv_timeout(timeout)
+        .expect("No audio frame received");
+    assert_eq!(frame.frame_type(), FrameType::AUDIO);
+    assert!(channels.metadata.try_recv().is_err());
+
+    handle.stop();
+    assert!(channels.video.recv().is_err());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_into_channel_keeps_newest_frames() {
+    let (sender, receiver) = connect("into_channel", FrameType::AUDIO);
+    let (handle, frames) = receiver.into_channel(FrameType::AUDIO, 2);
+
+    for i in 0..5 {
+        sender
+            .send(&audio_frame(i * 100_000).as_media_frame())
+            .expect("Failed to send audio");
+    }
+
+    // Wait until the thread has queued all frames
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while frames.dropped() < 3 {
+        assert!(Instant::now() < deadline, "Frames were not dropped");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let timestamps: Vec<_> = (0..2)
+        .map(|_| {
+            frames
+                .recv_timeout(Duration::from_secs(1))
+                .expect("No audio frame received")
+                .timestamp()
+        })
+        .collect();
+    assert_eq!(timestamps, [300_000, 400_000]);
+
+    handle.stop();
+    assert!(frames.recv().is_err());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_into_channels_with_thread_options() {
+    let (sender, receiver) = connect("into_channels_options", FrameType::AUDIO);
+    let options = ThreadOptions {
+        priority: Some(ThreadPriority::Normal),
+        affinity: Some(vec![0]),
+    };
+    let (handle, channels) = receiver.into_channels_with_options(100, options);
+    assert!(handle.is_running());
+
+    sender
+        .send(&audio_frame(0).as_media_frame())
+        .expect("Failed to send audio");
+    let frame = channels
+        .audio
+        .recv_timeout(Duration::from_secs(2))
+        .expect("No audio frame received");
+    assert_eq!(frame.frame_type(), FrameType::AUDIO);
+
+    handle.stop();
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_recorder_round_trip() {
+    let (sender, mut receiver) = connect_with_flags(
+        "recorder",
+        FrameType::VIDEO,
+        ReceiveFlags::INCLUDE_COMPRESSED,
+    );
+    let mut recorder = Recorder::new(Vec::new()).expect("Failed to start recording");
+
+    let mut recorded = Vec::new();
+    for i in 0..3u8 {
+        let frame = VideoFrameBuilder::new()
+            .codec(Codec::Vmx1)
+            .dimensions(16, 16)
+            .frame_rate(30, 1)
+            .timestamp(i as i64 * 333_333)
+            .data(vec![i; 64 + i as usize])
+            .build()
+            .expect("Failed to build video frame");
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+
+        let received = receiver
+            .receive(FrameType::VIDEO, 1000)
+            .expect("Failed to receive frame")
+            .expect("No video frame received");
+        let compressed = match received.compressed_data() {
+            [] => received.data().to_vec(),
+            compressed => compressed.to_vec(),
+        };
+        recorded.push((received.timestamp(), compressed));
+        assert!(recorder.write_frame(&received).expect("Failed to record"));
+    }
+    assert_eq!(recorder.frames_written(), 3);
+
+    let recording = recorder.into_inner();
+    let player = CompressedPlayer::new(&recording[..]).expect("Invalid recording");
+    let replayed: Vec<OwnedMediaFrame> = player
+        .collect::<std::io::Result<_>>()
+        .expect("Failed to replay");
+    assert_eq!(replayed.len(), 3);
+    for (frame, (timestamp, data)) in replayed.iter().zip(&recorded) {
+        assert_eq!(frame.codec(), Codec::Vmx1);
+        assert_eq!(frame.timestamp(), *timestamp);
+        assert_eq!(frame.data(), &data[..]);
+        assert_eq!(frame.as_media_frame().width(), 16);
+        assert_eq!(frame.as_media_frame().frame_rate_numerator(), 30);
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_recorder_round_trip_encoded_frames() {
+    let (sender, mut receiver) = connect_with_flags(
+        "recorder_encoded",
+        FrameType::VIDEO,
+        ReceiveFlags::INCLUDE_COMPRESSED,
+    );
+    let mut recorder = Recorder::new(Vec::new()).expect("Failed to start recording");
+
+    // Uncompressed frames, which libomt encodes to VMX1 on the way
+    let mut recorded = Vec::new();
+    for i in 0..3u8 {
+        let frame = VideoFrameBuilder::with_format(Codec::Uyvy, 64, 32)
+            .frame_rate(30, 1)
+            .timestamp(i as i64 * 333_333)
+            .data([128, 16 + i * 60].repeat(64 * 32))
+            .build()
+            .expect("Failed to build video frame");
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+
+        let received = receiver
+            .receive(FrameType::VIDEO, 1000)
+            .expect("Failed to receive frame")
+            .expect("No video frame received");
+        assert!(
+            !received.compressed_data().is_empty(),
+            "Received frame carries no VMX1 data"
+        );
+        recorded.push((received.timestamp(), received.compressed_data().to_vec()));
+        assert!(recorder.write_frame(&received).expect("Failed to record"));
+    }
+    assert_eq!(recorder.frames_written(), 3);
+
+    let recording = recorder.into_inner();
+    let replayed: Vec<OwnedMediaFrame> = CompressedPlayer::new(&recording[..])
+        .expect("Invalid recording")
+        .collect::<std::io::Result<_>>()
+        .expect("Failed to replay");
+    assert_eq!(replayed.len(), 3);
+    for (frame, (timestamp, data)) in replayed.iter().zip(&recorded) {
+        let media_frame = frame.as_media_frame();
+        assert_eq!(frame.codec(), Codec::Vmx1);
+        assert_eq!(media_frame.timestamp(), *timestamp);
+        assert_eq!(media_frame.data(), &data[..]);
+        assert_eq!((media_frame.width(), media_frame.height()), (64, 32));
+        assert_eq!(media_frame.frame_rate_numerator(), 30);
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_receive_owned_frames_outlive_next_receive() {
+    let (sender, mut receiver) = connect("receive_owned", FrameType::VIDEO);
+
+    for value in [0x11u8, 0x22] {
+        let frame = VideoFrameBuilder::new()
+            .codec(Codec::Bgra)
+            .dimensions(4, 4)
+            .timestamp(value as i64)
+            .data(vec![value; 4 * 4 * 4])
+            .build()
+            .expect("Failed to build video frame");
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+    }
+
+    let first = receiver
+        .receive_owned(FrameType::VIDEO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No video frame received");
+    let second = receiver
+        .receive_owned(FrameType::VIDEO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No video frame received");
+
+    // Both frames are held at the same time and keep their own data
+    assert_eq!(first.timestamp(), 0x11);
+    assert_eq!(second.timestamp(), 0x22);
+    assert!(first.data().iter().all(|&b| b == 0x11));
+    assert!(second.data().iter().all(|&b| b == 0x22));
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_reset_statistics() {
+    let (sender, mut receiver) = connect("reset_statistics", FrameType::AUDIO);
+    let send_and_receive = |receiver: &mut Receiver, count: i64| {
+        for i in 0..count {
+            sender
+                .send(&audio_frame(i * 100_000).as_media_frame())
+                .expect("Failed to send frame");
+            receiver
+                .receive(FrameType::AUDIO, 1000)
+                .expect("Failed to receive frame")
+                .expect("No audio frame received");
+        }
+    };
+
+    send_and_receive(&mut receiver, 3);
+    assert!(receiver.get_audio_statistics().frames >= 3);
+
+    receiver.reset_audio_statistics();
+    assert_eq!(receiver.get_audio_statistics().frames, 0);
+
+    send_and_receive(&mut receiver, 2);
+    assert_eq!(receiver.get_audio_statistics().frames, 2);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_dual_receiver_preview_and_full() {
+    let sender = Sender::new("dual_receiver", Quality::Default).expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+    let mut receiver =
+        DualReceiver::new(&address, PreferredVideoFormat::Uyvy).expect("Failed to create receiver");
+    assert_eq!(receiver.address(), address);
+    assert!(!receiver.is_full_connected());
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(64, 32)
+        .data(vec![0x80u8; 64 * 32 * 2])
+        .build()
+        .expect("Failed to build video frame");
+    let send_until = |receive: &mut dyn FnMut() -> Option<(i32, i32, VideoFlags)>| {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            sender
+                .send(&frame.as_media_frame())
+                .expect("Failed to send frame");
+            if let Some(result) = receive() {
+                return result;
+            }
+            assert!(Instant::now() < deadline, "No frame received");
+        }
+    };
+
+    let (width, height, flags) = send_until(&mut || {
+        receiver
+            .preview_frame(100)
+            .expect("Failed to receive preview")
+            .map(|f| (f.width(), f.height(), f.flags()))
+    });
+    assert!(flags.contains(VideoFlags::PREVIEW));
+    assert!(width < 64 && height < 32);
+
+    let (width, height, flags) = send_until(&mut || {
+        receiver
+            .full_frame(100)
+            .expect("Failed to receive full frame")
+            .map(|f| (f.width(), f.height(), f.flags()))
+    });
+    assert!(!flags.contains(VideoFlags::PREVIEW));
+    assert_eq!((width, height), (64, 32));
+    assert!(receiver.is_full_connected());
+
+    receiver.release_full();
+    assert!(receiver.full().is_none());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_try_to_rgba8_compressed_only() {
+    let (sender, mut receiver) = connect_with_flags(
+        "compressed_only",
+        FrameType::VIDEO,
+        ReceiveFlags::COMPRESSED_ONLY,
+    );
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(16, 16)
+        .data(vec![0xFF; 16 * 16 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+        if let Some(received) = receiver
+            .receive(FrameType::VIDEO, 100)
+            .expect("Failed to receive")
+        {
+            assert_eq!(received.to_rgba8(), None);
+            assert_eq!(received.try_to_rgba8(), Err(ConvertError::CompressedOnly));
+            break;
+        }
+        assert!(Instant::now() < deadline, "No frame received");
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_send_compressed_forwards_vmx1() {
+    let (sender, mut receiver) = connect_with_flags(
+        "compressed_source",
+        FrameType::VIDEO,
+        ReceiveFlags::INCLUDE_COMPRESSED,
+    );
+    let (relay, mut relay_receiver) = connect("compressed_relay", FrameType::VIDEO);
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(64, 32)
+        .frame_rate(30000, 1001)
+        .data(vec![0x80u8; 64 * 32 * 2])
+        .build()
+        .expect("Failed to build video frame");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let received = loop {
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+        if let Some(received) = receiver
+            .receive(FrameType::VIDEO, 100)
+            .expect("Failed to receive")
+        {
+            break OwnedMediaFrame::from_received(&received);
+        }
+        assert!(Instant::now() < deadline, "No frame received");
+    };
+    assert!(!received.as_media_frame().compressed_data().is_empty());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        relay
+            .send_compressed(&received.as_media_frame())
+            .expect("Failed to forward frame");
+        if let Some(forwarded) = relay_receiver
+            .receive(FrameType::VIDEO, 100)
+            .expect("Failed to receive forwarded frame")
+        {
+            assert_eq!((forwarded.width(), forwarded.height()), (64, 32));
+            assert_eq!(
+                (
+                    forwarded.frame_rate_numerator(),
+                    forwarded.frame_rate_denominator()
+                ),
+                (30000, 1001)
+            );
+            break;
+        }
+        assert!(Instant::now() < deadline, "No forwarded frame received");
+    }
+
+    let error = relay
+        .send_compressed(&frame.as_media_frame())
+        .expect_err("Uncompressed frame was forwarded");
+    assert!(matches!(error, omt::Error::InvalidParameter { .. }));
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_send_receipt() {
+    let (sender, mut receiver) = connect("send_receipt", FrameType::AUDIO);
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let receipt = loop {
+        let receipt = sender
+            .send(&audio_frame(0).as_media_frame())
+            .expect("Failed to send audio");
+        if receiver
+            .receive(FrameType::AUDIO, 100)
+            .expect("Failed to receive")
+            .is_some()
+        {
+            break receipt;
+        }
+        assert!(Instant::now() < deadline, "No frame received");
+    };
+
+    assert!(receipt.receivers >= 1);
+    assert!(receipt.is_delivered());
+    assert_eq!(receipt.bytes, receipt.return_code as usize);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_connection_count_follows_receivers() {
+    let sender =
+        Sender::new("connection_count", Quality::Default).expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+    assert_eq!(sender.connection_count(), 0);
+
+    let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+    let receiver_thread = thread::spawn(move || {
+        let _receiver = Receiver::new(
+            &address,
+            FrameType::ALL,
+            PreferredVideoFormat::Uyvy,
+            ReceiveFlags::NONE,
+        )
+        .expect("Failed to create receiver");
+        // Keep the receiver connected until the count has been checked
+        let _ = done_rx.recv();
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sender.connection_count() == 0 {
+        assert!(Instant::now() < deadline, "Receiver did not connect");
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(sender.connection_count() >= 1);
+
+    done_tx.send(()).expect("Receiver thread exited early");
+    receiver_thread.join().expect("Receiver thread panicked");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sender.connection_count() != 0 {
+        assert!(Instant::now() < deadline, "Receiver did not disconnect");
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_sender_tally_aggregates_receivers() {
+    let (sender, program_receiver) = connect("sender_tally", FrameType::VIDEO);
+    let address = sender.get_address().expect("Failed to get sender address");
+    let preview_receiver = Receiver::new(
+        &address,
+        FrameType::VIDEO,
+        PreferredVideoFormat::Uyvy,
+        ReceiveFlags::NONE,
+    )
+    .expect("Failed to create receiver");
+
+    program_receiver.set_tally(Tally::program_only());
+    preview_receiver.set_tally(Tally::preview_only());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let (tally, _) = sender.get_tally(100).expect("Failed to get tally");
+        if tally == Tally::new(true, true) {
+            break;
+        }
+        assert!(Instant::now() < deadline, "Tally was not aggregated");
+    }
+
+    // Without further changes the last known state is returned
+    let (tally, changed) = sender.get_tally(10).expect("Failed to get tally");
+    assert_eq!(tally, Tally::new(true, true));
+    assert!(!changed);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_interleaved_audio_round_trip() {
+    let (sender, mut receiver) = connect("interleaved_audio", FrameType::AUDIO);
+
+    let interleaved = (0..960).map(|i| i as f32 / 960.0).collect::<Vec<_>>();
+    let frame = AudioFrameBuilder::from_interleaved(48_000, 2, &interleaved)
+        .expect("Invalid samples")
+        .build()
+        .expect("Failed to build audio frame");
+    sender
+        .send(&frame.as_media_frame())
+        .expect("Failed to send frame");
+
+    let received = receiver
+        .receive(FrameType::AUDIO, 1000)
+        .expect("Failed to receive frame")
+        .expect("No audio frame received");
+    assert_eq!(received.channels(), 2);
+    assert_eq!(received.samples_per_channel(), 480);
+    assert_eq!(received.data(), frame.data());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_resolution_changed_trips_once() {
+    let (sender, mut receiver) = connect("resolution_changed", FrameType::VIDEO);
+
+    let send_and_receive = |receiver: &mut Receiver, width: i32, height: i32| {
+        let frame = VideoFrameBuilder::with_format(Codec::Uyvy, width, height)
+            .data(vec![0x80; (width * height * 2) as usize])
+            .build()
+            .expect("Failed to build video frame");
+        sender
+            .send(&frame.as_media_frame())
+            .expect("Failed to send frame");
+        let received = receiver
+            .receive_owned(FrameType::VIDEO, 1000)
+            .expect("Failed to receive frame")
+            .expect("No video frame received");
+        let received = received.as_media_frame();
+        assert_eq!((received.width(), received.height()), (width, height));
+        receiver.resolution_changed()
+    };
+
+    assert!(!send_and_receive(&mut receiver, 1920, 1080));
+    assert!(!send_and_receive(&mut receiver, 1920, 1080));
+    assert!(send_and_receive(&mut receiver, 1280, 720));
+    assert!(!send_and_receive(&mut receiver, 1280, 720));
+    assert!(!receiver.resolution_changed());
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_create_any_skips_unreachable_url() {
+    let sender = Sender::new("create_any", Quality::Default).expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("Failed to bind listener");
+    let closed = format!(
+        "omt://127.0.0.1:{}",
+        listener.local_addr().expect("No local address").port()
+    );
+    drop(listener);
+
+    let mut receiver = Receiver::create_any(
+        &[&closed, &address],
+        FrameType::AUDIO,
+        PreferredVideoFormat::Uyvy,
+        ReceiveFlags::NONE,
+        Duration::from_millis(200),
+    )
+    .expect("Failed to create receiver");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sender.connection_count() == 0 {
+        assert!(Instant::now() < deadline, "Receiver did not connect");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    sender
+        .send(&audio_frame(42).as_media_frame())
+        .expect("Failed to send frame");
+    let frame = receiver
+        .receive(FrameType::AUDIO, 1000)
+        .expect("Failed to receive")
+        .expect("No frame received");
+    assert_eq!(frame.timestamp(), 42);
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_placeholder_sends_without_user_frames() {
+    let placeholder = Sender::placeholder("placeholder", FrameRate::fps_30(), 64, 36)
+        .expect("Failed to create placeholder");
+    let address = placeholder
+        .sender()
+        .get_address()
+        .expect("Failed to get sender address");
+    let mut receiver = Receiver::new(
+        &address,
+        FrameType::VIDEO,
+        PreferredVideoFormat::UyvyOrBgra,
+        ReceiveFlags::NONE,
+    )
+    .expect("Failed to create receiver");
+
+    let receive_rgb = |receiver: &mut Receiver| {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            assert!(Instant::now() < deadline, "No placeholder frame received");
+            if let Some(frame) = receiver
+                .receive(FrameType::VIDEO, 100)
+                .expect("Failed to receive")
+            {
+                assert_eq!((frame.width(), frame.height()), (64, 36));
+                assert!(!frame.data().is_empty());
+                return frame.to_rgb8().expect("Failed to convert frame");
+            }
+        }
+    };
+
+    // Color bars start with white and end with black
+    let bars = receive_rgb(&mut receiver);
+    assert!(bars[0].r > 128 && bars[0].g > 128 && bars[0].b > 128);
+    assert!(bars[63].r < 64 && bars[63].g < 64 && bars[63].b < 64);
+
+    placeholder.set_pattern(PlaceholderPattern::Black);
+    assert_eq!(placeholder.pattern(), PlaceholderPattern::Black);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while receive_rgb(&mut receiver)[0].r > 64 {
+        assert!(Instant::now() < deadline, "Pattern did not switch to black");
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_frames_skips_timeouts_until_break() {
+    let (sender, mut receiver) = connect("frames_iterator", FrameType::AUDIO);
+
+    let sending = thread::spawn(move || {
+        for i in 0..3 {
+            // Longer than the receive timeout, so timeouts happen in between
+            thread::sleep(Duration::from_millis(50));
+            sender
+                .send(&audio_frame(i * 100_000).as_media_frame())
+                .expect("Failed to send frame");
+        }
+        sender
+    });
+
+    let mut timestamps = Vec::new();
+    let mut frames = receiver.frames(FrameType::AUDIO, 10);
+    while let Some(frame) = frames.next_frame() {
+        let frame = frame.expect("Failed to receive");
+        timestamps.push(frame.timestamp());
+        if timestamps.len() == 3 {
+            break;
+        }
+    }
+    assert_eq!(timestamps, [0, 100_000, 200_000]);
+
+    let _sender = sending.join().expect("Sending thread panicked");
+    // The receiver is usable again once the iterator is gone
+    assert!(
+        receiver
+            .receive(FrameType::AUDIO, 50)
+            .expect("Failed to receive")
+            .is_none()
+    );
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_new_with_info_is_read_by_receiver() {
+    let info = SenderInfo::new("Switcher".into(), "Acme".into(), "1.2.0".into());
+    let sender = Sender::new_with_info("sender_info", Quality::Default, info.clone())
+        .expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+    let receiver = Receiver::new(
+        &address,
+        FrameType::METADATA,
+        PreferredVideoFormat::Uyvy,
+        ReceiveFlags::NONE,
+    )
+    .expect("Failed to create receiver");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if let Some(received) = receiver
+            .get_sender_information()
+            .expect("Failed to read sender information")
+        {
+            assert_eq!(received, info);
+            break;
+        }
+        assert!(Instant::now() < deadline, "No sender information received");
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+#[ignore] // Requires libomt with working local networking
+fn test_connection_state_follows_sender() {
+    let info = SenderInfo::new("Camera".into(), "Acme".into(), "1.0".into());
+    let sender = Sender::new_with_info("connection_state", Quality::Default, info)
+        .expect("Failed to create sender");
+    let address = sender.get_address().expect("Failed to get sender address");
+    let receiver = Receiver::new(
+        &address,
+        FrameType::METADATA,
+        PreferredVideoFormat::Uyvy,
+        ReceiveFlags::NONE,
+    )
+    .expect("Failed to create receiver");
+
+    let wait_for = |state: ConnectionState| {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while receiver.connection_state() != state {
+            assert!(
+                Instant::now() < deadline,
+                "Receiver never reached {:?}",
+                state
+            );
+            thread::sleep(Duration::from_millis(10));
+        }
+    };
+
+    wait_for(ConnectionState::Connected);
+    assert!(receiver.is_connected());
+
+    drop(sender);
+    wait_for(ConnectionState::Disconnected);
+    assert!(!receiver.is_connected());
+}
@@ -4,6 +4,9 @@
 //! Many of these tests are compile-time tests - they should fail to compile if the
 //! lifetime constraints are removed.
 
+// Several tests pass owned metadata strings, which `frame_metadata()` also accepts
+#![allow(clippy::unnecessary_to_owned)]
+
 use omt::{AudioFrameBuilder, Codec, FrameType, MetadataFrameBuilder, VideoFrameBuilder};
 
 /// Test that MediaFrame cannot outlive the Receiver
@@ -503,7 +506,7 @@ fn test_stride_calculation() {
 fn test_explicit_stride() {
     let width = 1920;
     let height = 1080;
-    let stride = 2048; // Padded stride
+    let stride = 4096; // Padded stride
 
     let owned_frame = VideoFrameBuilder::new()
         .codec(Codec::Uyvy)
@@ -0,0 +1,1302 @@
+//! Integration tests for video frame conversions on `MediaFrame`.
+
+use omt::{
+    AlphaMode, Codec, ColorSpace, ConvertError, Error, MetadataFrameBuilder, OwnedMediaFrame,
+    VideoFlags, VideoFrameBuilder,
+};
+
+#[test]
+fn test_to_rgb16_upscaled_bgra_white() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(2, 2)
+        .data(vec![255u8; 2 * 2 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    assert!(media_frame.to_rgb16().is_none());
+
+    let pixels = media_frame
+        .to_rgb16_upscaled()
+        .expect("BGRA should upscale to RGB16");
+    assert_eq!(pixels.len(), 4);
+    for px in pixels {
+        assert_eq!((px.r, px.g, px.b), (65535, 65535, 65535));
+    }
+}
+
+#[test]
+fn test_to_rgb16_upscaled_matches_rgb8() {
+    let data: Vec<u8> = (0..4u8)
+        .flat_map(|i| [i * 10, i * 20, i * 30, 255])
+        .collect();
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(2, 2)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let rgb8 = media_frame.to_rgb8().expect("BGRA should convert to RGB8");
+    let rgb16 = media_frame
+        .to_rgb16_upscaled()
+        .expect("BGRA should upscale to RGB16");
+    for (a, b) in rgb8.iter().zip(rgb16.iter()) {
+        assert_eq!(a.r as u16 * 257, b.r);
+        assert_eq!(a.g as u16 * 257, b.g);
+        assert_eq!(a.b as u16 * 257, b.b);
+    }
+}
+
+#[test]
+fn test_to_rgb16_upscaled_unsupported_codec() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Vmx1)
+        .dimensions(2, 2)
+        .data(vec![0u8; 16])
+        .build()
+        .expect("Failed to build video frame");
+
+    assert!(frame.as_media_frame().to_rgb16_upscaled().is_none());
+}
+
+#[test]
+fn test_to_luma16_p216_mid_gray() {
+    let width = 4usize;
+    let height = 2usize;
+    let mut samples = vec![0x8000u16; width * height]; // Y plane
+    samples.extend(std::iter::repeat_n(0x8000u16, width * height)); // UV plane
+    let data: Vec<u8> = samples.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::P216)
+        .dimensions(width as i32, height as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame");
+
+    let luma = frame
+        .as_media_frame()
+        .to_luma16()
+        .expect("P216 should yield 16-bit luma");
+    assert_eq!(luma, vec![0x8000; width * height]);
+}
+
+#[test]
+fn test_to_luma16_uyvy_upscales() {
+    let width = 4usize;
+    let height = 2usize;
+    let data = [128u8, 235, 128, 16].repeat(width * height / 2);
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(width as i32, height as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame");
+
+    let luma = frame
+        .as_media_frame()
+        .to_luma16()
+        .expect("UYVY should yield upscaled luma");
+    assert_eq!(luma.len(), width * height);
+    for pair in luma.chunks(2) {
+        assert_eq!(pair, [235 * 257, 16 * 257]);
+    }
+}
+
+/// Returns a neutral gray frame with luma 126 (128 for BGRA) in `codec`.
+fn neutral_gray_data(codec: Codec, width: usize, height: usize) -> Vec<u8> {
+    let pixels = width * height;
+    let le16 =
+        |values: Vec<u16>| -> Vec<u8> { values.iter().flat_map(|v| v.to_le_bytes()).collect() };
+    match codec {
+        Codec::Uyvy => [128u8, 126].repeat(pixels),
+        Codec::Yuy2 => [126u8, 128].repeat(pixels),
+        Codec::Uyva => [[128u8, 126].repeat(pixels), vec![255; pixels]].concat(),
+        Codec::Nv12 | Codec::Nv21 | Codec::Yv12 => {
+            [vec![126u8; pixels], vec![128; pixels / 2]].concat()
+        }
+        Codec::P216 => le16([vec![0x7E00u16; pixels], vec![0x8000; pixels]].concat()),
+        Codec::Pa16 => le16(
+            [
+                vec![0x7E00u16; pixels],
+                vec![0x8000; pixels],
+                vec![0xFFFF; pixels],
+            ]
+            .concat(),
+        ),
+        Codec::Bgra => [128u8, 128, 128, 255].repeat(pixels),
+        _ => vec![0; pixels * 2],
+    }
+}
+
+#[test]
+fn test_to_luma8_neutral_gray_is_uniform() {
+    let (width, height) = (8usize, 4usize);
+    for (codec, expected) in [
+        (Codec::Uyvy, 126u8),
+        (Codec::Yuy2, 126),
+        (Codec::Uyva, 126),
+        (Codec::Nv12, 126),
+        (Codec::Nv21, 126),
+        (Codec::Yv12, 126),
+        (Codec::P216, 126),
+        (Codec::Pa16, 126),
+        (Codec::Bgra, 128),
+    ] {
+        let frame = VideoFrameBuilder::with_format(codec, width as i32, height as i32)
+            .data(neutral_gray_data(codec, width, height))
+            .allow_local_codecs(!codec.is_runtime_supported())
+            .build()
+            .expect("Failed to build video frame");
+
+        let luma = frame
+            .as_media_frame()
+            .to_luma8()
+            .unwrap_or_else(|| panic!("{} should yield luma", codec));
+        assert_eq!(luma, vec![expected; width * height], "{}", codec);
+    }
+}
+
+#[test]
+fn test_to_luma8_compressed_is_none() {
+    let frame = VideoFrameBuilder::with_format(Codec::Vmx1, 8, 4)
+        .data(vec![0u8; 64])
+        .build()
+        .expect("Failed to build video frame");
+    assert!(frame.as_media_frame().to_luma8().is_none());
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_to_image_matches_rgb_conversions() {
+    let (width, height) = (6usize, 4usize);
+    let data: Vec<u8> = (0..width * height)
+        .flat_map(|i| [i as u8, (i * 2) as u8, (i * 3) as u8, 200])
+        .collect();
+    // Padded rows must not leak into the image
+    let stride = width * 4 + 8;
+    let padded: Vec<u8> = data
+        .chunks(width * 4)
+        .flat_map(|row| [row, &[0u8; 8]].concat())
+        .collect();
+    let frame = VideoFrameBuilder::with_format(Codec::Bgra, width as i32, height as i32)
+        .stride(stride as i32)
+        .flags(VideoFlags::ALPHA)
+        .data(padded)
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let rgb = media_frame.to_image_rgb8().expect("BGRA should convert");
+    assert_eq!(rgb.dimensions(), (width as u32, height as u32));
+    let pixels = media_frame.to_rgb8().expect("BGRA should convert");
+    for (i, px) in pixels.iter().enumerate() {
+        let image_px = rgb.get_pixel((i % width) as u32, (i / width) as u32);
+        assert_eq!(image_px.0, [px.r, px.g, px.b]);
+    }
+
+    let rgba = media_frame.to_image_rgba8().expect("BGRA should convert");
+    assert_eq!(rgba.dimensions(), (width as u32, height as u32));
+    assert_eq!(rgba.get_pixel(1, 0).0, [3, 2, 1, 200]);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_to_image_unsupported_codec() {
+    let frame = VideoFrameBuilder::with_format(Codec::Vmx1, 8, 4)
+        .data(vec![0u8; 64])
+        .build()
+        .expect("Failed to build video frame");
+    assert!(frame.as_media_frame().to_image_rgb8().is_none());
+    assert!(frame.as_media_frame().to_image_rgba8().is_none());
+    assert!(
+        frame
+            .as_media_frame()
+            .to_image_rgba8_with_alpha(AlphaMode::Straight)
+            .is_none()
+    );
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_to_image_rgba8_with_alpha() {
+    // Straight red at 50% alpha, BGRA byte order
+    let frame = VideoFrameBuilder::with_format(Codec::Bgra, 2, 1)
+        .flags(VideoFlags::ALPHA)
+        .data([0u8, 0, 255, 128].repeat(2))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let straight = media_frame
+        .to_image_rgba8_with_alpha(AlphaMode::Straight)
+        .expect("BGRA should convert");
+    assert_eq!(straight.dimensions(), (2, 1));
+    assert_eq!(straight.get_pixel(0, 0).0, [255, 0, 0, 128]);
+
+    let premultiplied = media_frame
+        .to_image_rgba8_with_alpha(AlphaMode::Premultiplied)
+        .expect("BGRA should convert");
+    assert_eq!(premultiplied.get_pixel(1, 0).0, [128, 0, 0, 128]);
+}
+
+/// Eight vertical bars with distinct colors, rows padded to `stride`.
+#[cfg(feature = "rayon")]
+fn color_bar_data(codec: Codec, width: usize, height: usize, stride: usize) -> Vec<u8> {
+    // (Y, U, V) of 75% bars: white, yellow, cyan, green, magenta, red, blue, black
+    const BARS: [[u8; 3]; 8] = [
+        [180, 128, 128],
+        [162, 44, 142],
+        [131, 156, 44],
+        [112, 72, 58],
+        [84, 184, 198],
+        [65, 100, 212],
+        [35, 212, 114],
+        [16, 128, 128],
+    ];
+
+    let mut row = Vec::with_capacity(stride);
+    for x in (0..width).step_by(2) {
+        let [y, u, v] = BARS[x * BARS.len() / width];
+        match codec {
+            Codec::Uyvy | Codec::Uyva => row.extend_from_slice(&[u, y, v, y]),
+            Codec::Yuy2 => row.extend_from_slice(&[y, u, y, v]),
+            _ => {
+                row.extend_from_slice(&[u, y, v, 255]);
+                row.extend_from_slice(&[v, u, y, 128]);
+            }
+        }
+    }
+    row.resize(stride, 0);
+
+    let mut data = row.repeat(height);
+    if codec == Codec::Uyva {
+        // Horizontal alpha ramp
+        data.extend((0..height).flat_map(|_| (0..width).map(|x| (x % 256) as u8)));
+    }
+    data
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_to_rgba8_parallel_matches_serial() {
+    let (width, height) = (1920, 1080);
+    for (codec, stride) in [
+        (Codec::Uyvy, width * 2),
+        (Codec::Yuy2, width * 2),
+        (Codec::Uyva, width * 2),
+        (Codec::Bgra, width * 4),
+    ] {
+        let frame = VideoFrameBuilder::with_format(codec, width as i32, height as i32)
+            .stride(stride as i32)
+            .data(color_bar_data(codec, width, height, stride))
+            .build()
+            .expect("Failed to build video frame");
+        let media_frame = frame.as_media_frame();
+
+        let serial = media_frame.to_rgba8().expect("Serial conversion failed");
+        let parallel = media_frame
+            .to_rgba8_parallel()
+            .expect("Parallel conversion failed");
+        assert!(serial == parallel, "{:?} with stride {}", codec, stride);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_to_rgba8_parallel_falls_back_to_serial() {
+    // Below the 720 line threshold
+    let frame = VideoFrameBuilder::with_format(Codec::Uyvy, 64, 16)
+        .data(color_bar_data(Codec::Uyvy, 64, 16, 128))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.to_rgba8_parallel(), media_frame.to_rgba8());
+
+    // 4:2:0 rows share chroma, so these are never split
+    let frame = VideoFrameBuilder::with_format(Codec::Nv12, 1280, 720)
+        .data(neutral_gray_data(Codec::Nv12, 1280, 720))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+    assert!(media_frame.to_rgba8_parallel() == media_frame.to_rgba8());
+
+    let frame = VideoFrameBuilder::with_format(Codec::Vmx1, 1280, 720)
+        .data(vec![0u8; 1024])
+        .build()
+        .expect("Failed to build video frame");
+    assert_eq!(frame.as_media_frame().to_rgba8_parallel(), None);
+}
+
+#[test]
+fn test_to_rgba8_with_chroma_nearest_matches_to_rgba8() {
+    let width = 8usize;
+    let height = 2usize;
+    let data = [90u8, 118, 240, 118, 240, 118, 90, 118].repeat(width * height / 4);
+
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(width as i32, height as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    assert_eq!(
+        media_frame.to_rgba8_with_chroma(omt::ChromaUpsample::Nearest),
+        media_frame.to_rgba8()
+    );
+    let linear = media_frame
+        .to_rgba8_with_chroma(omt::ChromaUpsample::Linear)
+        .expect("UYVY should convert with linear chroma");
+    assert_eq!(linear.len(), width * height);
+}
+
+#[test]
+fn test_to_rgba8_with_chroma_rejects_bad_strides() {
+    // Rows of 4 UYVY pixels need 8 bytes
+    for codec in [Codec::Uyvy, Codec::Yuy2, Codec::Uyva] {
+        for stride in [4, 0, -8] {
+            let frame = VideoFrameBuilder::with_format(codec, 4, 2)
+                .stride(stride)
+                .data(vec![0x80; 12])
+                .allow_short_data(true)
+                .build()
+                .expect("Failed to build video frame");
+            assert_eq!(
+                frame
+                    .as_media_frame()
+                    .to_rgba8_with_chroma(omt::ChromaUpsample::Linear),
+                None,
+                "{codec:?} stride {stride}"
+            );
+        }
+    }
+
+    // An odd width whose stride cuts the last pair short
+    let frame = VideoFrameBuilder::with_format(Codec::Uyvy, 7, 2)
+        .stride(14)
+        .data(vec![0x80; 16 * 2 * 2])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert_eq!(
+        frame
+            .as_media_frame()
+            .to_rgba8_with_chroma(omt::ChromaUpsample::Linear),
+        None
+    );
+}
+
+#[test]
+fn test_can_convert_p216() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::P216)
+        .dimensions(4, 2)
+        .data(vec![0u8; 4 * 2 * 2 * 2])
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    assert!(media_frame.can_convert_rgb8());
+    assert!(media_frame.can_convert_rgba8());
+    assert!(media_frame.can_convert_rgb16());
+    assert!(media_frame.can_convert_rgba16());
+    assert!(media_frame.to_rgba16().is_some());
+    assert!(media_frame.to_rgba8().is_some());
+}
+
+#[test]
+fn test_can_convert_uyvy() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(4, 2)
+        .data(vec![128u8; 4 * 2 * 2])
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    assert!(media_frame.can_convert_rgba8());
+    assert!(!media_frame.can_convert_rgba16());
+    assert!(media_frame.to_rgba8().is_some());
+}
+
+#[test]
+fn test_can_convert_malformed_uyvy() {
+    // Data is one row short
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(4, 2)
+        .data(vec![128u8; 4 * 2])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(!frame.as_media_frame().can_convert_rgba8());
+
+    // Stride is too small for the width
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(4, 2)
+        .stride(4)
+        .data(vec![128u8; 4 * 2 * 2])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(!frame.as_media_frame().can_convert_rgba8());
+}
+
+#[test]
+fn test_write_ppm() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(3, 2)
+        .data([10u8, 20, 30, 255].repeat(3 * 2))
+        .build()
+        .expect("Failed to build video frame");
+
+    let mut out = Vec::new();
+    assert!(frame.as_media_frame().write_ppm(&mut out).unwrap());
+
+    let header = b"P6\n3 2\n255\n";
+    assert!(out.starts_with(header));
+    let pixels = &out[header.len()..];
+    assert_eq!(pixels.len(), 3 * 2 * 3);
+    assert_eq!(&pixels[..3], &[30, 20, 10]);
+}
+
+#[test]
+fn test_write_pam() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(3, 2)
+        .data([10u8, 20, 30, 128].repeat(3 * 2))
+        .build()
+        .expect("Failed to build video frame");
+
+    let mut out = Vec::new();
+    assert!(frame.as_media_frame().write_pam(&mut out).unwrap());
+
+    let header = b"P7\nWIDTH 3\nHEIGHT 2\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n";
+    assert!(out.starts_with(header));
+    assert_eq!(out.len() - header.len(), 3 * 2 * 4);
+}
+
+#[test]
+fn test_write_ppm_unsupported_codec() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Vmx1)
+        .dimensions(2, 2)
+        .data(vec![0u8; 2 * 2 * 4])
+        .build()
+        .expect("Failed to build video frame");
+
+    let mut out = Vec::new();
+    assert!(!frame.as_media_frame().write_ppm(&mut out).unwrap());
+    assert!(out.is_empty());
+}
+
+/// Builds PA16 (or P216 without `alpha`) data with a mid-gray image.
+fn pa16_data(width: usize, height: usize, alpha: Option<u16>) -> Vec<u8> {
+    let y_plane = vec![0x8000u16; width * height];
+    let uv_plane = vec![0x8000u16; width.div_ceil(2) * 2 * height];
+    let alpha_plane = alpha.map(|a| vec![a; width * height]).unwrap_or_default();
+    y_plane
+        .iter()
+        .chain(uv_plane.iter())
+        .chain(alpha_plane.iter())
+        .flat_map(|v| v.to_le_bytes())
+        .collect()
+}
+
+#[test]
+fn test_pa16_alpha() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Pa16)
+        .dimensions(4, 2)
+        .data(pa16_data(4, 2, Some(0xABCD)))
+        .build()
+        .expect("Failed to build video frame");
+    let alpha = frame
+        .as_media_frame()
+        .pa16_alpha()
+        .expect("PA16 frame should have alpha");
+    assert_eq!(alpha, vec![0xABCD; 4 * 2]);
+}
+
+#[test]
+fn test_pa16_alpha_p216_returns_none() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::P216)
+        .dimensions(4, 2)
+        .data(pa16_data(4, 2, None))
+        .build()
+        .expect("Failed to build video frame");
+    assert!(frame.as_media_frame().pa16_alpha().is_none());
+
+    // PA16 without the alpha plane is too short
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Pa16)
+        .dimensions(4, 2)
+        .data(pa16_data(4, 2, None))
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(frame.as_media_frame().pa16_alpha().is_none());
+}
+
+#[test]
+fn test_to_nv12_round_trip_gray() {
+    let gray = 128u8;
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(8, 4)
+        .data([gray, gray, gray, 255].repeat(8 * 4))
+        .build()
+        .expect("Failed to build video frame");
+
+    let nv12 = frame
+        .as_media_frame()
+        .to_nv12()
+        .expect("BGRA should convert to NV12");
+    let nv12_frame = nv12.as_media_frame();
+    assert_eq!(nv12_frame.codec(), Some(Codec::Nv12));
+    assert_eq!(nv12_frame.stride(), 8);
+    assert_eq!(nv12_frame.data().len(), 8 * 4 + 8 * 2);
+
+    let pixels = nv12_frame.to_rgb8().expect("NV12 should convert to RGB8");
+    assert_eq!(pixels.len(), 8 * 4);
+    for px in pixels {
+        for channel in [px.r, px.g, px.b] {
+            assert!(
+                (channel as i32 - gray as i32).abs() <= 2,
+                "Expected gray {}, got {:?}",
+                gray,
+                px
+            );
+        }
+    }
+}
+
+#[test]
+fn test_to_nv12_from_uyvy_matches_rgb8() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(4, 2)
+        .data([128u8, 126, 128, 126].repeat(2 * 2))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let nv12 = media_frame.to_nv12().expect("UYVY should convert to NV12");
+    let nv12_frame = nv12.as_media_frame();
+    assert_eq!(&nv12_frame.data()[..4 * 2], &[126u8; 4 * 2]);
+    assert_eq!(&nv12_frame.data()[4 * 2..], &[128u8; 4]);
+    let expected = media_frame.to_rgb8().expect("UYVY should convert to RGB8");
+    let actual = nv12_frame.to_rgb8().expect("NV12 should convert to RGB8");
+    for (a, b) in expected.iter().zip(actual.iter()) {
+        assert!((a.r as i32 - b.r as i32).abs() <= 2, "{:?} vs {:?}", a, b);
+        assert!((a.g as i32 - b.g as i32).abs() <= 2, "{:?} vs {:?}", a, b);
+        assert!((a.b as i32 - b.b as i32).abs() <= 2, "{:?} vs {:?}", a, b);
+    }
+}
+
+#[test]
+fn test_to_rgba8_or_black_converts_supported_frame() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(3, 2)
+        .data([10u8, 20, 30, 255].repeat(3 * 2))
+        .build()
+        .expect("Failed to build video frame");
+
+    let (width, height, pixels) = frame.as_media_frame().to_rgba8_or_black();
+    assert_eq!((width, height), (3, 2));
+    assert_eq!(pixels, [30u8, 20, 10, 255].repeat(3 * 2));
+}
+
+#[test]
+fn test_to_rgba8_or_black_vmx1_returns_black() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Vmx1)
+        .dimensions(64, 32)
+        .data(vec![0xAB; 256])
+        .build()
+        .expect("Failed to build video frame");
+
+    let (width, height, pixels) = frame.as_media_frame().to_rgba8_or_black();
+    assert_eq!((width, height), (64, 32));
+    assert_eq!(pixels, [0u8, 0, 0, 255].repeat(64 * 32));
+}
+
+#[test]
+fn test_to_rgba8_or_black_implausible_dimensions() {
+    // A malformed frame claiming 65535x65535 pixels must not allocate ~17 GB
+    for (width, height) in [(65535, 65535), (i32::MAX, i32::MAX), (7681, 4320)] {
+        let frame = VideoFrameBuilder::new()
+            .codec(Codec::Vmx1)
+            .dimensions(width, height)
+            .data(vec![0xAB; 256])
+            .build()
+            .expect("Failed to build video frame");
+        assert_eq!(
+            frame.as_media_frame().to_rgba8_or_black(),
+            (1, 1, vec![0, 0, 0, 255]),
+            "{width}x{height}"
+        );
+    }
+}
+
+#[test]
+fn test_to_rgba8_or_black_non_video() {
+    let frame = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+
+    assert_eq!(
+        frame.as_media_frame().to_rgba8_or_black(),
+        (1, 1, vec![0, 0, 0, 255])
+    );
+}
+
+#[test]
+fn test_to_rgba8_capped_under_cap_is_full_resolution() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(8, 4)
+        .data([10u8, 20, 30, 255].repeat(8 * 4))
+        .build()
+        .expect("Failed to build video frame");
+
+    let (width, height, pixels) = frame
+        .as_media_frame()
+        .to_rgba8_capped(8, 8)
+        .expect("Conversion failed");
+    assert_eq!((width, height), (8, 4));
+    assert_eq!(pixels, [30u8, 20, 10, 255].repeat(8 * 4));
+}
+
+#[test]
+fn test_to_rgba8_capped_over_cap_is_downscaled() {
+    // Left half red, right half blue
+    let data: Vec<u8> = (0..16 * 8)
+        .flat_map(|i| {
+            if i % 16 < 8 {
+                [0u8, 0, 255, 255]
+            } else {
+                [255, 0, 0, 255]
+            }
+        })
+        .collect();
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(16, 8)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame");
+
+    let (width, height, pixels) = frame
+        .as_media_frame()
+        .to_rgba8_capped(4, 4)
+        .expect("Conversion failed");
+    assert_eq!((width, height), (4, 2));
+    assert_eq!(pixels.len(), 4 * 2 * 4);
+    assert_eq!(&pixels[..4], &[255, 0, 0, 255]);
+    assert_eq!(&pixels[12..16], &[0, 0, 255, 255]);
+
+    assert!(frame.as_media_frame().to_rgba8_capped(0, 4).is_none());
+}
+
+#[test]
+fn test_to_rgba8_with_stride_pads_rows() {
+    let (width, height) = (3usize, 2usize);
+    let data: Vec<u8> = (0..width * height * 4).map(|i| i as u8).collect();
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(width as i32, height as i32)
+        .data(data)
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let tight = media_frame.to_rgba8_or_black().2;
+    let stride = width * 4 + 16;
+    let padded = media_frame
+        .to_rgba8_with_stride(stride as u32)
+        .expect("Conversion failed");
+    assert_eq!(padded.len(), stride * height);
+
+    for row in 0..height {
+        let padded_row = &padded[row * stride..(row + 1) * stride];
+        assert_eq!(
+            &padded_row[..width * 4],
+            &tight[row * width * 4..(row + 1) * width * 4]
+        );
+        assert!(padded_row[width * 4..].iter().all(|&b| b == 0));
+    }
+
+    assert_eq!(
+        media_frame.to_rgba8_with_stride((width * 4) as u32),
+        Some(tight)
+    );
+    assert!(
+        media_frame
+            .to_rgba8_with_stride((width * 4 - 1) as u32)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_to_rgba8_with_stride_aligned_uyvy() {
+    let (width, height) = (100usize, 4usize);
+    let frame = VideoFrameBuilder::with_format(Codec::Uyvy, width as i32, height as i32)
+        .data(neutral_gray_data(Codec::Uyvy, width, height))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let tight = media_frame.to_rgba8().expect("Conversion failed");
+    let tight: Vec<u8> = tight
+        .iter()
+        .flat_map(|px| [px.r, px.g, px.b, px.a])
+        .collect();
+
+    // 400 bytes per row, aligned up to 512
+    let stride = (width * 4).next_multiple_of(256);
+    let padded = media_frame
+        .to_rgba8_with_stride(stride as u32)
+        .expect("Conversion failed");
+    assert_eq!(padded.len(), 512 * height);
+    for (padded_row, tight_row) in padded.chunks(stride).zip(tight.chunks(width * 4)) {
+        assert_eq!(&padded_row[..width * 4], tight_row);
+        assert!(padded_row[width * 4..].iter().all(|&b| b == 0));
+    }
+}
+
+#[test]
+fn test_to_rgba8_alpha_premultiplied_halves_red() {
+    // Straight red at 50% alpha, BGRA byte order
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(2, 1)
+        .flags(VideoFlags::ALPHA)
+        .data([0u8, 0, 255, 128].repeat(2))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let straight = media_frame
+        .to_rgba8_alpha(AlphaMode::Straight)
+        .expect("Conversion failed");
+    let premultiplied = media_frame
+        .to_rgba8_alpha(AlphaMode::Premultiplied)
+        .expect("Conversion failed");
+    assert_eq!((straight[0].r, straight[0].a), (255, 128));
+    assert_eq!((premultiplied[0].r, premultiplied[0].a), (128, 128));
+
+    let flattened = media_frame
+        .to_rgba8_alpha(AlphaMode::None)
+        .expect("Conversion failed");
+    assert_eq!((flattened[0].r, flattened[0].a), (128, 255));
+}
+
+#[test]
+fn test_to_rgba8_alpha_without_alpha_flag_is_opaque() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(1, 1)
+        .data(vec![0, 0, 255, 0])
+        .build()
+        .expect("Failed to build video frame");
+
+    let pixels = frame
+        .as_media_frame()
+        .to_rgba8_alpha(AlphaMode::Premultiplied)
+        .expect("Conversion failed");
+    assert_eq!((pixels[0].r, pixels[0].a), (255, 255));
+}
+
+#[test]
+fn test_try_to_rgba8() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Bgra)
+        .dimensions(2, 1)
+        .data(vec![10, 20, 30, 255, 40, 50, 60, 128])
+        .build()
+        .expect("Failed to build frame");
+    assert_eq!(
+        frame.as_media_frame().try_to_rgba8(),
+        Ok(vec![30, 20, 10, 255, 60, 50, 40, 128])
+    );
+}
+
+#[test]
+fn test_try_to_rgba8_unsupported_codec() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Vmx1)
+        .dimensions(2, 2)
+        .data(vec![0; 2 * 2 * 2 * 2])
+        .build()
+        .expect("Failed to build frame");
+    assert_eq!(
+        frame.as_media_frame().try_to_rgba8(),
+        Err(ConvertError::UnsupportedCodec)
+    );
+
+    let audio = OwnedMediaFrame::silent_audio(48_000, 2, 16, -1).expect("Invalid parameters");
+    assert_eq!(
+        audio.as_media_frame().try_to_rgba8(),
+        Err(ConvertError::UnsupportedCodec)
+    );
+}
+
+#[test]
+fn test_try_to_rgba8_invalid_geometry() {
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::Uyvy)
+        .dimensions(16, 16)
+        .data(vec![0x80; 16])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build frame");
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.to_rgba8(), None);
+    assert_eq!(
+        media_frame.try_to_rgba8(),
+        Err(ConvertError::InvalidGeometry)
+    );
+}
+
+#[test]
+fn test_uyva_from_rgba8_round_trip() {
+    let width = 16;
+    let height = 8;
+    // Color varies per row so 4:2:2 chroma subsampling is lossless up to rounding,
+    // alpha varies per pixel to verify it is kept at full resolution
+    let rgba: Vec<u8> = (0..height)
+        .flat_map(|y| {
+            (0..width).flat_map(move |x| {
+                [
+                    (y * 32) as u8,
+                    255 - (y * 32) as u8,
+                    (64 + y * 16) as u8,
+                    (y * width + x) as u8,
+                ]
+            })
+        })
+        .collect();
+
+    let frame = OwnedMediaFrame::video_uyva_from_rgba8(width, height, &rgba, ColorSpace::Bt601)
+        .expect("Failed to encode UYVA");
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.codec(), Some(Codec::Uyva));
+    assert!(media_frame.flags().contains(VideoFlags::ALPHA));
+    assert_eq!(media_frame.alpha_mode(), AlphaMode::Straight);
+    assert_eq!(media_frame.data_matches_geometry(), Some(true));
+
+    let decoded = media_frame.to_rgba8().expect("Failed to decode UYVA");
+    for (pixel, expected) in decoded.iter().zip(rgba.chunks_exact(4)) {
+        assert!(
+            pixel.r.abs_diff(expected[0]) <= 3,
+            "{:?} vs {:?}",
+            pixel,
+            expected
+        );
+        assert!(
+            pixel.g.abs_diff(expected[1]) <= 3,
+            "{:?} vs {:?}",
+            pixel,
+            expected
+        );
+        assert!(
+            pixel.b.abs_diff(expected[2]) <= 3,
+            "{:?} vs {:?}",
+            pixel,
+            expected
+        );
+        assert_eq!(pixel.a, expected[3]);
+    }
+}
+
+#[test]
+fn test_uyva_from_rgba8_bt2020_uses_own_matrix() {
+    let rgba = [255, 0, 0, 255].repeat(4 * 2);
+    let encode = |color_space| {
+        OwnedMediaFrame::video_uyva_from_rgba8(4, 2, &rgba, color_space)
+            .expect("Failed to create frame")
+            .data()
+            .to_vec()
+    };
+
+    let bt2020 = encode(ColorSpace::Bt2020);
+    assert_ne!(bt2020, encode(ColorSpace::Bt709));
+    assert_ne!(bt2020, encode(ColorSpace::Bt601));
+}
+
+#[test]
+fn test_bt2020_frame_converts_with_bt2020_matrix() {
+    let rgb = [200u8, 40, 60].repeat(4 * 2);
+    let mut frame = OwnedMediaFrame::video_uyvy_from_rgb8(4, 2, &rgb, ColorSpace::Bt2020)
+        .expect("Failed to create frame");
+    assert_eq!(
+        frame.as_media_frame().color_space(),
+        Some(ColorSpace::Bt2020)
+    );
+
+    // Decoding with the matrix used for encoding restores the color
+    let decoded = frame
+        .as_media_frame()
+        .to_rgb8()
+        .expect("Failed to decode UYVY");
+    for pixel in &decoded {
+        assert!(pixel.r.abs_diff(200) <= 3, "{pixel:?}");
+        assert!(pixel.g.abs_diff(40) <= 3, "{pixel:?}");
+        assert!(pixel.b.abs_diff(60) <= 3, "{pixel:?}");
+    }
+
+    // The same data labelled BT.709 decodes to a different color
+    frame.set_color_space(ColorSpace::Bt709);
+    assert_ne!(frame.as_media_frame().to_rgb8(), Some(decoded));
+}
+
+#[test]
+fn test_uyva_from_rgba8_wrong_length() {
+    assert!(OwnedMediaFrame::video_uyva_from_rgba8(4, 4, &[0; 60], ColorSpace::Undefined).is_err());
+    assert!(OwnedMediaFrame::video_uyva_from_rgba8(0, 4, &[], ColorSpace::Undefined).is_err());
+}
+
+#[test]
+fn test_bgra_from_rgba8_round_trip() {
+    let (width, height) = (5, 3);
+    let rgba: Vec<u8> = (0..width * height * 4).map(|i| (i * 7) as u8).collect();
+
+    let frame = OwnedMediaFrame::video_bgra_from_rgba8(width, height, &rgba)
+        .expect("Failed to encode BGRA");
+    let media_frame = frame.as_media_frame();
+    assert_eq!(media_frame.codec(), Some(Codec::Bgra));
+    assert!(!media_frame.flags().contains(VideoFlags::ALPHA));
+    assert_eq!(media_frame.data_matches_geometry(), Some(true));
+
+    let decoded = media_frame.to_rgba8().expect("Failed to decode BGRA");
+    for (pixel, expected) in decoded.iter().zip(rgba.chunks_exact(4)) {
+        assert_eq!([pixel.r, pixel.g, pixel.b], expected[..3]);
+    }
+
+    assert!(OwnedMediaFrame::video_bgra_from_rgba8(2, 2, &[0; 15]).is_err());
+}
+
+#[test]
+fn test_uyvy_from_rgb8_round_trip() {
+    let (width, height) = (8, 4);
+    // Horizontal pairs share a color, so 4:2:2 chroma subsampling loses nothing
+    let rgb: Vec<u8> = (0..height)
+        .flat_map(|y| {
+            (0..width).flat_map(move |x| {
+                let pair = (x / 2 + y * 4) as u8;
+                [pair * 15, 255 - pair * 10, 40 + pair * 10]
+            })
+        })
+        .collect();
+
+    for color_space in [ColorSpace::Bt601, ColorSpace::Bt709, ColorSpace::Undefined] {
+        let frame = OwnedMediaFrame::video_uyvy_from_rgb8(width, height, &rgb, color_space)
+            .expect("Failed to encode UYVY");
+        let media_frame = frame.as_media_frame();
+        assert_eq!(media_frame.codec(), Some(Codec::Uyvy));
+        assert_eq!(media_frame.data_matches_geometry(), Some(true));
+
+        let decoded = media_frame.to_rgb8().expect("Failed to decode UYVY");
+        for (pixel, expected) in decoded.iter().zip(rgb.chunks_exact(3)) {
+            assert!(
+                pixel.r.abs_diff(expected[0]) <= 3
+                    && pixel.g.abs_diff(expected[1]) <= 3
+                    && pixel.b.abs_diff(expected[2]) <= 3,
+                "{:?}: {:?} vs {:?}",
+                color_space,
+                pixel,
+                expected
+            );
+        }
+    }
+
+    assert!(OwnedMediaFrame::video_uyvy_from_rgb8(4, 4, &[0; 47], ColorSpace::Undefined).is_err());
+    assert!(OwnedMediaFrame::video_uyvy_from_rgb8(0, 4, &[], ColorSpace::Undefined).is_err());
+}
+
+#[test]
+fn test_rgba8_output_len() {
+    let frame =
+        OwnedMediaFrame::into_sendable(Codec::Uyvy, 1920, 1080, vec![0x80; 1920 * 1080 * 2])
+            .expect("Failed to build frame");
+    assert_eq!(frame.as_media_frame().rgba8_output_len(), Some(8_294_400));
+
+    let metadata = MetadataFrameBuilder::new()
+        .metadata("<test/>")
+        .build()
+        .expect("Failed to build metadata frame");
+    assert_eq!(metadata.as_media_frame().rgba8_output_len(), None);
+}
+
+#[test]
+fn test_to_rgb16_partial_truncated_p216() {
+    let width = 8;
+    let height = 8;
+    // Full Y plane plus chroma for the first 4 rows
+    let frame = VideoFrameBuilder::new()
+        .codec(Codec::P216)
+        .dimensions(width, height)
+        .data(vec![0x80; (width * height * 2 + 4 * width * 2) as usize])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    assert!(media_frame.to_rgb16().is_none());
+    let (rows, pixels) = media_frame
+        .to_rgb16_partial()
+        .expect("Partial conversion failed");
+    assert_eq!(rows, 4);
+    assert_eq!(pixels.len(), (width * height) as usize);
+}
+
+#[test]
+fn test_diff_rgba8() {
+    let bgra = |data: Vec<u8>| {
+        VideoFrameBuilder::with_format(Codec::Bgra, 4, 2)
+            .data(data)
+            .build()
+            .expect("Failed to build video frame")
+    };
+    // Alternating black and white pixels, opaque
+    let pattern = (0..8)
+        .flat_map(|i| if i % 2 == 0 { [0, 0, 0, 255] } else { [255; 4] })
+        .collect::<Vec<u8>>();
+    let inverted = pattern
+        .chunks_exact(4)
+        .flat_map(|p| [255 - p[0], 255 - p[1], 255 - p[2], 255])
+        .collect::<Vec<u8>>();
+    let frame = bgra(pattern);
+    let inverted = bgra(inverted);
+
+    let same = frame
+        .as_media_frame()
+        .diff_rgba8(&frame.as_media_frame())
+        .expect("Diff failed");
+    assert_eq!(same.len(), 4 * 2 * 4);
+    assert!(same.chunks_exact(4).all(|p| p == [0, 0, 0, 255]));
+
+    let diff = frame
+        .as_media_frame()
+        .diff_rgba8(&inverted.as_media_frame())
+        .expect("Diff failed");
+    assert!(diff.chunks_exact(4).all(|p| p == [255, 255, 255, 255]));
+}
+
+#[test]
+fn test_diff_rgba8_mismatch() {
+    let frame = VideoFrameBuilder::with_format(Codec::Bgra, 4, 2)
+        .data(vec![0; 4 * 2 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    let larger = VideoFrameBuilder::with_format(Codec::Bgra, 4, 4)
+        .data(vec![0; 4 * 4 * 4])
+        .build()
+        .expect("Failed to build video frame");
+    let uyvy = VideoFrameBuilder::with_format(Codec::Uyvy, 4, 2)
+        .data(vec![0x80; 4 * 2 * 2])
+        .build()
+        .expect("Failed to build video frame");
+
+    assert!(
+        frame
+            .as_media_frame()
+            .diff_rgba8(&larger.as_media_frame())
+            .is_none()
+    );
+    assert!(
+        frame
+            .as_media_frame()
+            .diff_rgba8(&uyvy.as_media_frame())
+            .is_none()
+    );
+}
+
+#[test]
+fn test_p216_to_rgb8_gray() {
+    let frame = VideoFrameBuilder::with_format(Codec::P216, 6, 2)
+        .data(pa16_data(6, 2, None))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let rgb = media_frame.to_rgb8().expect("P216 should convert to RGB8");
+    assert_eq!(rgb.len(), 6 * 2);
+    assert!(rgb.iter().all(|p| p.r == p.g && p.g == p.b));
+    let rgba = media_frame
+        .to_rgba8()
+        .expect("P216 should convert to RGBA8");
+    assert!(
+        rgba.iter()
+            .all(|p| p.r == p.g && p.g == p.b && p.r == rgb[0].r && p.a == 255)
+    );
+}
+
+#[test]
+fn test_pa16_to_rgba8_alpha() {
+    let frame = VideoFrameBuilder::with_format(Codec::Pa16, 4, 2)
+        .flags(VideoFlags::ALPHA)
+        .data(pa16_data(4, 2, Some(0x4080)))
+        .build()
+        .expect("Failed to build video frame");
+    let media_frame = frame.as_media_frame();
+
+    let rgba = media_frame
+        .to_rgba8()
+        .expect("PA16 should convert to RGBA8");
+    assert!(rgba.iter().all(|p| p.r == p.g && p.g == p.b && p.a == 0x40));
+    assert_eq!(
+        media_frame
+            .to_rgb8()
+            .expect("PA16 should convert to RGB8")
+            .len(),
+        4 * 2
+    );
+}
+
+#[test]
+fn test_nv21_matches_nv12_with_swapped_chroma() {
+    let (width, height) = (8, 4);
+    let y_plane = (0..width * height)
+        .map(|i| 16 + i as u8 * 6)
+        .collect::<Vec<_>>();
+    let uv_pairs = [[90u8, 200u8], [160, 60], [110, 140], [200, 100]].repeat(height / 2);
+    let frame = |codec: Codec, swap: bool| {
+        let chroma = uv_pairs
+            .iter()
+            .flat_map(|&[u, v]| if swap { [v, u] } else { [u, v] });
+        // NV21 is only converted locally, so the builder must be told to accept it
+        VideoFrameBuilder::with_format(codec, width as i32, height as i32)
+            .data(y_plane.iter().copied().chain(chroma).collect())
+            .allow_local_codecs(swap)
+            .build()
+            .expect("Failed to build video frame")
+    };
+    let nv12 = frame(Codec::Nv12, false);
+    let nv21 = frame(Codec::Nv21, true);
+
+    assert!(nv21.as_media_frame().can_convert_rgba8());
+    assert_eq!(
+        nv21.as_media_frame().to_rgb8(),
+        nv12.as_media_frame().to_rgb8()
+    );
+    assert_eq!(
+        nv21.as_media_frame().to_rgba8(),
+        nv12.as_media_frame().to_rgba8()
+    );
+    assert_eq!(
+        nv21.as_media_frame()
+            .to_rgba8_with_chroma(omt::ChromaUpsample::Linear),
+        nv12.as_media_frame()
+            .to_rgba8_with_chroma(omt::ChromaUpsample::Linear)
+    );
+    assert!(
+        nv21.as_media_frame()
+            .to_rgba8_with_chroma(omt::ChromaUpsample::Linear)
+            .is_some()
+    );
+    assert_eq!(
+        nv21.as_media_frame().to_nv12().map(|f| f.data().to_vec()),
+        nv12.as_media_frame().to_nv12().map(|f| f.data().to_vec())
+    );
+    assert_eq!(Codec::Nv21.fourcc(), "NV21");
+
+    let result = VideoFrameBuilder::with_format(Codec::Nv21, width as i32, height as i32)
+        .data(vec![0u8; width * height * 3 / 2])
+        .build();
+    assert!(matches!(result, Err(Error::InvalidParameter { .. })));
+
+    // Skipping the length checks does not admit local-only codecs
+    let result = VideoFrameBuilder::with_format(Codec::Nv21, width as i32, height as i32)
+        .data(vec![0u8; width * height * 3 / 2])
+        .allow_short_data(true)
+        .build();
+    assert!(matches!(result, Err(Error::InvalidParameter { .. })));
+
+    // and admitting them keeps the length checks
+    let result = VideoFrameBuilder::with_format(Codec::Nv21, width as i32, height as i32)
+        .data(vec![0u8; width * height])
+        .allow_local_codecs(true)
+        .build();
+    assert!(matches!(result, Err(Error::BufferTooSmall { .. })));
+}
+
+#[test]
+fn test_odd_width_packed_422_last_column() {
+    let (width, height) = (7, 2);
+    for codec in [Codec::Uyvy, Codec::Yuy2] {
+        // Dark gray with a white last column, which shares its pair with padding
+        let (mut row, last_luma) = match codec {
+            Codec::Uyvy => ([128u8, 16, 128, 16].repeat(4), 13),
+            _ => ([16u8, 128, 16, 128].repeat(4), 12),
+        };
+        row[last_luma] = 235;
+        let frame = VideoFrameBuilder::with_format(codec, width, height)
+            .data(row.repeat(height as usize))
+            .build()
+            .expect("Failed to build video frame");
+        let media_frame = frame.as_media_frame();
+        assert_eq!(media_frame.stride(), 16, "{codec:?}");
+        assert!(media_frame.can_convert_rgba8(), "{codec:?}");
+
+        let rgb = media_frame.to_rgb8().expect("RGB8 conversion failed");
+        let rgba = media_frame.to_rgba8().expect("RGBA8 conversion failed");
+        assert_eq!(rgb.len(), 7 * 2);
+        assert_eq!(rgba.len(), 7 * 2);
+        for y in 0..height as usize {
+            let last = rgb[y * 7 + 6];
+            assert!(
+                last.r > 240 && last.g > 240 && last.b > 240,
+                "{codec:?}: {last:?}"
+            );
+            let first = rgba[y * 7];
+            assert!(
+                first.r < 10 && first.g < 10 && first.b < 10,
+                "{codec:?}: {first:?}"
+            );
+            assert_eq!(rgba[y * 7 + 6].rgb(), last, "{codec:?}");
+        }
+    }
+
+    // A stride that cuts the final pair short is rejected by the builder
+    let result = VideoFrameBuilder::with_format(Codec::Uyvy, width, height)
+        .stride(14)
+        .data(vec![0x80; 14 * 2])
+        .build();
+    assert!(matches!(result, Err(Error::InvalidParameter { .. })));
+
+    // and by the converters rather than read past
+    let frame = VideoFrameBuilder::with_format(Codec::Uyvy, width, height)
+        .stride(14)
+        .data(vec![0x80; 14 * 2])
+        .allow_short_data(true)
+        .build()
+        .expect("Failed to build video frame");
+    assert!(!frame.as_media_frame().can_convert_rgba8());
+    assert_eq!(frame.as_media_frame().to_rgba8(), None);
+
+    // even when the buffer itself is long enough for the padded rows
+    for codec in [Codec::Uyvy, Codec::Yuy2, Codec::Uyva] {
+        let frame = VideoFrameBuilder::with_format(codec, width, height)
+            .stride(14)
+            .data(vec![0x80; 16 * 2 * 2])
+            .allow_short_data(true)
+            .build()
+            .expect("Failed to build video frame");
+        assert_eq!(frame.as_media_frame().to_rgb8(), None, "{codec:?}");
+        assert_eq!(frame.as_media_frame().to_rgba8(), None, "{codec:?}");
+    }
+}